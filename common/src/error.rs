@@ -12,6 +12,9 @@ pub enum Error {
     #[error(transparent)]
     FromBase64(#[from] base64::DecodeError),
 
+    #[error("Malformed query: {0}")]
+    MalformedQuery(&'static str),
+
     #[cfg(not(feature = "test-utils"))]
     #[error(transparent)]
     Prove(#[from] VrfError),
@@ -0,0 +1,12 @@
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct Execute {
+    pub dr_id: String,
+}
+
+impl From<Execute> for crate::msgs::ExecuteMsg {
+    fn from(value: Execute) -> Self {
+        super::ExecuteMsg::CancelDataRequest(value).into()
+    }
+}
@@ -1,17 +1,23 @@
 use super::DrConfig;
 
+pub mod cancel_request;
 pub mod commit_result;
 pub mod post_request;
+pub mod post_requests;
 pub mod reveal_result;
+pub mod top_up_request;
 
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
 #[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
 pub enum ExecuteMsg {
+    CancelDataRequest(cancel_request::Execute),
     CommitDataResult(commit_result::Execute),
     PostDataRequest(post_request::Execute),
+    PostDataRequests(post_requests::Execute),
     RevealDataResult(reveal_result::Execute),
     SetDrConfig(DrConfig),
+    TopUpDataRequest(top_up_request::Execute),
 }
 
 impl From<ExecuteMsg> for crate::msgs::ExecuteMsg {
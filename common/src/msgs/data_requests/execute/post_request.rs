@@ -1,4 +1,7 @@
-use crate::{msgs::data_requests::PostDataRequestArgs, types::Bytes};
+use crate::{
+    msgs::data_requests::{DataRequestCallback, PostDataRequestArgs},
+    types::Bytes,
+};
 
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
@@ -7,6 +10,9 @@ pub struct Execute {
     pub posted_dr:       PostDataRequestArgs,
     pub seda_payload:    Bytes,
     pub payback_address: Bytes,
+    /// Contract to notify with the result once the request is removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback:        Option<DataRequestCallback>,
 }
 
 impl From<Execute> for crate::msgs::ExecuteMsg {
@@ -0,0 +1,16 @@
+use super::post_request;
+
+/// Posts several data requests at once. The attached funds are split over the
+/// requests, each escrowing exactly its `gas_price` times its total gas limit.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct Execute {
+    pub requests: Vec<post_request::Execute>,
+}
+
+impl From<Execute> for crate::msgs::ExecuteMsg {
+    fn from(value: Execute) -> Self {
+        super::ExecuteMsg::PostDataRequests(value).into()
+    }
+}
@@ -8,6 +8,23 @@ use crate::{msgs, types::U128};
 #[cfg(feature = "cosmwasm")]
 use crate::{msgs::assert_json_deser, types::Bytes};
 
+#[test]
+fn json_cancel_request() {
+    let expected_json = json!({
+      "cancel_data_request": {
+        "dr_id": "dr_id"
+      }
+    });
+    let msg: msgs::ExecuteMsg = cancel_request::Execute {
+        dr_id: "dr_id".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
 #[test]
 fn json_commit_result() {
     let expected_json = json!({
@@ -79,6 +96,9 @@ fn json_post_request() {
         consensus_filter: consensus_filter.clone(),
         gas_price,
         memo: memo.clone(),
+        commit_timeout_in_blocks: None,
+        reveal_timeout_in_blocks: None,
+        executors: None,
     };
     let expected_json = json!({
       "post_data_request": {
@@ -103,6 +123,7 @@ fn json_post_request() {
         posted_dr: args,
         seda_payload,
         payback_address,
+        callback: None,
     }
     .into();
     #[cfg(not(feature = "cosmwasm"))]
@@ -157,3 +178,82 @@ fn json_reveal_result() {
     #[cfg(feature = "cosmwasm")]
     assert_json_deser(msg, expected_json);
 }
+
+#[test]
+fn json_top_up_request() {
+    let expected_json = json!({
+      "top_up_data_request": {
+        "dr_id": "dr_id"
+      }
+    });
+    let msg: msgs::ExecuteMsg = top_up_request::Execute {
+        dr_id: "dr_id".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_post_requests() {
+    #[cfg(not(feature = "cosmwasm"))]
+    let bytes = "bytes".to_string();
+    #[cfg(feature = "cosmwasm")]
+    let bytes: Bytes = "bytes".as_bytes().into();
+    let gas_price: U128 = 100u128.into();
+
+    let args = PostDataRequestArgs {
+        version: Version::new(1, 0, 0),
+        exec_program_id: "exec_program_id".to_string(),
+        exec_inputs: bytes.clone(),
+        exec_gas_limit: 100,
+        tally_program_id: "tally_program_id".to_string(),
+        tally_inputs: bytes.clone(),
+        tally_gas_limit: 100,
+        replication_factor: 1,
+        consensus_filter: bytes.clone(),
+        gas_price,
+        memo: bytes.clone(),
+        commit_timeout_in_blocks: None,
+        reveal_timeout_in_blocks: None,
+        executors: None,
+    };
+    let expected_json = json!({
+      "post_data_requests": {
+        "requests": [
+          {
+            "posted_dr": {
+              "version": "1.0.0",
+              "exec_program_id": "exec_program_id",
+              "exec_inputs": bytes,
+              "exec_gas_limit": 100,
+              "tally_program_id": "tally_program_id",
+              "tally_inputs": bytes,
+              "tally_gas_limit": 100,
+              "replication_factor": 1,
+              "consensus_filter": bytes,
+              "gas_price": gas_price.to_string(),
+              "memo": bytes
+            },
+            "seda_payload": bytes,
+            "payback_address": bytes,
+          }
+        ]
+      }
+    });
+    let msg: msgs::ExecuteMsg = post_requests::Execute {
+        requests: vec![post_request::Execute {
+            posted_dr:       args,
+            seda_payload:    bytes.clone(),
+            payback_address: bytes,
+            callback:        None,
+        }],
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
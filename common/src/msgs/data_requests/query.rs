@@ -32,8 +32,14 @@ pub enum QueryMsg {
         last_seen_index: Option<LastSeenIndexKey>,
         limit:           u32,
     },
+    #[cfg_attr(feature = "cosmwasm", returns(Option<GetDataRequestExecutorsResponse>))]
+    GetDataRequestExecutors { dr_id: String },
     #[cfg_attr(feature = "cosmwasm", returns(DrConfig))]
     GetDrConfig {},
+    #[cfg_attr(feature = "cosmwasm", returns(Option<DataResult>))]
+    GetDataResult { dr_id: String },
+    #[cfg_attr(feature = "cosmwasm", returns(std::collections::HashMap<String, Option<DataResult>>))]
+    GetDataResults { dr_ids: Vec<String> },
 }
 
 impl From<QueryMsg> for crate::msgs::QueryMsg {
@@ -155,3 +155,57 @@ fn json_get_data_requests_statuses() {
     #[cfg(feature = "cosmwasm")]
     assert_json_deser(msg, expected_json);
 }
+
+#[test]
+fn json_get_data_result() {
+    let expected_json = json!({
+      "get_data_result": {
+        "dr_id": "dr_id",
+      }
+    });
+    let msg: QueryMsg = DrQueryMsg::GetDataResult {
+        dr_id: "dr_id".to_string(),
+    }
+    .into();
+
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_get_data_results() {
+    let expected_json = json!({
+      "get_data_results": {
+        "dr_ids": ["dr_id1", "dr_id2"],
+      }
+    });
+    let msg: QueryMsg = DrQueryMsg::GetDataResults {
+        dr_ids: vec!["dr_id1".to_string(), "dr_id2".to_string()],
+    }
+    .into();
+
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_get_data_request_executors() {
+    let expected_json = json!({
+      "get_data_request_executors": {
+        "dr_id": "dr_id",
+      }
+    });
+    let msg: QueryMsg = DrQueryMsg::GetDataRequestExecutors {
+        dr_id: "dr_id".to_string(),
+    }
+    .into();
+
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
@@ -3,6 +3,19 @@
 #[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
 pub struct Sudo {}
 
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct ExpireDataRequestsResponse {
+    /// The IDs of the data requests moved to tallying.
+    pub timed_out_drs: Vec<String>,
+    /// Whether more timed out data requests are left for a later call.
+    pub backlog:       bool,
+}
+
 impl From<Sudo> for crate::msgs::SudoMsg {
     fn from(value: Sudo) -> Self {
         super::SudoMsg::ExpireDataRequests(value).into()
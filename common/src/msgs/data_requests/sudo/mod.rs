@@ -33,6 +33,17 @@ pub struct DistributionDataProxyReward {
     pub public_key:     String,
 }
 
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct DistributionExecutorSlash {
+    /// The amount to slash from the executor's own stake. Tokens delegated to
+    /// the executor are not slashed.
+    pub amount:   U128,
+    /// The identity to slash.
+    pub identity: String,
+}
+
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
 #[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
@@ -43,6 +54,134 @@ pub enum DistributionMessage {
     ExecutorReward(DistributionExecutorReward),
     /// For rewarding a data proxy
     DataProxyReward(DistributionDataProxyReward),
+    /// For slashing a misbehaving executor, paid from their stake rather than
+    /// the escrow
+    Slash(DistributionExecutorSlash),
+    /// For funding the chain's community pool
+    CommunityPool(DistributionCommunityPool),
+    /// For paying the protocol treasury set by the owner
+    Treasury(DistributionTreasury),
+}
+
+/// What happened to a data request passed to `RemoveDataRequests`.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub enum RemoveDataRequestStatus {
+    /// The request was removed and its distributions processed.
+    Removed,
+    /// The data request ID is not a valid hex encoded hash.
+    InvalidDrId,
+    /// No data request with this ID exists.
+    NotFound,
+    /// Processing the request failed, so it was left in place.
+    Failed,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct DistributionCommunityPool {
+    /// The amount to send to the community pool.
+    pub amount: U128,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct DistributionTreasury {
+    /// The amount to send to the treasury.
+    pub amount: U128,
+}
+
+/// Why a payout could not be made. The amount is burned instead.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub enum FailedPayoutReason {
+    /// The data proxy payout address is not a valid address.
+    InvalidPayoutAddress,
+    /// The executor identity is not a valid public key.
+    InvalidIdentity,
+    /// No staker exists for the executor identity.
+    UnknownExecutor,
+    /// Updating the executor's state failed.
+    StorageError,
+    /// The owner has not set a treasury address.
+    TreasuryNotSet,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct FailedPayout {
+    /// The position of the distribution message in the request's list.
+    pub index:     u32,
+    /// The payout address or executor identity the payout was meant for, empty
+    /// for treasury payouts.
+    pub recipient: String,
+    /// The amount burned instead of paid out.
+    pub amount:    U128,
+    pub reason:    FailedPayoutReason,
+}
+
+/// Totals of how the escrow of a removed data request was distributed.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+#[derive(Default)]
+pub struct DistributionSummary {
+    /// Burned from the escrow, including failed payouts.
+    pub burned:         U128,
+    /// Paid to executors, their delegators and data proxies.
+    pub rewarded:       U128,
+    /// Added to the stake of executors below the minimum stake.
+    pub topped_up:      U128,
+    /// Returned to the poster.
+    pub refunded:       U128,
+    /// Slashed from executor stakes, which is not taken from the escrow.
+    pub slashed:        U128,
+    /// Sent to the chain's community pool.
+    pub community_pool: U128,
+    /// Paid to the protocol treasury.
+    pub treasury:       U128,
+    pub failed_payouts: Vec<FailedPayout>,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct RemovedDataRequest {
+    pub dr_id:        String,
+    pub status:       RemoveDataRequestStatus,
+    /// Only set for requests that were removed.
+    pub distribution: Option<DistributionSummary>,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct RemoveDataRequestsResponse {
+    /// The requests in the order they were processed.
+    pub requests: Vec<RemovedDataRequest>,
 }
 
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
@@ -1,12 +1,16 @@
 use std::collections::HashMap;
 
 use super::*;
+use crate::msgs::data_requests::DataResult;
 
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
 #[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
 pub struct Sudo {
     pub requests: HashMap<String, Vec<DistributionMessage>>,
+    /// Tally results to keep in the contract, keyed by data request id.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub results:  HashMap<String, DataResult>,
 }
 
 impl From<Sudo> for crate::msgs::SudoMsg {
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde_json::json;
 
-use super::sudo::*;
+use super::{sudo::*, DataResult};
 use crate::msgs;
 #[cfg(feature = "cosmwasm")]
 use crate::msgs::assert_json_deser;
@@ -32,6 +32,22 @@ fn json_remove_requests() {
                             "payout_address": "payout_address",
                             "public_key": "public_key"
                         },
+                    },
+                    {
+                        "slash": {
+                            "amount": "100",
+                            "identity": "identity"
+                        }
+                    },
+                    {
+                        "community_pool": {
+                            "amount": "100"
+                        }
+                    },
+                    {
+                        "treasury": {
+                            "amount": "100"
+                        }
                     }
                 ],
         },
@@ -51,10 +67,78 @@ fn json_remove_requests() {
                 payout_address: "payout_address".to_string(),
                 public_key:     "public_key".to_string(),
             }),
+            DistributionMessage::Slash(DistributionExecutorSlash {
+                amount:   100u128.into(),
+                identity: "identity".to_string(),
+            }),
+            DistributionMessage::CommunityPool(DistributionCommunityPool { amount: 100u128.into() }),
+            DistributionMessage::Treasury(DistributionTreasury { amount: 100u128.into() }),
         ],
     );
 
-    let msg: msgs::SudoMsg = remove_requests::Sudo { requests }.into();
+    let msg: msgs::SudoMsg = remove_requests::Sudo {
+        requests,
+        results: HashMap::new(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_remove_requests_with_results() {
+    #[cfg(not(feature = "cosmwasm"))]
+    let result = "cmVzdWx0".to_string();
+    #[cfg(feature = "cosmwasm")]
+    let result = b"result".into();
+
+    let expected_json = json!({
+    "remove_data_requests": {
+        "requests": {
+            "dr_id1": [],
+        },
+        "results": {
+            "dr_id1": {
+                "id": "id",
+                "dr_id": "dr_id1",
+                "dr_block_height": 1,
+                "version": "0.0.1",
+                "block_height": 2,
+                "block_timestamp": 3,
+                "exit_code": 0,
+                "gas_used": "100",
+                "result": "cmVzdWx0",
+                "payback_address": "",
+                "seda_payload": "",
+                "consensus": true,
+            },
+        },
+    }
+    });
+    let mut requests = HashMap::new();
+    requests.insert("dr_id1".to_string(), vec![]);
+    let mut results = HashMap::new();
+    results.insert(
+        "dr_id1".to_string(),
+        DataResult {
+            id: "id".to_string(),
+            dr_id: "dr_id1".to_string(),
+            dr_block_height: 1,
+            version: "0.0.1".to_string(),
+            block_height: 2,
+            block_timestamp: 3,
+            exit_code: 0,
+            gas_used: 100u128.into(),
+            result,
+            payback_address: "".to_string(),
+            seda_payload: "".to_string(),
+            consensus: true,
+        },
+    );
+
+    let msg: msgs::SudoMsg = remove_requests::Sudo { requests, results }.into();
     #[cfg(not(feature = "cosmwasm"))]
     assert_json_ser(msg, expected_json);
     #[cfg(feature = "cosmwasm")]
@@ -70,3 +154,95 @@ fn json_remove_timed_out_data_requests() {
     #[cfg(feature = "cosmwasm")]
     assert_json_deser(msg, expected_json);
 }
+
+#[test]
+fn json_expire_data_requests_response() {
+    let expected_json = json!({
+        "timed_out_drs": ["dr_id1", "dr_id2"],
+        "backlog": true,
+    });
+    let msg = expire_data_requests::ExpireDataRequestsResponse {
+        timed_out_drs: vec!["dr_id1".to_string(), "dr_id2".to_string()],
+        backlog:       true,
+    };
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_remove_data_requests_response() {
+    let expected_json = json!({
+        "requests": [
+            {
+                "dr_id": "dr_id1",
+                "status": "removed",
+                "distribution": {
+                    "burned": "3",
+                    "rewarded": "10",
+                    "topped_up": "1",
+                    "refunded": "5",
+                    "slashed": "0",
+                    "community_pool": "4",
+                    "treasury": "0",
+                    "failed_payouts": [
+                        {
+                            "index": 2,
+                            "recipient": "invalid",
+                            "amount": "2",
+                            "reason": "invalid_payout_address"
+                        }
+                    ]
+                }
+            },
+            {
+                "dr_id": "dr_id2",
+                "status": "not_found",
+                "distribution": null
+            },
+            {
+                "dr_id": "dr_id3",
+                "status": "invalid_dr_id",
+                "distribution": null
+            }
+        ]
+    });
+    let msg = RemoveDataRequestsResponse {
+        requests: vec![
+            RemovedDataRequest {
+                dr_id:        "dr_id1".to_string(),
+                status:       RemoveDataRequestStatus::Removed,
+                distribution: Some(DistributionSummary {
+                    burned:         3u128.into(),
+                    rewarded:       10u128.into(),
+                    topped_up:      1u128.into(),
+                    refunded:       5u128.into(),
+                    slashed:        0u128.into(),
+                    community_pool: 4u128.into(),
+                    treasury:       0u128.into(),
+                    failed_payouts: vec![FailedPayout {
+                        index:     2,
+                        recipient: "invalid".to_string(),
+                        amount:    2u128.into(),
+                        reason:    FailedPayoutReason::InvalidPayoutAddress,
+                    }],
+                }),
+            },
+            RemovedDataRequest {
+                dr_id:        "dr_id2".to_string(),
+                status:       RemoveDataRequestStatus::NotFound,
+                distribution: None,
+            },
+            RemovedDataRequest {
+                dr_id:        "dr_id3".to_string(),
+                status:       RemoveDataRequestStatus::InvalidDrId,
+                distribution: None,
+            },
+        ],
+    };
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
@@ -17,6 +17,17 @@ pub struct PostRequestResponsePayload {
     pub height: u64,
 }
 
+/// The outcome of a single data request posted through `PostDataRequests`.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub enum PostDataRequestsResult {
+    /// The data request was posted and its funds escrowed.
+    Posted(PostRequestResponsePayload),
+    /// The data request was invalid and none of the funds were used for it.
+    Skipped { reason: String },
+}
+
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Debug, PartialEq))]
 #[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
@@ -26,6 +37,62 @@ pub enum DataRequestStatus {
     Tallying,
 }
 
+/// The result of a tallied data request, mirroring
+/// `sedachain.batching.v1.DataResult`.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Deserialize, Clone, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct DataResult {
+    /// Keccak-256 hash of the data result.
+    pub id:              String,
+    /// The data request identifier.
+    pub dr_id:           String,
+    /// Height at which the data request was submitted.
+    pub dr_block_height: u64,
+    /// Semantic version string.
+    pub version:         String,
+    /// Height at which the data request was tallied.
+    pub block_height:    u64,
+    /// Unix timestamp in seconds of when the data request was tallied.
+    pub block_timestamp: u64,
+    /// Exit code of the tally program execution.
+    pub exit_code:       u32,
+    /// Gas used by the data request execution.
+    pub gas_used:        U128,
+    /// Result of the tally program execution.
+    pub result:          Bytes,
+    /// Payback address set by the relayer.
+    pub payback_address: String,
+    /// Payload set by SEDA Protocol.
+    pub seda_payload:    String,
+    /// Whether consensus was reached in the tally process.
+    pub consensus:       bool,
+}
+
+/// A contract to notify once a data request has been resolved.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Deserialize, Clone, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct DataRequestCallback {
+    /// Address of the contract that receives the result.
+    pub contract:  String,
+    /// Gas limit for executing the callback, paid for by the poster at the
+    /// data request's gas price.
+    pub gas_limit: u64,
+}
+
+/// The message a callback contract is executed with.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Deserialize, Clone, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub enum CallbackMsg {
+    DataResult {
+        dr_id:     String,
+        exit_code: u32,
+        result:    Bytes,
+    },
+}
+
 /// Represents a data request at creation time
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Deserialize, Clone, Debug, PartialEq))]
@@ -72,6 +139,15 @@ pub struct DataRequestBase {
     /// The actual gas price derived from the funds sent (funds /
     /// total_gas_limit). This may be higher than the minimum gas_price.
     pub posted_gas_price: U128,
+
+    /// Number of blocks the data request waits for commits before timing out.
+    pub commit_timeout_in_blocks: NonZero<u8>,
+    /// Number of blocks the data request waits for reveals before timing out.
+    pub reveal_timeout_in_blocks: NonZero<u8>,
+
+    /// Public keys of the only executors that may execute the data request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executors: Option<Vec<String>>,
 }
 
 impl DataRequestBase {
@@ -86,6 +162,16 @@ impl DataRequestBase {
     pub fn reveal_started(&self) -> bool {
         self.commits.len() >= self.replication_factor as usize
     }
+
+    /// Whether the executor may execute this data request, comparing the hex
+    /// public keys case-insensitively.
+    pub fn allows_executor(&self, public_key: &str) -> bool {
+        self.executors.as_ref().is_none_or(|executors| {
+            executors
+                .iter()
+                .any(|executor| executor.eq_ignore_ascii_case(public_key))
+        })
+    }
 }
 
 #[cfg(feature = "cosmwasm")]
@@ -112,9 +198,12 @@ impl DataRequestContract {
 #[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
 pub struct DataRequestResponse {
     #[serde(flatten)]
-    pub base:    DataRequestBase,
+    pub base:           DataRequestBase,
     /// Reveals submitted by executors
-    pub reveals: HashMap<String, RevealBody>,
+    pub reveals:        HashMap<String, RevealBody>,
+    /// The height at which the current stage times out, or `None` once the
+    /// data request is tallying.
+    pub timeout_height: Option<u64>,
 }
 
 impl DataRequestResponse {
@@ -182,6 +271,19 @@ pub struct PostDataRequestArgs {
     pub consensus_filter:   Bytes,
     pub gas_price:          U128,
     pub memo:               Bytes,
+
+    /// Overrides the commit timeout, clamped to the bounds in [`DrConfig`].
+    /// Not part of the data request id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_timeout_in_blocks: Option<NonZero<u8>>,
+    /// Overrides the reveal timeout, clamped to the bounds in [`DrConfig`].
+    /// Not part of the data request id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reveal_timeout_in_blocks: Option<NonZero<u8>>,
+    /// Restricts execution to these executor public keys, limited in size by
+    /// [`DrConfig`]. Part of the data request id when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executors:                Option<Vec<String>>,
 }
 
 impl TryHashSelf for PostDataRequestArgs {
@@ -229,6 +331,11 @@ impl TryHashSelf for PostDataRequestArgs {
         dr_hasher.update(consensus_filter_hash);
         dr_hasher.update(self.gas_price.to_be_bytes());
         dr_hasher.update(memo_hash);
+        // only hashed when set so existing data request ids stay the same
+        if let Some(executors) = &self.executors {
+            let executors: &[String] = executors;
+            dr_hasher.update(executors.hash());
+        }
 
         Ok(dr_hasher.finalize().into())
     }
@@ -262,6 +369,64 @@ pub struct DrConfig {
     pub payback_address_limit_in_bytes:  NonZero<u16>,
     /// The maximum size of the SEDA payload.
     pub seda_payload_limit_in_bytes:     NonZero<u16>,
+    /// The lowest commit timeout a data request may ask for.
+    #[serde(default = "default_min_commit_timeout_in_blocks")]
+    pub min_commit_timeout_in_blocks:    NonZero<u8>,
+    /// The highest commit timeout a data request may ask for.
+    #[serde(default = "default_max_commit_timeout_in_blocks")]
+    pub max_commit_timeout_in_blocks:    NonZero<u8>,
+    /// The lowest reveal timeout a data request may ask for.
+    #[serde(default = "default_min_reveal_timeout_in_blocks")]
+    pub min_reveal_timeout_in_blocks:    NonZero<u8>,
+    /// The highest reveal timeout a data request may ask for.
+    #[serde(default = "default_max_reveal_timeout_in_blocks")]
+    pub max_reveal_timeout_in_blocks:    NonZero<u8>,
+    /// Number of blocks a data result is kept in the contract after it was
+    /// stored.
+    #[serde(default = "default_data_result_retention_in_blocks")]
+    pub data_result_retention_in_blocks: NonZero<u32>,
+    /// The maximum number of executors a data request may restrict itself to.
+    #[serde(default = "default_executors_limit")]
+    pub executors_limit:                 NonZero<u16>,
+    /// The maximum number of timed out data requests expired in one call.
+    #[serde(default = "default_max_expirations_per_call")]
+    pub max_expirations_per_call:        NonZero<u16>,
+    /// The maximum number of data requests posted in one batch.
+    #[serde(default = "default_max_requests_per_post")]
+    pub max_requests_per_post:           NonZero<u16>,
+}
+
+// Defaults for configs stored before these fields existed.
+fn default_min_commit_timeout_in_blocks() -> NonZero<u8> {
+    NonZero::new(10).unwrap()
+}
+
+fn default_max_commit_timeout_in_blocks() -> NonZero<u8> {
+    NonZero::new(200).unwrap()
+}
+
+fn default_min_reveal_timeout_in_blocks() -> NonZero<u8> {
+    NonZero::new(2).unwrap()
+}
+
+fn default_max_reveal_timeout_in_blocks() -> NonZero<u8> {
+    NonZero::new(50).unwrap()
+}
+
+fn default_data_result_retention_in_blocks() -> NonZero<u32> {
+    NonZero::new(100_800).unwrap()
+}
+
+fn default_executors_limit() -> NonZero<u16> {
+    NonZero::new(100).unwrap()
+}
+
+fn default_max_expirations_per_call() -> NonZero<u16> {
+    NonZero::new(100).unwrap()
+}
+
+fn default_max_requests_per_post() -> NonZero<u16> {
+    NonZero::new(100).unwrap()
 }
 
 impl From<DrConfig> for crate::msgs::ExecuteMsg {
@@ -280,3 +445,35 @@ pub struct GetDataRequestsByStatusResponse {
     pub last_seen_index: Option<LastSeenIndexKey>,
     pub total:           u32,
 }
+
+/// Whether an executor is selected for a data request from the start, or as a
+/// backup once enough blocks passed without all reveals.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Deserialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub enum DataRequestExecutorRole {
+    Primary,
+    Backup,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Deserialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct DataRequestExecutor {
+    /// The executor's current public key, which differs from the key they were
+    /// selected under if they rotated it since.
+    pub public_key:    String,
+    pub role:          DataRequestExecutorRole,
+    /// The height from which the executor's slot is open.
+    pub eligible_from: u64,
+    /// Whether the slot is open at the current height and the executor is
+    /// still active.
+    pub eligible:      bool,
+}
+
+/// The executors of a data request, ordered by their selection priority.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(Serialize, Deserialize, Debug, PartialEq))]
+pub struct GetDataRequestExecutorsResponse {
+    pub executors: Vec<DataRequestExecutor>,
+}
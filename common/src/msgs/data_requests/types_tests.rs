@@ -24,6 +24,21 @@ fn data_request_id_vector() {
     });
 }
 
+#[test]
+fn data_request_id_includes_executors() {
+    let test_vector = include_str!("dr_id.test_vector.json");
+    let mut cases: Vec<DRIdTestCase> = serde_json::from_str(test_vector).unwrap();
+    let mut args = cases.remove(0).args;
+    let dr_id = args.try_hash().unwrap();
+
+    args.executors = Some(vec!["executor1".to_string()]);
+    let restricted_dr_id = args.try_hash().unwrap();
+    assert_ne!(dr_id, restricted_dr_id);
+
+    args.executors = Some(vec!["executor2".to_string()]);
+    assert_ne!(restricted_dr_id, args.try_hash().unwrap());
+}
+
 #[test]
 fn json_data_request_response() {
     let id = "id".to_string();
@@ -62,6 +77,9 @@ fn json_data_request_response() {
     let reveals = HashMap::new();
     let height = 1;
     let posted_gas_price = gas_price;
+    let commit_timeout_in_blocks = 50.try_into().unwrap();
+    let reveal_timeout_in_blocks = 5.try_into().unwrap();
+    let timeout_height = Some(51);
 
     let expected_json = json!({
       "id": id,
@@ -82,6 +100,9 @@ fn json_data_request_response() {
       "reveals": {},
       "height": height,
       "posted_gas_price": posted_gas_price,
+      "commit_timeout_in_blocks": commit_timeout_in_blocks,
+      "reveal_timeout_in_blocks": reveal_timeout_in_blocks,
+      "timeout_height": timeout_height,
     });
 
     let msg = DataRequestResponse {
@@ -103,8 +124,12 @@ fn json_data_request_response() {
             commits,
             height,
             posted_gas_price,
+            commit_timeout_in_blocks,
+            reveal_timeout_in_blocks,
+            executors: None,
         },
         reveals,
+        timeout_height,
     };
 
     #[cfg(not(feature = "cosmwasm"))]
@@ -207,6 +232,53 @@ fn json_post_data_request_args() {
         consensus_filter,
         gas_price,
         memo,
+        commit_timeout_in_blocks: None,
+        reveal_timeout_in_blocks: None,
+        executors: None,
+    };
+
+    assert_json_ser(msg, expected_json);
+}
+
+#[test]
+fn json_post_data_request_args_with_timeouts() {
+    let gas_price: U128 = 1u128.into();
+    #[cfg(not(feature = "cosmwasm"))]
+    let bytes = "bytes".to_string();
+    #[cfg(feature = "cosmwasm")]
+    let bytes: Bytes = "bytes".as_bytes().into();
+
+    let expected_json = json!({
+        "version": "1.0.0",
+        "exec_program_id": "exec_program_id",
+        "exec_inputs": bytes,
+        "exec_gas_limit": 1,
+        "tally_program_id": "tally_program_id",
+        "tally_inputs": bytes,
+        "tally_gas_limit": 1,
+        "replication_factor": 1,
+        "consensus_filter": bytes,
+        "gas_price": gas_price,
+        "memo": bytes,
+        "commit_timeout_in_blocks": 100,
+        "reveal_timeout_in_blocks": 10,
+    });
+
+    let msg = PostDataRequestArgs {
+        version: "1.0.0".parse().unwrap(),
+        exec_program_id: "exec_program_id".to_string(),
+        exec_inputs: bytes.clone(),
+        exec_gas_limit: 1,
+        tally_program_id: "tally_program_id".to_string(),
+        tally_inputs: bytes.clone(),
+        tally_gas_limit: 1,
+        replication_factor: 1,
+        consensus_filter: bytes.clone(),
+        gas_price,
+        memo: bytes,
+        commit_timeout_in_blocks: Some(100.try_into().unwrap()),
+        reveal_timeout_in_blocks: Some(10.try_into().unwrap()),
+        executors: None,
     };
 
     assert_json_ser(msg, expected_json);
@@ -225,6 +297,14 @@ fn json_dr_config() {
         "memo_limit_in_bytes":               512,
         "payback_address_limit_in_bytes":    128,
         "seda_payload_limit_in_bytes":       512,
+        "min_commit_timeout_in_blocks":      2,
+        "max_commit_timeout_in_blocks":      20,
+        "min_reveal_timeout_in_blocks":      5,
+        "max_reveal_timeout_in_blocks":      50,
+        "data_result_retention_in_blocks":   100_800,
+        "executors_limit":                   100,
+        "max_expirations_per_call":          100,
+        "max_requests_per_post":             100,
     });
 
     let msg = DrConfig {
@@ -238,6 +318,115 @@ fn json_dr_config() {
         memo_limit_in_bytes:             512.try_into().unwrap(),
         payback_address_limit_in_bytes:  128.try_into().unwrap(),
         seda_payload_limit_in_bytes:     512.try_into().unwrap(),
+        min_commit_timeout_in_blocks:    2.try_into().unwrap(),
+        max_commit_timeout_in_blocks:    20.try_into().unwrap(),
+        min_reveal_timeout_in_blocks:    5.try_into().unwrap(),
+        max_reveal_timeout_in_blocks:    50.try_into().unwrap(),
+        data_result_retention_in_blocks: 100_800.try_into().unwrap(),
+        executors_limit:                 100.try_into().unwrap(),
+        max_expirations_per_call:        100.try_into().unwrap(),
+        max_requests_per_post:           100.try_into().unwrap(),
+    };
+
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_post_data_requests_result() {
+    let expected_json = json!([
+        {
+            "posted": {
+                "dr_id": "dr_id",
+                "height": 1,
+            }
+        },
+        {
+            "skipped": {
+                "reason": "reason",
+            }
+        }
+    ]);
+
+    let msg = vec![
+        PostDataRequestsResult::Posted(PostRequestResponsePayload {
+            dr_id:  "dr_id".to_string(),
+            height: 1,
+        }),
+        PostDataRequestsResult::Skipped {
+            reason: "reason".to_string(),
+        },
+    ];
+
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_callback_msg() {
+    #[cfg(not(feature = "cosmwasm"))]
+    let result = "cmVzdWx0".to_string();
+    #[cfg(feature = "cosmwasm")]
+    let result: Bytes = b"result".into();
+
+    let expected_json = json!({
+        "data_result": {
+            "dr_id": "dr_id",
+            "exit_code": 1,
+            "result": "cmVzdWx0",
+        }
+    });
+
+    let msg = CallbackMsg::DataResult {
+        dr_id: "dr_id".to_string(),
+        exit_code: 1,
+        result,
+    };
+
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_get_data_request_executors_response() {
+    let expected_json = json!({
+        "executors": [
+            {
+                "public_key": "key1",
+                "role": "primary",
+                "eligible_from": 1,
+                "eligible": true,
+            },
+            {
+                "public_key": "key2",
+                "role": "backup",
+                "eligible_from": 17,
+                "eligible": false,
+            }
+        ]
+    });
+
+    let msg = GetDataRequestExecutorsResponse {
+        executors: vec![
+            DataRequestExecutor {
+                public_key:    "key1".to_string(),
+                role:          DataRequestExecutorRole::Primary,
+                eligible_from: 1,
+                eligible:      true,
+            },
+            DataRequestExecutor {
+                public_key:    "key2".to_string(),
+                role:          DataRequestExecutorRole::Backup,
+                eligible_from: 17,
+                eligible:      false,
+            },
+        ],
     };
 
     #[cfg(not(feature = "cosmwasm"))]
@@ -2,6 +2,7 @@ pub mod accept_ownership;
 pub mod add_to_allowlist;
 pub mod pause;
 pub mod remove_from_allowlist;
+pub mod set_treasury;
 pub mod transfer_ownership;
 pub mod unpause;
 
@@ -17,6 +18,8 @@ pub enum ExecuteMsg {
     RemoveFromAllowlist(remove_from_allowlist::Execute),
     Pause(pause::Execute),
     Unpause(unpause::Execute),
+    /// Set the address paid by treasury distributions.
+    SetTreasury(set_treasury::Execute),
 }
 
 impl From<ExecuteMsg> for crate::msgs::ExecuteMsg {
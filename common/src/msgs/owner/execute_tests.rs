@@ -96,3 +96,20 @@ fn json_unpause() {
     #[cfg(feature = "cosmwasm")]
     assert_json_deser(msg, expected_json);
 }
+
+#[test]
+fn json_set_treasury() {
+    let expected_json = json!({
+      "set_treasury": {
+        "treasury": "treasury"
+      }
+    });
+    let msg: msgs::ExecuteMsg = set_treasury::Execute {
+        treasury: "treasury".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
@@ -11,6 +11,8 @@ pub enum QueryMsg {
     IsPaused {},
     #[cfg_attr(feature = "cosmwasm", returns(Vec<String>))]
     GetAllowList {},
+    #[cfg_attr(feature = "cosmwasm", returns(Option<cosmwasm_std::Addr>))]
+    GetTreasury {},
 }
 
 impl From<QueryMsg> for crate::msgs::QueryMsg {
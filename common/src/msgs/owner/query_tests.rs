@@ -58,3 +58,16 @@ fn json_get_allowed() {
     #[cfg(feature = "cosmwasm")]
     assert_json_deser(msg, expected_json);
 }
+
+#[test]
+fn json_get_treasury() {
+    let expected_json = json!(
+    {
+        "get_treasury": {}
+    });
+    let msg: msgs::QueryMsg = OwnerQueryMsg::GetTreasury {}.into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
@@ -1,8 +1,15 @@
 use super::StakingConfig;
 
+pub mod delegate;
+pub mod rotate_key;
+pub mod set_commission_rate;
+pub mod set_reward_policy;
 pub mod stake;
+pub mod undelegate;
+pub mod unjail;
 pub mod unstake;
 pub mod withdraw;
+pub mod withdraw_delegation;
 
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
@@ -12,6 +19,13 @@ pub enum ExecuteMsg {
     Unstake(unstake::Execute),
     Withdraw(withdraw::Execute),
     SetStakingConfig(StakingConfig),
+    Delegate(delegate::Execute),
+    Undelegate(undelegate::Execute),
+    WithdrawDelegation(withdraw_delegation::Execute),
+    SetCommissionRate(set_commission_rate::Execute),
+    RotateKey(rotate_key::Execute),
+    Unjail(unjail::Execute),
+    SetRewardPolicy(set_reward_policy::Execute),
 }
 
 impl From<ExecuteMsg> for crate::msgs::ExecuteMsg {
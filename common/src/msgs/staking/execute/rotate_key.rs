@@ -0,0 +1,112 @@
+use crate::{error::Result, types::*};
+
+/// Moves an executor to a new public key, signed by both the old and the new
+/// key.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct Execute {
+    pub public_key:     String,
+    pub new_public_key: String,
+    /// Proof made with the old key
+    pub proof:          String,
+    /// Proof made with the new key
+    pub new_proof:      String,
+}
+
+impl Execute {
+    fn generate_hash(
+        public_key: &str,
+        new_public_key: &str,
+        chain_id: &str,
+        contract_addr: &str,
+        sequence: U128,
+    ) -> Result<Hash> {
+        Ok(crate::crypto::hash([
+            "rotate_key".as_bytes(),
+            &hex::decode(public_key)?,
+            &hex::decode(new_public_key)?,
+            chain_id.as_bytes(),
+            contract_addr.as_bytes(),
+            &sequence.to_be_bytes(),
+        ]))
+    }
+}
+
+impl VerifySelf for Execute {
+    type Extra = U128;
+
+    fn proof(&self) -> Result<Vec<u8>> {
+        Ok(hex::decode(&self.proof)?)
+    }
+
+    fn msg_hash(&self, chain_id: &str, contract_addr: &str, sequence: Self::Extra) -> Result<Hash> {
+        Self::generate_hash(
+            &self.public_key,
+            &self.new_public_key,
+            chain_id,
+            contract_addr,
+            sequence,
+        )
+    }
+}
+
+pub struct ExecuteFactory {
+    public_key:     String,
+    new_public_key: String,
+    hash:           Hash,
+}
+
+impl ExecuteFactory {
+    pub fn get_hash(&self) -> &[u8] {
+        &self.hash
+    }
+
+    pub fn create_message(self, proof: Vec<u8>, new_proof: Vec<u8>) -> crate::msgs::ExecuteMsg {
+        Execute {
+            public_key:     self.public_key,
+            new_public_key: self.new_public_key,
+            proof:          proof.to_hex(),
+            new_proof:      new_proof.to_hex(),
+        }
+        .into()
+    }
+}
+
+impl Execute {
+    pub fn factory(
+        public_key: String,
+        new_public_key: String,
+        chain_id: &str,
+        contract_addr: &str,
+        sequence: U128,
+    ) -> Result<ExecuteFactory> {
+        let hash = Self::generate_hash(&public_key, &new_public_key, chain_id, contract_addr, sequence)?;
+        Ok(ExecuteFactory {
+            public_key,
+            new_public_key,
+            hash,
+        })
+    }
+
+    /// Verifies the proofs of both the old and the new key against the
+    /// sequence of the old key.
+    pub fn verify(
+        &self,
+        public_key: &[u8],
+        new_public_key: &[u8],
+        chain_id: &str,
+        contract_addr: &str,
+        sequence: U128,
+    ) -> Result<()> {
+        self.verify_inner(public_key, chain_id, contract_addr, sequence)?;
+        let msg_hash = self.msg_hash(chain_id, contract_addr, sequence)?;
+        crate::crypto::verify_proof(new_public_key, &hex::decode(&self.new_proof)?, msg_hash)
+    }
+}
+
+impl From<Execute> for crate::msgs::ExecuteMsg {
+    fn from(value: Execute) -> Self {
+        super::ExecuteMsg::RotateKey(value).into()
+    }
+}
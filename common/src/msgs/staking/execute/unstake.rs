@@ -6,16 +6,22 @@ use crate::{error::Result, types::*};
 pub struct Execute {
     pub public_key: String,
     pub proof:      String,
+    /// Amount to unstake, unstakes everything when not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount:     Option<U128>,
 }
 
 impl Execute {
-    fn generate_hash(chain_id: &str, contract_addr: &str, sequence: U128) -> Hash {
-        crate::crypto::hash([
-            "unstake".as_bytes(),
-            chain_id.as_bytes(),
-            contract_addr.as_bytes(),
-            &sequence.to_be_bytes(),
-        ])
+    fn generate_hash(amount: Option<U128>, chain_id: &str, contract_addr: &str, sequence: U128) -> Hash {
+        // the amount is only part of the preimage when set, so full unstakes keep
+        // their original hash
+        let amount = amount.map(|amount| amount.to_be_bytes());
+        crate::crypto::hash(
+            ["unstake".as_bytes()]
+                .into_iter()
+                .chain(amount.as_ref().map(|amount| amount.as_slice()))
+                .chain([chain_id.as_bytes(), contract_addr.as_bytes(), &sequence.to_be_bytes()]),
+        )
     }
 }
 
@@ -27,12 +33,13 @@ impl VerifySelf for Execute {
     }
 
     fn msg_hash(&self, chain_id: &str, contract_addr: &str, sequence: Self::Extra) -> Result<Hash> {
-        Ok(Self::generate_hash(chain_id, contract_addr, sequence))
+        Ok(Self::generate_hash(self.amount, chain_id, contract_addr, sequence))
     }
 }
 
 pub struct ExecuteFactory {
     public_key: String,
+    amount:     Option<U128>,
     hash:       Hash,
 }
 
@@ -45,15 +52,26 @@ impl ExecuteFactory {
         Execute {
             public_key: self.public_key,
             proof:      proof.to_hex(),
+            amount:     self.amount,
         }
         .into()
     }
 }
 
 impl Execute {
-    pub fn factory(public_key: String, chain_id: &str, contract_addr: &str, sequence: U128) -> ExecuteFactory {
-        let hash = Self::generate_hash(chain_id, contract_addr, sequence);
-        ExecuteFactory { public_key, hash }
+    pub fn factory(
+        public_key: String,
+        amount: Option<U128>,
+        chain_id: &str,
+        contract_addr: &str,
+        sequence: U128,
+    ) -> ExecuteFactory {
+        let hash = Self::generate_hash(amount, chain_id, contract_addr, sequence);
+        ExecuteFactory {
+            public_key,
+            amount,
+            hash,
+        }
     }
 
     pub fn verify(&self, public_key: &[u8], chain_id: &str, contract_addr: &str, sequence: U128) -> Result<()> {
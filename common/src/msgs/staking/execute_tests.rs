@@ -62,6 +62,25 @@ fn json_unstake() {
     let msg: msgs::ExecuteMsg = unstake::Execute {
         public_key: "public".to_string(),
         proof:      "proof".to_string(),
+        amount:     None,
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+
+    let serialized = json!({
+      "unstake": {
+        "proof": "proof",
+        "public_key": "public",
+        "amount": "100",
+      }
+    });
+    let msg: msgs::ExecuteMsg = unstake::Execute {
+        public_key: "public".to_string(),
+        proof:      "proof".to_string(),
+        amount:     Some(100u128.into()),
     }
     .into();
     #[cfg(not(feature = "cosmwasm"))]
@@ -90,3 +109,153 @@ fn json_withdraw() {
     #[cfg(feature = "cosmwasm")]
     assert_json_deser(msg, serialized);
 }
+
+#[test]
+fn json_delegate() {
+    let serialized = json!({
+      "delegate": {
+        "public_key": "public",
+      }
+    });
+    let msg: msgs::ExecuteMsg = delegate::Execute {
+        public_key: "public".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+}
+
+#[test]
+fn json_undelegate() {
+    let serialized = json!({
+      "undelegate": {
+        "public_key": "public",
+        "amount": "100",
+      }
+    });
+    let msg: msgs::ExecuteMsg = undelegate::Execute {
+        public_key: "public".to_string(),
+        amount:     100u128.into(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+}
+
+#[test]
+fn json_withdraw_delegation() {
+    let serialized = json!({
+      "withdraw_delegation": {
+        "public_key": "public",
+      }
+    });
+    let msg: msgs::ExecuteMsg = withdraw_delegation::Execute {
+        public_key: "public".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+}
+
+#[test]
+fn json_set_commission_rate() {
+    let serialized = json!({
+      "set_commission_rate": {
+        "public_key": "public",
+        "proof": "proof",
+        "commission_rate_bps": 500,
+      }
+    });
+    let msg: msgs::ExecuteMsg = set_commission_rate::Execute {
+        public_key:          "public".to_string(),
+        proof:               "proof".to_string(),
+        commission_rate_bps: 500,
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+}
+
+#[test]
+fn json_set_reward_policy() {
+    let serialized = json!({
+      "set_reward_policy": {
+        "public_key": "public",
+        "proof": "proof",
+        "restake_ratio_bps": 2500,
+      }
+    });
+    let msg: msgs::ExecuteMsg = set_reward_policy::Execute {
+        public_key:        "public".to_string(),
+        proof:             "proof".to_string(),
+        restake_ratio_bps: 2500,
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+}
+
+#[test]
+fn json_rotate_key() {
+    let serialized = json!({
+      "rotate_key": {
+        "public_key": "public",
+        "new_public_key": "new_public",
+        "proof": "proof",
+        "new_proof": "new_proof",
+      }
+    });
+    let msg: msgs::ExecuteMsg = rotate_key::Execute {
+        public_key:     "public".to_string(),
+        new_public_key: "new_public".to_string(),
+        proof:          "proof".to_string(),
+        new_proof:      "new_proof".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+}
+
+#[test]
+fn unstake_hash_includes_amount() {
+    let full = unstake::Execute::factory("public".to_string(), None, "chain", "contract", 0u128.into());
+    let partial = unstake::Execute::factory(
+        "public".to_string(),
+        Some(1u128.into()),
+        "chain",
+        "contract",
+        0u128.into(),
+    );
+    assert_ne!(full.get_hash(), partial.get_hash());
+}
+
+#[test]
+fn json_unjail() {
+    let serialized = json!({
+      "unjail": {
+        "public_key": "public",
+        "proof": "proof",
+      }
+    });
+    let msg: msgs::ExecuteMsg = unjail::Execute {
+        public_key: "public".to_string(),
+        proof:      "proof".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, serialized);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, serialized);
+}
@@ -1,6 +1,9 @@
 use core::str;
 
-use crate::{error::Result, types::*};
+use crate::{
+    error::{Error, Result},
+    types::*,
+};
 
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
@@ -9,14 +12,108 @@ pub struct Query {
     pub data: Bytes,
 }
 
+/// First byte of a v2 payload. Legacy payloads start with a hex character, so
+/// the two formats can't be confused.
+const PAYLOAD_V2: u8 = 2;
+
+/// The fields of an eligibility query, decoded from either payload format.
+///
+/// The v2 payload is laid out as the version byte, the compressed public key,
+/// the data request ID, a byte flagging whether an expiry height follows, the
+/// big endian expiry height if present, and finally the proof. The legacy
+/// payload is `public_key:dr_id:proof`, all hex encoded.
+#[derive(Debug, PartialEq)]
+pub struct QueryPayload {
+    pub public_key:    [u8; 33],
+    pub dr_id:         Hash,
+    pub proof:         Vec<u8>,
+    /// The last height at which the query is valid, only in v2 payloads.
+    pub expiry_height: Option<u64>,
+}
+
+impl QueryPayload {
+    fn decode(data: &[u8]) -> Result<Self> {
+        match data.first() {
+            Some(&PAYLOAD_V2) => Self::decode_v2(&data[1..]),
+            _ => Self::decode_legacy(data),
+        }
+    }
+
+    fn decode_v2(data: &[u8]) -> Result<Self> {
+        let (public_key, data) = split_array(data).ok_or(Error::MalformedQuery("missing public key"))?;
+        let (dr_id, data) = split_array(data).ok_or(Error::MalformedQuery("missing dr_id"))?;
+        let (expiry_height, proof) = match data.split_first() {
+            Some((0, proof)) => (None, proof),
+            Some((1, data)) => {
+                let (expiry_height, proof) = split_array(data).ok_or(Error::MalformedQuery("missing expiry height"))?;
+                (Some(u64::from_be_bytes(expiry_height)), proof)
+            }
+            _ => return Err(Error::MalformedQuery("invalid expiry height flag")),
+        };
+
+        Ok(Self {
+            public_key,
+            dr_id,
+            proof: proof.to_vec(),
+            expiry_height,
+        })
+    }
+
+    fn decode_legacy(data: &[u8]) -> Result<Self> {
+        let data = str::from_utf8(data).map_err(|_| Error::MalformedQuery("payload is not valid utf-8"))?;
+        let mut parts = data.split(':');
+        let (Some(public_key), Some(dr_id), Some(proof), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(Error::MalformedQuery("expected public_key:dr_id:proof"));
+        };
+
+        Ok(Self {
+            public_key:    hex::decode(public_key)?
+                .try_into()
+                .map_err(|_| Error::MalformedQuery("invalid public key length"))?,
+            dr_id:         hex::decode(dr_id)?
+                .try_into()
+                .map_err(|_| Error::MalformedQuery("invalid dr_id length"))?,
+            proof:         hex::decode(proof)?,
+            expiry_height: None,
+        })
+    }
+
+    fn encode_v2(&self) -> Vec<u8> {
+        let mut data = vec![PAYLOAD_V2];
+        data.extend_from_slice(&self.public_key);
+        data.extend_from_slice(&self.dr_id);
+        match self.expiry_height {
+            Some(expiry_height) => {
+                data.push(1);
+                data.extend_from_slice(&expiry_height.to_be_bytes());
+            }
+            None => data.push(0),
+        }
+        data.extend_from_slice(&self.proof);
+        data
+    }
+}
+
+fn split_array<const N: usize>(data: &[u8]) -> Option<([u8; N], &[u8])> {
+    let (head, tail) = data.split_at_checked(N)?;
+    Some((head.try_into().ok()?, tail))
+}
+
 impl Query {
-    fn generate_hash(dr_id: &str, chain_id: &str, contract_addr: &str) -> Hash {
-        crate::crypto::hash([
-            "is_executor_eligible".as_bytes(),
-            dr_id.as_bytes(),
-            chain_id.as_bytes(),
-            contract_addr.as_bytes(),
-        ])
+    fn generate_hash(dr_id: &str, chain_id: &str, contract_addr: &str, expiry_height: Option<u64>) -> Hash {
+        let expiry_height = expiry_height.map(u64::to_be_bytes);
+        crate::crypto::hash(
+            [
+                "is_executor_eligible".as_bytes(),
+                dr_id.as_bytes(),
+                chain_id.as_bytes(),
+                contract_addr.as_bytes(),
+            ]
+            .into_iter()
+            .chain(expiry_height.as_ref().map(|height| height.as_slice())),
+        )
     }
 
     #[cfg(not(feature = "cosmwasm"))]
@@ -32,18 +129,14 @@ impl Query {
         Ok(self.data.to_vec())
     }
 
-    fn dr_id_hex(&self) -> Result<String> {
-        let decoded = self.decode()?;
-        Ok(str::from_utf8(&decoded[67..131]).unwrap().to_owned())
+    /// Decodes the query, in either the v2 or the legacy format.
+    pub fn payload(&self) -> Result<QueryPayload> {
+        QueryPayload::decode(&self.decode()?)
     }
 
     pub fn parts(&self) -> Result<([u8; 33], Hash, Vec<u8>)> {
-        let decoded = self.decode()?;
-        let public_key = hex::decode(&decoded[..66])?.try_into().expect("Invalid public key");
-        let dr_id = hex::decode(&decoded[67..131])?.try_into().expect("Invalid dr_id");
-        let proof = hex::decode(&decoded[132..])?;
-
-        Ok((public_key, dr_id, proof))
+        let payload = self.payload()?;
+        Ok((payload.public_key, payload.dr_id, payload.proof))
     }
 }
 
@@ -51,19 +144,34 @@ impl VerifySelf for Query {
     type Extra = ();
 
     fn proof(&self) -> Result<Vec<u8>> {
-        let decoded = self.decode()?;
-        Ok(hex::decode(&decoded[132..])?)
+        Ok(self.payload()?.proof)
     }
 
     fn msg_hash(&self, chain_id: &str, contract_addr: &str, _: Self::Extra) -> Result<Hash> {
-        Ok(Self::generate_hash(&self.dr_id_hex()?, chain_id, contract_addr))
+        let payload = self.payload()?;
+        Ok(Self::generate_hash(
+            &payload.dr_id.to_hex(),
+            chain_id,
+            contract_addr,
+            payload.expiry_height,
+        ))
     }
 }
 
+enum QueryFormat {
+    Legacy,
+    V2 {
+        public_key:    [u8; 33],
+        dr_id:         Hash,
+        expiry_height: Option<u64>,
+    },
+}
+
 pub struct QueryFactory {
     pub(crate) dr_id:      String,
     pub(crate) public_key: String,
     pub(crate) hash:       Hash,
+    format:                QueryFormat,
 }
 
 impl QueryFactory {
@@ -72,20 +180,33 @@ impl QueryFactory {
     }
 
     #[cfg(not(feature = "cosmwasm"))]
-    pub(crate) fn encode_data(data: &str) -> Bytes {
+    pub(crate) fn encode_data(data: &[u8]) -> Bytes {
         use base64::{prelude::BASE64_STANDARD, Engine};
 
         BASE64_STANDARD.encode(data)
     }
 
     #[cfg(feature = "cosmwasm")]
-    pub(crate) fn encode_data(data: &str) -> Bytes {
+    pub(crate) fn encode_data(data: &[u8]) -> Bytes {
         use cosmwasm_std::Binary;
-        Binary::new(data.as_bytes().to_vec())
+        Binary::new(data.to_vec())
     }
 
     pub fn create_message(self, proof: Vec<u8>) -> (crate::msgs::QueryMsg, Bytes) {
-        let data = format!("{}:{}:{}", self.public_key, self.dr_id, proof.to_hex());
+        let data = match self.format {
+            QueryFormat::Legacy => format!("{}:{}:{}", self.public_key, self.dr_id, proof.to_hex()).into_bytes(),
+            QueryFormat::V2 {
+                public_key,
+                dr_id,
+                expiry_height,
+            } => QueryPayload {
+                public_key,
+                dr_id,
+                proof,
+                expiry_height,
+            }
+            .encode_v2(),
+        };
         let base64_data = Self::encode_data(&data);
 
         (
@@ -99,15 +220,44 @@ impl QueryFactory {
 }
 
 impl Query {
+    /// Creates a query in the legacy format.
     pub fn factory(public_key: String, dr_id: String, chain_id: &str, contract_addr: &str) -> QueryFactory {
-        let hash = Self::generate_hash(&dr_id, chain_id, contract_addr);
+        let hash = Self::generate_hash(&dr_id, chain_id, contract_addr, None);
         QueryFactory {
             dr_id,
             public_key,
             hash,
+            format: QueryFormat::Legacy,
         }
     }
 
+    /// Creates a query in the v2 format, which is no longer valid after
+    /// `expiry_height` if one is given.
+    pub fn factory_v2(
+        public_key: String,
+        dr_id: String,
+        expiry_height: Option<u64>,
+        chain_id: &str,
+        contract_addr: &str,
+    ) -> Result<QueryFactory> {
+        let format = QueryFormat::V2 {
+            public_key: hex::decode(&public_key)?
+                .try_into()
+                .map_err(|_| Error::MalformedQuery("invalid public key length"))?,
+            dr_id: hex::decode(&dr_id)?
+                .try_into()
+                .map_err(|_| Error::MalformedQuery("invalid dr_id length"))?,
+            expiry_height,
+        };
+        let hash = Self::generate_hash(&dr_id, chain_id, contract_addr, expiry_height);
+        Ok(QueryFactory {
+            dr_id,
+            public_key,
+            hash,
+            format,
+        })
+    }
+
     pub fn verify(&self, public_key: &[u8], chain_id: &str, contract_addr: &str) -> Result<()> {
         self.verify_inner(public_key, chain_id, contract_addr, ())
     }
@@ -0,0 +1,84 @@
+use crate::{error::Result, types::*};
+
+/// Checks an executor's eligibility for many data requests at once, signed with
+/// a single proof over all of them.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(not(feature = "cosmwasm"), derive(serde::Serialize, Debug, PartialEq))]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct Query {
+    pub public_key: String,
+    pub dr_ids:     Vec<String>,
+    pub proof:      String,
+}
+
+impl Query {
+    fn generate_hash(dr_ids: &[String], chain_id: &str, contract_addr: &str) -> Hash {
+        // every ID is hashed on its own, so a list cannot be reinterpreted by
+        // moving characters between neighbouring IDs
+        let dr_id_hashes = dr_ids
+            .iter()
+            .map(|dr_id| crate::crypto::hash([dr_id.as_bytes()]))
+            .collect::<Vec<_>>();
+        let dr_ids_hash = crate::crypto::hash(dr_id_hashes.iter().map(|hash| hash.as_slice()));
+        crate::crypto::hash([
+            "is_executor_eligible_batch".as_bytes(),
+            &dr_ids_hash,
+            chain_id.as_bytes(),
+            contract_addr.as_bytes(),
+        ])
+    }
+}
+
+impl VerifySelf for Query {
+    type Extra = ();
+
+    fn proof(&self) -> Result<Vec<u8>> {
+        Ok(hex::decode(&self.proof)?)
+    }
+
+    fn msg_hash(&self, chain_id: &str, contract_addr: &str, _: Self::Extra) -> Result<Hash> {
+        Ok(Self::generate_hash(&self.dr_ids, chain_id, contract_addr))
+    }
+}
+
+pub struct QueryFactory {
+    public_key: String,
+    dr_ids:     Vec<String>,
+    hash:       Hash,
+}
+
+impl QueryFactory {
+    pub fn get_hash(&self) -> &[u8] {
+        &self.hash
+    }
+
+    pub fn create_message(self, proof: Vec<u8>) -> crate::msgs::QueryMsg {
+        Query {
+            public_key: self.public_key,
+            dr_ids:     self.dr_ids,
+            proof:      proof.to_hex(),
+        }
+        .into()
+    }
+}
+
+impl Query {
+    pub fn factory(public_key: String, dr_ids: Vec<String>, chain_id: &str, contract_addr: &str) -> QueryFactory {
+        let hash = Self::generate_hash(&dr_ids, chain_id, contract_addr);
+        QueryFactory {
+            public_key,
+            dr_ids,
+            hash,
+        }
+    }
+
+    pub fn verify(&self, public_key: &[u8], chain_id: &str, contract_addr: &str) -> Result<()> {
+        self.verify_inner(public_key, chain_id, contract_addr, ())
+    }
+}
+
+impl From<Query> for crate::msgs::QueryMsg {
+    fn from(value: Query) -> Self {
+        super::QueryMsg::GetExecutorEligibilityBatch(value).into()
+    }
+}
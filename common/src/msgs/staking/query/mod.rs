@@ -1,4 +1,5 @@
 pub mod is_executor_eligible;
+pub mod is_executor_eligible_batch;
 
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(feature = "cosmwasm", derive(cosmwasm_schema::QueryResponses))]
@@ -17,10 +18,29 @@ pub enum QueryMsg {
     IsExecutorEligible(is_executor_eligible::Query),
     #[cfg_attr(feature = "cosmwasm", returns(crate::msgs::staking::GetExecutorEligibilityResponse))]
     GetExecutorEligibility(is_executor_eligible::Query),
+    #[cfg_attr(
+        feature = "cosmwasm",
+        returns(crate::msgs::staking::GetExecutorEligibilityBatchResponse)
+    )]
+    GetExecutorEligibilityBatch(is_executor_eligible_batch::Query),
     #[cfg_attr(feature = "cosmwasm", returns(crate::msgs::staking::StakingConfig))]
     GetStakingConfig {},
     #[cfg_attr(feature = "cosmwasm", returns(crate::msgs::staking::GetExecutorsResponse))]
     GetExecutors { offset: u32, limit: u32 },
+    #[cfg_attr(feature = "cosmwasm", returns(Option<crate::msgs::staking::Delegation>))]
+    GetDelegation { delegator: String, public_key: String },
+    #[cfg_attr(feature = "cosmwasm", returns(crate::msgs::staking::GetDelegationsResponse))]
+    GetDelegations {
+        public_key:  String,
+        start_after: Option<String>,
+        limit:       u32,
+    },
+    #[cfg_attr(feature = "cosmwasm", returns(crate::msgs::staking::GetExecutorStatsResponse))]
+    GetExecutorStats {
+        sort_by: crate::msgs::staking::ExecutorStatsSortBy,
+        offset:  u32,
+        limit:   u32,
+    },
 }
 
 impl From<QueryMsg> for crate::msgs::QueryMsg {
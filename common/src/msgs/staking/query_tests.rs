@@ -5,7 +5,8 @@ use k256::{
 use serde_json::json;
 
 use super::query::{
-    is_executor_eligible::{self, Query, QueryFactory},
+    is_executor_eligible::{self, Query, QueryFactory, QueryPayload},
+    is_executor_eligible_batch,
     QueryMsg as StakingQueryMsg,
 };
 #[cfg(feature = "cosmwasm")]
@@ -113,6 +114,150 @@ fn json_get_staking_config() {
     assert_json_deser(msg, expected_json);
 }
 
+#[test]
+fn json_get_delegation() {
+    let expected_json = json!({
+      "get_delegation": {
+        "delegator": "delegator",
+        "public_key": "public_key",
+      }
+    });
+    let msg: msgs::QueryMsg = StakingQueryMsg::GetDelegation {
+        delegator:  "delegator".to_string(),
+        public_key: "public_key".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_get_delegations() {
+    let expected_json = json!({
+      "get_delegations": {
+        "public_key": "public_key",
+        "start_after": "delegator",
+        "limit": 10,
+      }
+    });
+    let msg: msgs::QueryMsg = StakingQueryMsg::GetDelegations {
+        public_key:  "public_key".to_string(),
+        start_after: Some("delegator".to_string()),
+        limit:       10,
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_get_executor_stats() {
+    let expected_json = json!({
+      "get_executor_stats": {
+        "sort_by": "missed_reveals",
+        "offset": 0,
+        "limit": 10,
+      }
+    });
+    let msg: msgs::QueryMsg = StakingQueryMsg::GetExecutorStats {
+        sort_by: msgs::staking::ExecutorStatsSortBy::MissedReveals,
+        offset:  0,
+        limit:   10,
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_get_executor_eligibility_batch() {
+    let expected_json = json!({
+      "get_executor_eligibility_batch": {
+        "public_key": "public_key",
+        "dr_ids": ["dr_id1", "dr_id2"],
+        "proof": "proof",
+      }
+    });
+    let msg: msgs::QueryMsg = is_executor_eligible_batch::Query {
+        public_key: "public_key".to_string(),
+        dr_ids:     vec!["dr_id1".to_string(), "dr_id2".to_string()],
+        proof:      "proof".to_string(),
+    }
+    .into();
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(msg, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(msg, expected_json);
+}
+
+#[test]
+fn json_get_executor_eligibility_batch_response() {
+    let expected_json = json!({
+      "eligibilities": [
+        { "dr_id": "dr_id1", "status": "eligible" },
+        { "dr_id": "dr_id2", "status": "data_request_not_found" },
+      ],
+      "block_height": 12345
+    });
+    let response = msgs::staking::GetExecutorEligibilityBatchResponse {
+        eligibilities: vec![
+            msgs::staking::DataRequestEligibility {
+                dr_id:  "dr_id1".to_string(),
+                status: msgs::staking::ExecutorEligibilityStatus::Eligible,
+            },
+            msgs::staking::DataRequestEligibility {
+                dr_id:  "dr_id2".to_string(),
+                status: msgs::staking::ExecutorEligibilityStatus::DataRequestNotFound,
+            },
+        ],
+        block_height:  12345,
+    };
+    #[cfg(not(feature = "cosmwasm"))]
+    assert_json_ser(response, expected_json);
+    #[cfg(feature = "cosmwasm")]
+    assert_json_deser(response, expected_json);
+}
+
+#[test]
+fn executor_eligibility_batch_proof_covers_every_dr_id() {
+    let (sk, pk) = new_public_key();
+    let dr_ids = vec!["dr_id1".hash().to_hex(), "dr_id2".hash().to_hex()];
+
+    let factory = is_executor_eligible_batch::Query::factory(hex::encode(pk), dr_ids.clone(), "foo", "bar");
+    let proof = prove(sk.to_bytes().as_slice(), factory.get_hash());
+    let mut query = is_executor_eligible_batch::Query {
+        public_key: hex::encode(pk),
+        dr_ids,
+        proof: proof.to_hex(),
+    };
+    query.verify(&pk, "foo", "bar").unwrap();
+
+    // the proof does not carry over to a different list
+    query.dr_ids.pop();
+    assert!(query.verify(&pk, "foo", "bar").is_err());
+
+    // nor to the same characters split into different IDs
+    let factory = is_executor_eligible_batch::Query::factory(
+        hex::encode(pk),
+        vec!["ab".to_string(), "cd".to_string()],
+        "foo",
+        "bar",
+    );
+    let proof = prove(sk.to_bytes().as_slice(), factory.get_hash());
+    let query = is_executor_eligible_batch::Query {
+        public_key: hex::encode(pk),
+        dr_ids:     vec!["abcd".to_string()],
+        proof:      proof.to_hex(),
+    };
+    assert!(query.verify(&pk, "foo", "bar").is_err());
+}
+
 fn new_public_key() -> (SigningKey, [u8; 33]) {
     let signing_key = SigningKey::random(&mut OsRng);
     let verifying_key = VerifyingKey::from(&signing_key);
@@ -130,7 +275,7 @@ impl QueryFactory {
         let data = format!("{}:{}:{}", self.public_key, self.dr_id, proof.to_hex());
 
         Query {
-            data: Self::encode_data(&data),
+            data: Self::encode_data(data.as_bytes()),
         }
     }
 }
@@ -153,6 +298,69 @@ fn is_executor_eligible_decode_correctly() {
     assert_eq!(proof, decoded_proof);
 }
 
+#[test]
+fn is_executor_eligible_v2_decode_correctly() {
+    let (sk, pk) = new_public_key();
+    let dr_id = "dr_id".hash();
+
+    let factory =
+        is_executor_eligible::Query::factory_v2(hex::encode(pk), dr_id.to_hex(), Some(42), "foo", "bar").unwrap();
+    let proof = prove(sk.to_bytes().as_slice(), factory.get_hash());
+    let (_, data) = factory.create_message(proof.clone());
+    let query = Query { data };
+
+    assert_eq!(
+        QueryPayload {
+            public_key: pk,
+            dr_id,
+            proof,
+            expiry_height: Some(42),
+        },
+        query.payload().unwrap()
+    );
+    query.verify(&pk, "foo", "bar").unwrap();
+}
+
+#[test]
+fn is_executor_eligible_v2_signs_expiry_height() {
+    let (sk, pk) = new_public_key();
+    let dr_id = "dr_id".hash().to_hex();
+
+    let factory = is_executor_eligible::Query::factory_v2(hex::encode(pk), dr_id.clone(), None, "foo", "bar").unwrap();
+    let proof = prove(sk.to_bytes().as_slice(), factory.get_hash());
+
+    // a proof without an expiry height can't be reused with one
+    let factory = is_executor_eligible::Query::factory_v2(hex::encode(pk), dr_id, Some(42), "foo", "bar").unwrap();
+    let (_, data) = factory.create_message(proof);
+    assert!(Query { data }.verify(&pk, "foo", "bar").is_err());
+}
+
+#[test]
+fn is_executor_eligible_malformed_payloads() {
+    let (_, pk) = new_public_key();
+    let dr_id = "dr_id".hash().to_hex();
+
+    let malformed = [
+        vec![],
+        vec![2],
+        vec![2; 66],
+        [vec![2], pk.to_vec(), "dr_id".hash().to_vec(), vec![1, 0, 0]].concat(),
+        [vec![2], pk.to_vec(), "dr_id".hash().to_vec(), vec![7]].concat(),
+        format!("{}:{}", hex::encode(pk), dr_id).into_bytes(),
+        format!("{}:{}:00:00", hex::encode(pk), dr_id).into_bytes(),
+        format!("00:{dr_id}:00").into_bytes(),
+        format!("{}:zz:00", hex::encode(pk)).into_bytes(),
+        vec![0xff, 0xfe],
+    ];
+    for data in malformed {
+        let query = Query {
+            data: QueryFactory::encode_data(&data),
+        };
+        assert!(query.payload().is_err(), "{data:?} should be malformed");
+        assert!(query.verify(&pk, "foo", "bar").is_err());
+    }
+}
+
 #[test]
 fn json_query_executors() {
     let expected_json = json!({
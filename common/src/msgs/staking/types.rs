@@ -11,6 +11,38 @@ pub struct Staker {
     pub memo:                      Option<Bytes>,
     pub tokens_staked:             U128,
     pub tokens_pending_withdrawal: U128,
+    /// Unstaked tokens that become withdrawable at their release height
+    #[serde(default)]
+    pub unbonding:                 Vec<UnbondingEntry>,
+    /// Tokens delegated to this executor by third parties
+    #[serde(default)]
+    pub tokens_delegated:          U128,
+    /// Share of the delegators' rewards kept by the executor, in basis points
+    #[serde(default)]
+    pub commission_rate_bps:       u16,
+    /// Reveals missed since the executor was last jailed or revealed
+    #[serde(default)]
+    pub missed_reveals:            u32,
+    /// Height until which the executor is jailed, they stay jailed until they
+    /// unjail themselves
+    #[serde(default)]
+    pub jailed_until:              Option<u64>,
+    /// Share of the executor's rewards added to their stake instead of pending
+    /// withdrawal, in basis points
+    #[serde(default)]
+    pub restake_ratio_bps:         u16,
+}
+
+/// An amount of unstaked tokens locked until `release_height`
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct UnbondingEntry {
+    pub amount:         U128,
+    pub release_height: u64,
 }
 
 /// Governance-controlled staking configuration parameters
@@ -23,9 +55,39 @@ pub struct Staker {
 pub struct StakingConfig {
     /// Minimum amount of SEDA tokens required to register as a data request
     /// executor
-    pub minimum_stake:     U128,
+    pub minimum_stake:                 U128,
     /// Whether the allowlist is enabled
-    pub allowlist_enabled: bool,
+    pub allowlist_enabled:             bool,
+    /// Number of blocks unstaked tokens stay locked before they can be
+    /// withdrawn
+    #[serde(default)]
+    pub unbonding_period_in_blocks:    u64,
+    /// Number of missed reveals after which an executor is jailed, 0 disables
+    /// jailing
+    #[serde(default)]
+    pub missed_reveals_jail_threshold: u32,
+    /// Number of blocks a jailed executor has to wait before they can unjail
+    #[serde(default)]
+    pub jail_duration_in_blocks:       u64,
+    /// How executors are ordered when selecting them for a data request
+    #[serde(default)]
+    pub selection_mode:                SelectionMode,
+}
+
+/// How executors are ordered when selecting them for a data request
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+#[derive(Default)]
+pub enum SelectionMode {
+    /// Every executor is equally likely to be selected
+    #[default]
+    Uniform,
+    /// Executors are selected with a probability proportional to their stake
+    StakeWeighted,
 }
 
 impl From<StakingConfig> for crate::msgs::ExecuteMsg {
@@ -58,6 +120,33 @@ pub struct Executor {
     pub tokens_pending_withdrawal: U128,
 }
 
+/// Tokens a delegator has delegated to an executor
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct Delegation {
+    pub delegator:       String,
+    pub public_key:      String,
+    pub amount:          U128,
+    /// Rewards earned so far that have not been withdrawn
+    pub accrued_rewards: U128,
+    /// Undelegated tokens that become withdrawable at their release height
+    pub unbonding:       Vec<UnbondingEntry>,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct GetDelegationsResponse {
+    pub delegations: Vec<Delegation>,
+}
+
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(
     not(feature = "cosmwasm"),
@@ -68,6 +157,57 @@ pub struct GetExecutorsResponse {
     pub executors: Vec<Executor>,
 }
 
+/// Counters of an executor's participation in data requests
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct ExecutorStats {
+    pub public_key:         String,
+    /// Data requests the executor committed on
+    pub commits:            u64,
+    /// Data requests the executor revealed on
+    pub reveals:            u64,
+    /// Data requests that timed out while revealing without the executor's
+    /// reveal
+    pub missed_reveals:     u64,
+    /// Data requests the executor committed on that timed out
+    pub timeouts:           u64,
+    /// Total rewards paid out for the executor's work, including the
+    /// delegators' share
+    pub rewards_received:   U128,
+    /// Height of the executor's last commit or reveal
+    pub last_active_height: u64,
+}
+
+/// The metric executor stats are sorted by, in descending order
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub enum ExecutorStatsSortBy {
+    Commits,
+    Reveals,
+    MissedReveals,
+    Timeouts,
+    RewardsReceived,
+    LastActiveHeight,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct GetExecutorStatsResponse {
+    pub stats: Vec<ExecutorStats>,
+}
+
 /// Response for the `GetExecutorEligibility` query
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(
@@ -80,6 +220,30 @@ pub struct GetExecutorEligibilityResponse {
     pub block_height: u64,
 }
 
+/// Response to a batch eligibility query, with a status for every requested
+/// data request in the order they were requested.
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct GetExecutorEligibilityBatchResponse {
+    pub eligibilities: Vec<DataRequestEligibility>,
+    pub block_height:  u64,
+}
+
+#[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
+#[cfg_attr(
+    not(feature = "cosmwasm"),
+    derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)
+)]
+#[cfg_attr(not(feature = "cosmwasm"), serde(rename_all = "snake_case"))]
+pub struct DataRequestEligibility {
+    pub dr_id:  String,
+    pub status: ExecutorEligibilityStatus,
+}
+
 /// Status codes for executor eligibility
 #[cfg_attr(feature = "cosmwasm", cosmwasm_schema::cw_serde)]
 #[cfg_attr(
@@ -99,4 +263,6 @@ pub enum ExecutorEligibilityStatus {
     NotStaker           = 3,
     /// Invalid signature
     InvalidSignature    = 4,
+    /// The query could not be decoded
+    MalformedRequest    = 5,
 }
@@ -1,6 +1,6 @@
 use serde_json::json;
 
-use super::{Staker, StakingConfig};
+use super::{SelectionMode, Staker, StakingConfig, UnbondingEntry};
 use crate::msgs::*;
 
 #[test]
@@ -9,11 +9,23 @@ fn json_staker() {
       "memo": null,
       "tokens_staked": "100",
       "tokens_pending_withdrawal": "0",
+      "unbonding": [],
+      "tokens_delegated": "0",
+      "commission_rate_bps": 0,
+      "missed_reveals": 0,
+      "jailed_until": null,
+      "restake_ratio_bps": 0,
     });
     let staker_with_no_memo = Staker {
         memo:                      None,
         tokens_staked:             100u128.into(),
         tokens_pending_withdrawal: 0u128.into(),
+        unbonding:                 vec![],
+        tokens_delegated:          0u128.into(),
+        commission_rate_bps:       0,
+        missed_reveals:            0,
+        jailed_until:              None,
+        restake_ratio_bps:         0,
     };
 
     assert_json_deser(staker_with_no_memo, serialized_with_no_memo);
@@ -27,14 +39,57 @@ fn json_staker() {
       "memo": memo,
       "tokens_staked": "100",
       "tokens_pending_withdrawal": "0",
+      "unbonding": [],
+      "tokens_delegated": "0",
+      "commission_rate_bps": 0,
+      "missed_reveals": 0,
+      "jailed_until": null,
+      "restake_ratio_bps": 0,
     });
     let staker_with_memo = Staker {
         memo:                      Some(memo),
         tokens_staked:             100u128.into(),
         tokens_pending_withdrawal: 0u128.into(),
+        unbonding:                 vec![],
+        tokens_delegated:          0u128.into(),
+        commission_rate_bps:       0,
+        missed_reveals:            0,
+        jailed_until:              None,
+        restake_ratio_bps:         0,
     };
 
     assert_json_deser(staker_with_memo, serialized_with_memo);
+
+    let serialized_with_unbonding = json!({
+      "memo": null,
+      "tokens_staked": "0",
+      "tokens_pending_withdrawal": "0",
+      "unbonding": [{
+        "amount": "100",
+        "release_height": 42,
+      }],
+      "tokens_delegated": "0",
+      "commission_rate_bps": 0,
+      "missed_reveals": 0,
+      "jailed_until": null,
+      "restake_ratio_bps": 0,
+    });
+    let staker_with_unbonding = Staker {
+        memo:                      None,
+        tokens_staked:             0u128.into(),
+        tokens_pending_withdrawal: 0u128.into(),
+        unbonding:                 vec![UnbondingEntry {
+            amount:         100u128.into(),
+            release_height: 42,
+        }],
+        tokens_delegated:          0u128.into(),
+        commission_rate_bps:       0,
+        missed_reveals:            0,
+        jailed_until:              None,
+        restake_ratio_bps:         0,
+    };
+
+    assert_json_deser(staker_with_unbonding, serialized_with_unbonding);
 }
 
 #[test]
@@ -42,10 +97,18 @@ fn json_staking_config() {
     let expected_json = json!({
       "minimum_stake": "100",
       "allowlist_enabled": true,
+      "unbonding_period_in_blocks": 100,
+      "missed_reveals_jail_threshold": 5,
+      "jail_duration_in_blocks": 1000,
+      "selection_mode": "uniform",
     });
     let msg = StakingConfig {
-        minimum_stake:     100u128.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 100u128.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    100,
+        missed_reveals_jail_threshold: 5,
+        jail_duration_in_blocks:       1000,
+        selection_mode:                SelectionMode::Uniform,
     };
 
     assert_json_deser(msg, expected_json);
@@ -74,6 +137,12 @@ fn json_staker_and_seq() {
         "memo": memo,
         "tokens_staked": "100",
         "tokens_pending_withdrawal": "0",
+        "unbonding": [],
+        "tokens_delegated": "0",
+        "commission_rate_bps": 0,
+        "missed_reveals": 0,
+        "jailed_until": null,
+        "restake_ratio_bps": 0,
       },
       "seq": "100",
     });
@@ -82,6 +151,12 @@ fn json_staker_and_seq() {
             memo:                      Some(memo),
             tokens_staked:             100u128.into(),
             tokens_pending_withdrawal: 0u128.into(),
+            unbonding:                 vec![],
+            tokens_delegated:          0u128.into(),
+            commission_rate_bps:       0,
+            missed_reveals:            0,
+            jailed_until:              None,
+            restake_ratio_bps:         0,
         }),
         seq:    100u128.into(),
     };
@@ -6,6 +6,17 @@ use seda_common::msgs::data_requests::DrConfig;
 // 10_000 SEDA
 pub const INITIAL_MINIMUM_STAKE: Uint128 = Uint128::new(10_000_000_000_000_000_000_000);
 
+// 100% in basis points
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+// ~7 days at 6s blocks
+pub const INITIAL_UNBONDING_PERIOD_IN_BLOCKS: u64 = 100_800;
+
+pub const INITIAL_MISSED_REVEALS_JAIL_THRESHOLD: u32 = 5;
+
+// ~1 day at 6s blocks
+pub const INITIAL_JAIL_DURATION_IN_BLOCKS: u64 = 14_400;
+
 pub const INITIAL_DR_CONFIG: DrConfig = DrConfig {
     commit_timeout_in_blocks:        NonZero::new(50).unwrap(),
     reveal_timeout_in_blocks:        NonZero::new(5).unwrap(),
@@ -24,4 +35,13 @@ pub const INITIAL_DR_CONFIG: DrConfig = DrConfig {
     payback_address_limit_in_bytes:  NonZero::new(128).unwrap(),
     // 512 B
     seda_payload_limit_in_bytes:     NonZero::new(512).unwrap(),
+    min_commit_timeout_in_blocks:    NonZero::new(10).unwrap(),
+    max_commit_timeout_in_blocks:    NonZero::new(200).unwrap(),
+    min_reveal_timeout_in_blocks:    NonZero::new(2).unwrap(),
+    max_reveal_timeout_in_blocks:    NonZero::new(50).unwrap(),
+    // ~7 days at 6s blocks
+    data_result_retention_in_blocks: NonZero::new(100_800).unwrap(),
+    executors_limit:                 NonZero::new(100).unwrap(),
+    max_expirations_per_call:        NonZero::new(100).unwrap(),
+    max_requests_per_post:           NonZero::new(100).unwrap(),
 };
@@ -1,16 +1,20 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
-use cosmwasm_std::{Empty, Event};
+use cosmwasm_std::{Empty, Event, Reply};
 use cw2::{get_contract_version, set_contract_version};
 use seda_common::msgs::*;
 use semver::Version;
-use staking::StakingConfig;
+use staking::{SelectionMode, StakingConfig};
 
 use crate::{
     consts::*,
     error::ContractError,
     msgs::{
-        data_requests::{execute::dr_events::create_dr_config_event, state::DR_CONFIG},
+        data_requests::{
+            callback::{handle_callback_reply, CALLBACK_REPLY_ID},
+            execute::{dr_events::create_dr_config_event, set_dr_config::validate_dr_config},
+            state::{backfill_request_timeouts, DR_CONFIG},
+        },
         owner::state::{OWNER, PENDING_OWNER},
         staking::{
             execute::staking_events::create_staking_config_event,
@@ -50,8 +54,12 @@ pub fn instantiate(
     PAUSED.save(deps.storage, &false)?;
 
     let init_staking_config = msg.staking_config.unwrap_or(StakingConfig {
-        minimum_stake:     INITIAL_MINIMUM_STAKE,
-        allowlist_enabled: true,
+        minimum_stake:                 INITIAL_MINIMUM_STAKE,
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    INITIAL_UNBONDING_PERIOD_IN_BLOCKS,
+        missed_reveals_jail_threshold: INITIAL_MISSED_REVEALS_JAIL_THRESHOLD,
+        jail_duration_in_blocks:       INITIAL_JAIL_DURATION_IN_BLOCKS,
+        selection_mode:                SelectionMode::Uniform,
     });
 
     if init_staking_config.minimum_stake.is_zero() {
@@ -61,6 +69,7 @@ pub fn instantiate(
     STAKING_CONFIG.save(deps.storage, &init_staking_config)?;
 
     let init_dr_config = msg.dr_config.unwrap_or(INITIAL_DR_CONFIG);
+    validate_dr_config(&init_dr_config)?;
     DR_CONFIG.save(deps.storage, &init_dr_config)?;
 
     STAKERS.initialize(deps.storage)?;
@@ -90,6 +99,14 @@ pub fn sudo(deps: DepsMut, env: Env, sudo: SudoMsg) -> Result<Response, Contract
     sudo.sudo(deps, env)
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
+    match reply.id {
+        CALLBACK_REPLY_ID => handle_callback_reply(reply),
+        id => Err(ContractError::UnknownReplyId(id)),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     msg.query(deps, env)
@@ -124,6 +141,14 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         return Err(ContractError::NoMigrationNeeded);
     }
 
+    // rebuilding the active executor index is idempotent, and also fills it for
+    // contracts migrating from before it existed
+    STAKERS.rebuild_active(deps.storage)?;
+
+    // data requests posted before requests had their own timeouts keep the ones
+    // from the config they were posted under
+    backfill_request_timeouts(deps.storage)?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::new()
@@ -141,10 +166,26 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
 mod tests {
     use std::collections::HashMap;
 
+    use cosmwasm_std::Storage;
     use cw_multi_test::{ContractWrapper, Executor};
+    use cw_storage_plus::Map;
+    use seda_common::{
+        msgs::data_requests::RevealBody,
+        types::{Hash, HashSelf},
+    };
 
     use super::*;
-    use crate::TestInfo;
+    use crate::{msgs::data_requests::test_helpers, types::FromHexStr, TestInfo};
+
+    /// Removes fields from a stored value, to get what an older version of the
+    /// contract stored.
+    fn remove_stored_fields(store: &mut dyn Storage, key: &[u8], fields: &[&str]) {
+        let mut value: serde_json::Value = serde_json::from_slice(&store.get(key).unwrap()).unwrap();
+        for field in fields {
+            value.as_object_mut().unwrap().remove(*field).unwrap();
+        }
+        store.set(key, &serde_json::to_vec(&value).unwrap());
+    }
 
     #[test]
     fn migrate_downgrade() {
@@ -248,4 +289,83 @@ mod tests {
             Err(e) => panic!("Migrate failed: {}", e),
         }
     }
+
+    #[test]
+    fn migrate_loads_pre_upgrade_state() {
+        let test_info = TestInfo::init_with_version(Some("1.0.5"));
+        let alice = test_info.new_account("alice", 22);
+        let executor = test_info.new_executor("exec", 22, 1);
+        let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+        let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+        // store the config and the request without the fields added since
+        {
+            let mut app = test_info.app_mut();
+            let mut store = app.contract_storage_mut(&test_info.contract_addr());
+            remove_stored_fields(
+                &mut *store,
+                b"dr_config",
+                &[
+                    "min_commit_timeout_in_blocks",
+                    "max_commit_timeout_in_blocks",
+                    "min_reveal_timeout_in_blocks",
+                    "max_reveal_timeout_in_blocks",
+                    "data_result_retention_in_blocks",
+                    "executors_limit",
+                    "max_expirations_per_call",
+                    "max_requests_per_post",
+                ],
+            );
+            let reqs: Map<&Hash, serde_json::Value> = Map::new("data_request_pool_reqs");
+            remove_stored_fields(
+                &mut *store,
+                &reqs.key(&Hash::from_hex_str(&dr_id).unwrap()),
+                &["commit_timeout_in_blocks", "reveal_timeout_in_blocks"],
+            );
+        }
+
+        let contract = Box::new(
+            ContractWrapper::new(execute, instantiate, query)
+                .with_sudo(sudo)
+                .with_migrate_empty(migrate),
+        );
+        let new_code_id = test_info
+            .app_mut()
+            .store_code_with_creator(test_info.creator().addr(), contract);
+        test_info
+            .app_mut()
+            .migrate_contract(
+                test_info.creator().addr(),
+                test_info.contract_addr(),
+                &Empty {},
+                new_code_id,
+            )
+            .unwrap();
+
+        let config = alice.get_dr_config();
+        assert_eq!(10, config.min_commit_timeout_in_blocks.get());
+        assert_eq!(200, config.max_commit_timeout_in_blocks.get());
+        assert_eq!(2, config.min_reveal_timeout_in_blocks.get());
+        assert_eq!(50, config.max_reveal_timeout_in_blocks.get());
+        assert_eq!(100_800, config.data_result_retention_in_blocks.get());
+        assert_eq!(100, config.executors_limit.get());
+        assert_eq!(100, config.max_expirations_per_call.get());
+        assert_eq!(100, config.max_requests_per_post.get());
+
+        // the request keeps the timeouts of the config it was posted under
+        let dr = alice.get_data_request(&dr_id).unwrap();
+        assert_eq!(config.commit_timeout_in_blocks, dr.base.commit_timeout_in_blocks);
+        assert_eq!(config.reveal_timeout_in_blocks, dr.base.reveal_timeout_in_blocks);
+
+        // and can still be executed
+        let reveal = executor.create_reveal_message(RevealBody {
+            dr_id:             dr_id.clone(),
+            dr_block_height:   1,
+            reveal:            "10".hash().into(),
+            gas_used:          0,
+            exit_code:         0,
+            proxy_public_keys: vec![],
+        });
+        executor.commit_result(&dr_id, &reveal).unwrap();
+    }
 }
@@ -4,7 +4,12 @@ use cosmwasm_std::{StdError, Uint128};
 use hex::FromHexError;
 use thiserror::Error;
 
-use crate::msgs::data_requests::consts::{MIN_EXEC_GAS_LIMIT, MIN_GAS_PRICE, MIN_TALLY_GAS_LIMIT};
+use crate::msgs::data_requests::consts::{
+    MAX_CALLBACK_GAS_LIMIT,
+    MIN_EXEC_GAS_LIMIT,
+    MIN_GAS_PRICE,
+    MIN_TALLY_GAS_LIMIT,
+};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
@@ -31,6 +36,20 @@ pub enum ContractError {
     NoPendingOwnerFound,
     #[error("NotOnAllowlist: Address is not on the allowlist")]
     NotOnAllowlist,
+    #[error("NothingToWithdraw: No tokens are available to withdraw yet")]
+    NothingToWithdraw,
+    #[error("NoDelegation: No delegation found for this executor")]
+    NoDelegation,
+    #[error("InvalidCommissionRate: Commission rate must be at most 10000 bps, got: {0}")]
+    InvalidCommissionRate(u16),
+    #[error("InvalidRestakeRatio: Restake ratio must be at most 10000 bps, got: {0}")]
+    InvalidRestakeRatio(u16),
+    #[error("PublicKeyAlreadyUsed: Public key is already used by a staker: {0}")]
+    PublicKeyAlreadyUsed(String),
+    #[error("NotJailed: Executor is not jailed")]
+    NotJailed,
+    #[error("JailPeriodNotOver: Executor is jailed until block height {0}")]
+    JailPeriodNotOver(u64),
 
     // DR contract errors
     #[error("InvalidAddress: Invalid address: {0}")]
@@ -39,6 +58,8 @@ pub enum ContractError {
     InsufficientFunds(Uint128, Uint128),
     #[error("InsufficientStake: Stake amount is insufficient. Required: {0}, got: {1}")]
     InsufficientStake(Uint128, Uint128),
+    #[error("RemainingStakeBelowMinimum: Remaining stake must be zero or at least {0}, got: {1}")]
+    RemainingStakeBelowMinimum(Uint128, Uint128),
     #[error("DataRequestAlreadyExists: Data request already exists")]
     DataRequestAlreadyExists,
     #[error("DataRequestReplicationFactorZero: Data request replication factor cannot be zero")]
@@ -61,6 +82,26 @@ pub enum ContractError {
     RevealMismatch,
     #[error("NotEnoughReveals: Not enough reveals to post the data result")]
     NotEnoughReveals,
+    #[error("NotDataRequestPoster: Only the poster can cancel a data request")]
+    NotDataRequestPoster,
+    #[error("DataRequestHasCommits: Cannot cancel a data request that already has commits")]
+    DataRequestHasCommits,
+    #[error("TooManyExecutors: Data request lists {0} executors, max allowed is {1}")]
+    TooManyExecutors(usize, NonZero<u16>),
+    #[error("EligibilityBatchTooLarge: Eligibility batch lists {0} data requests, max allowed is {1}")]
+    EligibilityBatchTooLarge(usize, usize),
+    #[error("TooManyDataRequests: Batch posts {0} data requests, max allowed is {1}")]
+    TooManyDataRequests(usize, NonZero<u16>),
+    #[error("DuplicateExecutor: Executor {0} is listed more than once")]
+    DuplicateExecutor(String),
+    #[error("ExecutorJailed: Executor is jailed until block height {0}")]
+    ExecutorJailed(u64),
+    #[error("NotAllowedExecutor: Executor is not allowed to execute this data request")]
+    NotAllowedExecutor,
+    #[error("DataRequestTallying: Data request is already in the tallying stage")]
+    DataRequestTallying,
+    #[error("DataRequestNotCommitting: Data request is no longer in the committing stage")]
+    DataRequestNotCommitting,
     #[error("DataRequestExpired: Data request expired at block height {0} during {1} stage")]
     DataRequestExpired(u64, &'static str),
     #[error("Could not encode protobuf message: {0}")]
@@ -88,6 +129,8 @@ pub enum ContractError {
     ContractPaused(String),
     #[error("Contract not paused: cannot unpause")]
     ContractNotPaused,
+    #[error("InvalidTimeoutBounds: The default {0} timeout must be within its min and max bounds")]
+    InvalidTimeoutBounds(&'static str),
     #[error("ZeroMinimumStakeToRegister: Minimum stake to register cannot be zero")]
     ZeroMinimumStakeToRegister,
 
@@ -97,6 +140,10 @@ pub enum ContractError {
     ExecGasLimitTooLow(u64),
     #[error("TallyGasLimitTooLow: Tally gas limit {0} is lower than the minimum {MIN_TALLY_GAS_LIMIT}")]
     TallyGasLimitTooLow(u64),
+    #[error("CallbackGasLimitTooHigh: Callback gas limit {0} is higher than the maximum {MAX_CALLBACK_GAS_LIMIT}")]
+    CallbackGasLimitTooHigh(u64),
+    #[error("UnknownReplyId: Unknown reply id {0}")]
+    UnknownReplyId(u64),
 
     #[error("SemVer: Invalid semver: {0}")]
     SemVer(String),
@@ -0,0 +1,49 @@
+use cosmwasm_std::{to_json_binary, Event, Reply, Response, StdResult, SubMsg, SubMsgResult, WasmMsg};
+use seda_common::{
+    msgs::data_requests::{CallbackMsg, DataResult},
+    types::{Hash, ToHexStr},
+};
+
+use super::state::Callback;
+use crate::{contract::CONTRACT_VERSION, error::ContractError};
+
+pub const CALLBACK_REPLY_ID: u64 = 1;
+
+/// Builds the message that delivers a data result to the consumer contract.
+///
+/// The reply reports the outcome, so a failing consumer cannot revert the
+/// removal of the data request.
+pub fn callback_msg(callback: Callback, dr_id: &Hash, result: &DataResult) -> StdResult<SubMsg> {
+    let msg = CallbackMsg::DataResult {
+        dr_id:     dr_id.to_hex(),
+        exit_code: result.exit_code,
+        result:    result.result.clone(),
+    };
+
+    Ok(SubMsg::reply_always(
+        WasmMsg::Execute {
+            contract_addr: callback.contract.into_string(),
+            msg:           to_json_binary(&msg)?,
+            funds:         vec![],
+        },
+        CALLBACK_REPLY_ID,
+    )
+    .with_gas_limit(callback.gas_limit)
+    .with_payload(dr_id.to_vec()))
+}
+
+/// Records the outcome of a callback, the data request itself stays removed
+/// either way.
+pub fn handle_callback_reply(reply: Reply) -> Result<Response, ContractError> {
+    let event = Event::new("seda-data-request-callback").add_attributes([
+        ("version", CONTRACT_VERSION.to_string()),
+        ("dr_id", hex::encode(reply.payload)),
+        ("gas_used", reply.gas_used.to_string()),
+    ]);
+    let event = match reply.result {
+        SubMsgResult::Ok(_) => event.add_attribute("success", "true"),
+        SubMsgResult::Err(error) => event.add_attributes([("success", "false".to_string()), ("error", error)]),
+    };
+
+    Ok(Response::new().add_event(event))
+}
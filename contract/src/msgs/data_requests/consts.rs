@@ -8,6 +8,12 @@ pub const MIN_TALLY_GAS_LIMIT: u64 = 10 * TERA_GAS;
 
 pub const MAX_REPLICATION_FACTOR: u16 = 100;
 
+pub const MAX_CALLBACK_GAS_LIMIT: u64 = 1_000_000;
+
+/// Bounds the work done by a single removal, e.g. after the retention window
+/// was shortened.
+pub const MAX_DATA_RESULTS_PRUNED_PER_CALL: usize = 100;
+
 #[cfg(test)]
 pub fn min_post_dr_cost() -> u128 {
     let exec_gas_limit = Uint128::new(MIN_EXEC_GAS_LIMIT as u128);
@@ -15,3 +21,8 @@ pub fn min_post_dr_cost() -> u128 {
 
     ((exec_gas_limit + tally_gas_limit) * MIN_GAS_PRICE).u128()
 }
+
+#[cfg(test)]
+pub fn callback_cost(gas_limit: u64) -> u128 {
+    (Uint128::from(gas_limit) * MIN_GAS_PRICE).u128()
+}
@@ -0,0 +1,51 @@
+use state::{DR_CALLBACKS, DR_ESCROW, DR_EXECUTOR_SNAPSHOT};
+
+use super::*;
+use crate::{msgs::staking::state::executor_snapshots::release_snapshot, state::TOKEN};
+
+impl ExecuteHandler for execute::cancel_request::Execute {
+    /// Cancels a data request that has not received any commits yet, refunding
+    /// the escrow to the poster.
+    fn execute(self, deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let dr_id = Hash::from_hex_str(&self.dr_id)?;
+        let dr = state::load_request(deps.storage, &dr_id)?;
+
+        let escrow = DR_ESCROW.load(deps.storage, &dr_id)?;
+        if info.sender != escrow.poster {
+            return Err(ContractError::NotDataRequestPoster);
+        }
+
+        // once an executor has committed they have started doing work for the request
+        if !dr.base.commits.is_empty() {
+            return Err(ContractError::DataRequestHasCommits);
+        }
+
+        if state::request_status(deps.storage, &dr_id)? != DataRequestStatus::Committing {
+            return Err(ContractError::DataRequestNotCommitting);
+        }
+
+        state::cancel_request(deps.storage, &dr_id)?;
+        DR_ESCROW.remove(deps.storage, &dr_id);
+        DR_CALLBACKS.remove(deps.storage, &dr_id);
+        if let Some(epoch) = DR_EXECUTOR_SNAPSHOT.may_load(deps.storage, &dr_id)? {
+            release_snapshot(deps.storage, epoch)?;
+            DR_EXECUTOR_SNAPSHOT.remove(deps.storage, &dr_id);
+        }
+
+        let token = TOKEN.load(deps.storage)?;
+        let refund = BankMsg::Send {
+            to_address: escrow.poster.to_string(),
+            amount:     coins(escrow.amount.u128(), token),
+        };
+
+        Ok(Response::new()
+            .add_attribute("action", "cancel_data_request")
+            .add_message(refund)
+            .add_event(Event::new("seda-cancel-dr").add_attributes([
+                ("version", CONTRACT_VERSION.to_string()),
+                ("dr_id", self.dr_id),
+                ("posted_dr_height", dr.base.height.to_string()),
+                ("refund", escrow.amount.to_string()),
+            ])))
+    }
+}
@@ -1,4 +1,4 @@
-use staking::state::{STAKERS, STAKING_CONFIG};
+use staking::state::{effective_stake, executor_stats::update_executor_stats, STAKERS, STAKING_CONFIG};
 
 use super::*;
 use crate::{msgs::owner::state::ALLOWLIST, state::CHAIN_ID};
@@ -28,6 +28,12 @@ impl ExecuteHandler for execute::commit_result::Execute {
         );
         state::commit(deps.storage, env.block.height, &dr_id, dr)?;
 
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+        update_executor_stats(deps.storage, &public_key, |stats| {
+            stats.commits += 1;
+            stats.last_active_height = env.block.height;
+        })?;
+
         Ok(resp.add_message(new_refund_msg(env, self.dr_id, self.public_key, false)?))
     }
 }
@@ -38,8 +44,15 @@ pub fn verify_commit(
     commit: &execute::commit_result::Execute,
     dr: &DataRequestContract,
 ) -> Result<(), ContractError> {
-    // error if the user has already committed
-    if dr.base.has_committer(commit.public_key.as_str()) {
+    // error if the user has already committed, possibly under a key they rotated
+    // away from since
+    let public_key = PublicKey::from_hex_str(commit.public_key.as_str())?;
+    let previous_keys = STAKERS
+        .previous_keys(deps.storage, &public_key)?
+        .into_iter()
+        .map(|key| key.to_hex())
+        .collect::<Vec<_>>();
+    if dr.base.has_committer(commit.public_key.as_str()) || previous_keys.iter().any(|key| dr.base.has_committer(key)) {
         return Err(ContractError::AlreadyCommitted);
     }
 
@@ -54,9 +67,19 @@ pub fn verify_commit(
         return Err(ContractError::DataRequestExpired(expires_at, "commit"));
     }
 
-    let public_key = PublicKey::from_hex_str(commit.public_key.as_str())?;
+    // Check if the poster restricted the data request to a set of executors, which
+    // may list the executor under a previous key
+    if !dr.base.allows_executor(&commit.public_key) && !previous_keys.iter().any(|key| dr.base.allows_executor(key)) {
+        return Err(ContractError::NotAllowedExecutor);
+    }
+
     let staker = STAKERS.get_staker(deps.storage, &public_key)?;
 
+    // error if the executor has been jailed for missing reveals
+    if let Some(jailed_until) = staker.jailed_until {
+        return Err(ContractError::ExecutorJailed(jailed_until));
+    }
+
     // Check if the staker is on the allowlist if it is enabled
     if STAKING_CONFIG.load(deps.storage)?.allowlist_enabled {
         let allowed = ALLOWLIST.may_load(deps.storage, &public_key)?;
@@ -65,10 +88,11 @@ pub fn verify_commit(
         }
     }
 
-    // Check if the staker has enough funds staked to commit
+    // Check if the staker has enough funds staked, or delegated to them, to commit
     let minimum_stake = STAKING_CONFIG.load(deps.storage)?.minimum_stake;
-    if staker.tokens_staked < minimum_stake {
-        return Err(ContractError::InsufficientStake(minimum_stake, staker.tokens_staked));
+    let stake = effective_stake(&staker);
+    if stake < minimum_stake {
+        return Err(ContractError::InsufficientStake(minimum_stake, stake));
     }
 
     // verify the proof
@@ -34,5 +34,28 @@ pub fn create_dr_config_event(config: DrConfig) -> Event {
             "seda_payload_limit_in_bytes",
             config.seda_payload_limit_in_bytes.to_string(),
         ),
+        (
+            "min_commit_timeout_in_blocks",
+            config.min_commit_timeout_in_blocks.to_string(),
+        ),
+        (
+            "max_commit_timeout_in_blocks",
+            config.max_commit_timeout_in_blocks.to_string(),
+        ),
+        (
+            "min_reveal_timeout_in_blocks",
+            config.min_reveal_timeout_in_blocks.to_string(),
+        ),
+        (
+            "max_reveal_timeout_in_blocks",
+            config.max_reveal_timeout_in_blocks.to_string(),
+        ),
+        (
+            "data_result_retention_in_blocks",
+            config.data_result_retention_in_blocks.to_string(),
+        ),
+        ("executors_limit", config.executors_limit.to_string()),
+        ("max_expirations_per_call", config.max_expirations_per_call.to_string()),
+        ("max_requests_per_post", config.max_requests_per_post.to_string()),
     ])
 }
@@ -6,11 +6,14 @@ use super::{
 };
 use crate::state::PAUSED;
 
+pub(in crate::msgs::data_requests) mod cancel_request;
 pub(in crate::msgs::data_requests) mod commit_result;
 pub(crate) mod dr_events;
 pub(in crate::msgs::data_requests) mod post_request;
+pub(in crate::msgs::data_requests) mod post_requests;
 pub(in crate::msgs::data_requests) mod reveal_result;
-pub(in crate::msgs::data_requests) mod set_dr_config;
+pub(crate) mod set_dr_config;
+pub(in crate::msgs::data_requests) mod top_up_request;
 
 impl ExecuteHandler for ExecuteMsg {
     fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
@@ -22,10 +25,13 @@ impl ExecuteHandler for ExecuteMsg {
         }
 
         match self {
+            ExecuteMsg::CancelDataRequest(msg) => msg.execute(deps, env, info),
             ExecuteMsg::CommitDataResult(msg) => msg.execute(deps, env, info),
             ExecuteMsg::PostDataRequest(msg) => msg.execute(deps, env, info),
+            ExecuteMsg::PostDataRequests(msg) => msg.execute(deps, env, info),
             ExecuteMsg::RevealDataResult(msg) => msg.execute(deps, env, info),
             ExecuteMsg::SetDrConfig(msg) => msg.execute(deps, env, info),
+            ExecuteMsg::TopUpDataRequest(msg) => msg.execute(deps, env, info),
         }
     }
 }
@@ -1,10 +1,18 @@
-use staking::state::STAKERS;
-use state::{Escrow, DR_ESCROW};
+use std::collections::HashSet;
+
+use staking::state::{executor_snapshots::reference_snapshot, STAKERS};
+use state::{Callback, Escrow, DR_CALLBACKS, DR_ESCROW, DR_EXECUTOR_SNAPSHOT};
 
 use super::*;
 use crate::{
     msgs::data_requests::{
-        consts::{MAX_REPLICATION_FACTOR, MIN_EXEC_GAS_LIMIT, MIN_GAS_PRICE, MIN_TALLY_GAS_LIMIT},
+        consts::{
+            MAX_CALLBACK_GAS_LIMIT,
+            MAX_REPLICATION_FACTOR,
+            MIN_EXEC_GAS_LIMIT,
+            MIN_GAS_PRICE,
+            MIN_TALLY_GAS_LIMIT,
+        },
         state::DR_CONFIG,
     },
     state::TOKEN,
@@ -14,107 +22,12 @@ use crate::{
 impl ExecuteHandler for execute::post_request::Execute {
     /// Posts a data request to the pool
     fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-        // require the replication to be non-zero
-        if self.posted_dr.replication_factor == 0 {
-            return Err(ContractError::DataRequestReplicationFactorZero);
-        }
-
-        // require the gas price, and gas limits to be above the minimums
-        if self.posted_dr.gas_price < MIN_GAS_PRICE {
-            return Err(ContractError::GasPriceTooLow(self.posted_dr.gas_price));
-        }
-        if self.posted_dr.exec_gas_limit < MIN_EXEC_GAS_LIMIT {
-            return Err(ContractError::ExecGasLimitTooLow(self.posted_dr.exec_gas_limit));
-        }
-        if self.posted_dr.tally_gas_limit < MIN_TALLY_GAS_LIMIT {
-            return Err(ContractError::TallyGasLimitTooLow(self.posted_dr.tally_gas_limit));
-        }
-        // check the program ids are a valid hash length (32 bytes) in hex (64 utf-8
-        // bytes)
-        if self.posted_dr.exec_program_id.len() != 64 {
-            return Err(ContractError::ProgramIdInvalidLength(
-                "exec",
-                self.posted_dr.exec_program_id.len(),
-            ));
-        }
-        if self.posted_dr.tally_program_id.len() != 64 {
-            return Err(ContractError::ProgramIdInvalidLength(
-                "tally",
-                self.posted_dr.tally_program_id.len(),
-            ));
-        }
-        // Ensure the version only consists of Major.Minor.Patch
-        if !self.posted_dr.version.pre.is_empty() || !self.posted_dr.version.build.is_empty() {
-            return Err(ContractError::DataRequestVersionInvalid);
-        }
-        // check the size limits of the dr
-        let dr_config = DR_CONFIG.load(deps.storage)?;
-        if self.posted_dr.exec_inputs.len() > dr_config.exec_input_limit_in_bytes.get() as usize {
-            return Err(ContractError::DrFieldTooBig(
-                "exec inputs",
-                self.posted_dr.exec_inputs.len(),
-                dr_config.exec_input_limit_in_bytes,
-            ));
-        }
-        if self.posted_dr.tally_inputs.len() > dr_config.tally_input_limit_in_bytes.get() as usize {
-            return Err(ContractError::DrFieldTooBig(
-                "tally inputs",
-                self.posted_dr.tally_inputs.len(),
-                dr_config.tally_input_limit_in_bytes,
-            ));
-        }
-        if self.posted_dr.consensus_filter.len() > dr_config.consensus_filter_limit_in_bytes.get() as usize {
-            return Err(ContractError::DrFieldTooBig(
-                "consensus filter",
-                self.posted_dr.consensus_filter.len(),
-                dr_config.consensus_filter_limit_in_bytes,
-            ));
-        }
-        if self.posted_dr.memo.len() > dr_config.memo_limit_in_bytes.get() as usize {
-            return Err(ContractError::DrFieldTooBig(
-                "memo",
-                self.posted_dr.memo.len(),
-                dr_config.memo_limit_in_bytes,
-            ));
-        }
-        if self.payback_address.len() > dr_config.payback_address_limit_in_bytes.get() as usize {
-            return Err(ContractError::DrFieldTooBig(
-                "payback address",
-                self.payback_address.len(),
-                dr_config.payback_address_limit_in_bytes,
-            ));
-        }
-        if self.seda_payload.len() > dr_config.seda_payload_limit_in_bytes.get() as usize {
-            return Err(ContractError::DrFieldTooBig(
-                "seda payload",
-                self.seda_payload.len(),
-                dr_config.seda_payload_limit_in_bytes,
-            ));
-        }
-
-        // require the data request replication factor to be bigger than amount of
-        // stakers
-        let stakers_length = STAKERS.len(deps.storage)?;
-        let max_allowed_replication_factor = std::cmp::min(stakers_length, MAX_REPLICATION_FACTOR as u32);
-        if self.posted_dr.replication_factor as u32 > max_allowed_replication_factor {
-            return Err(ContractError::DataRequestReplicationFactorTooHigh(
-                max_allowed_replication_factor,
-            ));
-        }
-
-        // hash the inputs to get the data request id
-        let dr_id = self.posted_dr.try_hash()?;
-
-        // require the data request id to be unique
-        if state::data_request_exists(deps.as_ref(), dr_id) {
-            return Err(ContractError::DataRequestAlreadyExists);
-        }
+        let dr_id = validate_request(deps.as_ref(), &self)?;
 
         // Take the funds from the user
         let token = TOKEN.load(deps.storage)?;
         let funds = cw_utils::must_pay(&info, &token)?;
-        let total_gas_limit =
-            Uint128::from(self.posted_dr.exec_gas_limit) + Uint128::from(self.posted_dr.tally_gas_limit);
+        let total_gas_limit = posted_gas_limit(&self);
         let posted_gas_price = funds / total_gas_limit;
 
         // Validate the derived gas price meets minimum requirements
@@ -126,70 +39,270 @@ impl ExecuteHandler for execute::post_request::Execute {
             ));
         }
 
-        let dr_poster = info.sender.to_string();
-        DR_ESCROW.save(
-            deps.storage,
-            &dr_id,
-            &Escrow {
-                amount: funds,
-                poster: info.sender,
-            },
-        )?;
-
-        // TODO: verify the payback non seda address...
-        let hex_dr_id = dr_id.to_hex();
-        let res = Response::new()
+        let (payload, event) = store_request(deps, &env, self, dr_id, info.sender, funds)?;
+
+        Ok(Response::new()
             .add_attribute("action", "post_data_request")
-            .set_data(to_json_binary(&PostRequestResponsePayload {
-                dr_id:  hex_dr_id.clone(),
-                height: env.block.height,
-            })?)
-            .add_event(Event::new("seda-data-request").add_attributes([
-                ("dr_id", hex_dr_id.clone()),
-                ("dr_poster", dr_poster),
-                ("exec_program_id", self.posted_dr.exec_program_id.clone()),
-                ("exec_inputs", self.posted_dr.exec_inputs.to_base64()),
-                ("exec_gas_limit", self.posted_dr.exec_gas_limit.to_string()),
-                ("tally_program_id", self.posted_dr.tally_program_id.clone()),
-                ("tally_inputs", self.posted_dr.tally_inputs.to_base64()),
-                ("tally_gas_limit", self.posted_dr.tally_gas_limit.to_string()),
-                ("replication_factor", self.posted_dr.replication_factor.to_string()),
-                ("consensus_filter", self.posted_dr.consensus_filter.to_base64()),
-                ("gas_price", self.posted_dr.gas_price.to_string()),
-                ("memo", self.posted_dr.memo.to_base64()),
-                ("seda_payload", self.seda_payload.to_base64()),
-                ("payback_address", self.payback_address.to_base64()),
-                ("version", self.posted_dr.version.to_string()),
-                ("posted_gas_price", posted_gas_price.to_string()),
-            ]));
-
-        // save the data request
-        let dr = DataRequestContract {
-            base:    DataRequestBase {
-                id: hex_dr_id,
-                version: self.posted_dr.version,
-                exec_program_id: self.posted_dr.exec_program_id,
-                exec_inputs: self.posted_dr.exec_inputs,
-                exec_gas_limit: self.posted_dr.exec_gas_limit,
-                tally_program_id: self.posted_dr.tally_program_id,
-                tally_inputs: self.posted_dr.tally_inputs,
-                tally_gas_limit: self.posted_dr.tally_gas_limit,
-                replication_factor: self.posted_dr.replication_factor,
-                consensus_filter: self.posted_dr.consensus_filter,
-                gas_price: self.posted_dr.gas_price,
-                memo: self.posted_dr.memo,
-
-                payback_address: self.payback_address,
-                seda_payload: self.seda_payload,
-                commits: Default::default(),
-
-                height: env.block.height,
-                posted_gas_price,
-            },
-            reveals: Default::default(),
-        };
-        state::post_request(deps.storage, env.block.height, &dr_id, dr)?;
+            .set_data(to_json_binary(&payload)?)
+            .add_event(event))
+    }
+}
+
+/// The gas the poster pays for, including the gas limit of their callback.
+pub(super) fn total_gas_limit(exec_gas_limit: u64, tally_gas_limit: u64, callback_gas_limit: Option<u64>) -> Uint128 {
+    Uint128::from(exec_gas_limit)
+        + Uint128::from(tally_gas_limit)
+        + Uint128::from(callback_gas_limit.unwrap_or_default())
+}
 
-        Ok(res)
+/// The [`total_gas_limit`] of a data request that is about to be posted.
+pub(super) fn posted_gas_limit(msg: &execute::post_request::Execute) -> Uint128 {
+    total_gas_limit(
+        msg.posted_dr.exec_gas_limit,
+        msg.posted_dr.tally_gas_limit,
+        msg.callback.as_ref().map(|callback| callback.gas_limit),
+    )
+}
+
+/// Checks the data request against the [`DrConfig`] and the minimum gas
+/// requirements, returning its id.
+pub(super) fn validate_request(deps: Deps, msg: &execute::post_request::Execute) -> Result<Hash, ContractError> {
+    // require the replication to be non-zero
+    if msg.posted_dr.replication_factor == 0 {
+        return Err(ContractError::DataRequestReplicationFactorZero);
+    }
+
+    // require the gas price, and gas limits to be above the minimums
+    if msg.posted_dr.gas_price < MIN_GAS_PRICE {
+        return Err(ContractError::GasPriceTooLow(msg.posted_dr.gas_price));
+    }
+    if msg.posted_dr.exec_gas_limit < MIN_EXEC_GAS_LIMIT {
+        return Err(ContractError::ExecGasLimitTooLow(msg.posted_dr.exec_gas_limit));
     }
+    if msg.posted_dr.tally_gas_limit < MIN_TALLY_GAS_LIMIT {
+        return Err(ContractError::TallyGasLimitTooLow(msg.posted_dr.tally_gas_limit));
+    }
+    // check the program ids are a valid hash length (32 bytes) in hex (64 utf-8
+    // bytes)
+    if msg.posted_dr.exec_program_id.len() != 64 {
+        return Err(ContractError::ProgramIdInvalidLength(
+            "exec",
+            msg.posted_dr.exec_program_id.len(),
+        ));
+    }
+    if msg.posted_dr.tally_program_id.len() != 64 {
+        return Err(ContractError::ProgramIdInvalidLength(
+            "tally",
+            msg.posted_dr.tally_program_id.len(),
+        ));
+    }
+    // Ensure the version only consists of Major.Minor.Patch
+    if !msg.posted_dr.version.pre.is_empty() || !msg.posted_dr.version.build.is_empty() {
+        return Err(ContractError::DataRequestVersionInvalid);
+    }
+    // check the size limits of the dr
+    let dr_config = DR_CONFIG.load(deps.storage)?;
+    if msg.posted_dr.exec_inputs.len() > dr_config.exec_input_limit_in_bytes.get() as usize {
+        return Err(ContractError::DrFieldTooBig(
+            "exec inputs",
+            msg.posted_dr.exec_inputs.len(),
+            dr_config.exec_input_limit_in_bytes,
+        ));
+    }
+    if msg.posted_dr.tally_inputs.len() > dr_config.tally_input_limit_in_bytes.get() as usize {
+        return Err(ContractError::DrFieldTooBig(
+            "tally inputs",
+            msg.posted_dr.tally_inputs.len(),
+            dr_config.tally_input_limit_in_bytes,
+        ));
+    }
+    if msg.posted_dr.consensus_filter.len() > dr_config.consensus_filter_limit_in_bytes.get() as usize {
+        return Err(ContractError::DrFieldTooBig(
+            "consensus filter",
+            msg.posted_dr.consensus_filter.len(),
+            dr_config.consensus_filter_limit_in_bytes,
+        ));
+    }
+    if msg.posted_dr.memo.len() > dr_config.memo_limit_in_bytes.get() as usize {
+        return Err(ContractError::DrFieldTooBig(
+            "memo",
+            msg.posted_dr.memo.len(),
+            dr_config.memo_limit_in_bytes,
+        ));
+    }
+    if msg.payback_address.len() > dr_config.payback_address_limit_in_bytes.get() as usize {
+        return Err(ContractError::DrFieldTooBig(
+            "payback address",
+            msg.payback_address.len(),
+            dr_config.payback_address_limit_in_bytes,
+        ));
+    }
+    if msg.seda_payload.len() > dr_config.seda_payload_limit_in_bytes.get() as usize {
+        return Err(ContractError::DrFieldTooBig(
+            "seda payload",
+            msg.seda_payload.len(),
+            dr_config.seda_payload_limit_in_bytes,
+        ));
+    }
+
+    if let Some(callback) = &msg.callback {
+        if deps.api.addr_validate(&callback.contract).is_err() {
+            return Err(ContractError::InvalidAddress(callback.contract.clone()));
+        }
+        if callback.gas_limit > MAX_CALLBACK_GAS_LIMIT {
+            return Err(ContractError::CallbackGasLimitTooHigh(callback.gas_limit));
+        }
+    }
+
+    // a restricted data request can never use more executors than it lists
+    let mut max_allowed_replication_factor = MAX_REPLICATION_FACTOR as u32;
+    if let Some(executors) = &msg.posted_dr.executors {
+        if executors.len() > dr_config.executors_limit.get() as usize {
+            return Err(ContractError::TooManyExecutors(
+                executors.len(),
+                dr_config.executors_limit,
+            ));
+        }
+        let mut seen = HashSet::with_capacity(executors.len());
+        for executor in executors {
+            if !seen.insert(PublicKey::from_hex_str(executor)?) {
+                return Err(ContractError::DuplicateExecutor(executor.clone()));
+            }
+        }
+        max_allowed_replication_factor = max_allowed_replication_factor.min(executors.len() as u32);
+    }
+
+    // require the data request replication factor to be bigger than amount of
+    // stakers
+    let stakers_length = STAKERS.len(deps.storage)?;
+    let max_allowed_replication_factor = std::cmp::min(stakers_length, max_allowed_replication_factor);
+    if msg.posted_dr.replication_factor as u32 > max_allowed_replication_factor {
+        return Err(ContractError::DataRequestReplicationFactorTooHigh(
+            max_allowed_replication_factor,
+        ));
+    }
+
+    // hash the inputs to get the data request id
+    let dr_id = msg.posted_dr.try_hash()?;
+
+    // require the data request id to be unique
+    if state::data_request_exists(deps, dr_id) {
+        return Err(ContractError::DataRequestAlreadyExists);
+    }
+
+    Ok(dr_id)
+}
+
+/// Stores a validated data request with `funds` as its escrow.
+pub(super) fn store_request(
+    deps: DepsMut,
+    env: &Env,
+    msg: execute::post_request::Execute,
+    dr_id: Hash,
+    poster: Addr,
+    funds: Uint128,
+) -> Result<(PostRequestResponsePayload, Event), ContractError> {
+    let posted_gas_price = funds / posted_gas_limit(&msg);
+
+    // requested timeouts are clamped to the governance bounds
+    let dr_config = DR_CONFIG.load(deps.storage)?;
+    let commit_timeout_in_blocks =
+        msg.posted_dr
+            .commit_timeout_in_blocks
+            .map_or(dr_config.commit_timeout_in_blocks, |timeout| {
+                timeout.clamp(
+                    dr_config.min_commit_timeout_in_blocks,
+                    dr_config.max_commit_timeout_in_blocks,
+                )
+            });
+    let reveal_timeout_in_blocks =
+        msg.posted_dr
+            .reveal_timeout_in_blocks
+            .map_or(dr_config.reveal_timeout_in_blocks, |timeout| {
+                timeout.clamp(
+                    dr_config.min_reveal_timeout_in_blocks,
+                    dr_config.max_reveal_timeout_in_blocks,
+                )
+            });
+
+    let dr_poster = poster.to_string();
+    DR_ESCROW.save(deps.storage, &dr_id, &Escrow { amount: funds, poster })?;
+    let snapshot_epoch = reference_snapshot(deps.storage)?;
+    DR_EXECUTOR_SNAPSHOT.save(deps.storage, &dr_id, &snapshot_epoch)?;
+
+    // TODO: verify the payback non seda address...
+    let hex_dr_id = dr_id.to_hex();
+    let payload = PostRequestResponsePayload {
+        dr_id:  hex_dr_id.clone(),
+        height: env.block.height,
+    };
+    let mut event = Event::new("seda-data-request").add_attributes([
+        ("dr_id", hex_dr_id.clone()),
+        ("dr_poster", dr_poster),
+        ("exec_program_id", msg.posted_dr.exec_program_id.clone()),
+        ("exec_inputs", msg.posted_dr.exec_inputs.to_base64()),
+        ("exec_gas_limit", msg.posted_dr.exec_gas_limit.to_string()),
+        ("tally_program_id", msg.posted_dr.tally_program_id.clone()),
+        ("tally_inputs", msg.posted_dr.tally_inputs.to_base64()),
+        ("tally_gas_limit", msg.posted_dr.tally_gas_limit.to_string()),
+        ("replication_factor", msg.posted_dr.replication_factor.to_string()),
+        ("consensus_filter", msg.posted_dr.consensus_filter.to_base64()),
+        ("gas_price", msg.posted_dr.gas_price.to_string()),
+        ("memo", msg.posted_dr.memo.to_base64()),
+        ("seda_payload", msg.seda_payload.to_base64()),
+        ("payback_address", msg.payback_address.to_base64()),
+        ("version", msg.posted_dr.version.to_string()),
+        ("posted_gas_price", posted_gas_price.to_string()),
+        ("commit_timeout_in_blocks", commit_timeout_in_blocks.to_string()),
+        ("reveal_timeout_in_blocks", reveal_timeout_in_blocks.to_string()),
+        ("executor_snapshot_epoch", snapshot_epoch.to_string()),
+    ]);
+
+    if let Some(executors) = &msg.posted_dr.executors {
+        event = event.add_attribute("executors", to_json_string(executors)?);
+    }
+
+    if let Some(callback) = msg.callback {
+        let callback = Callback {
+            contract:  deps.api.addr_validate(&callback.contract)?,
+            gas_limit: callback.gas_limit,
+        };
+        event = event.add_attributes([
+            ("callback_contract", callback.contract.to_string()),
+            ("callback_gas_limit", callback.gas_limit.to_string()),
+        ]);
+        DR_CALLBACKS.save(deps.storage, &dr_id, &callback)?;
+    }
+
+    // save the data request
+    let dr = DataRequestContract {
+        base:    DataRequestBase {
+            id: hex_dr_id,
+            version: msg.posted_dr.version,
+            exec_program_id: msg.posted_dr.exec_program_id,
+            exec_inputs: msg.posted_dr.exec_inputs,
+            exec_gas_limit: msg.posted_dr.exec_gas_limit,
+            tally_program_id: msg.posted_dr.tally_program_id,
+            tally_inputs: msg.posted_dr.tally_inputs,
+            tally_gas_limit: msg.posted_dr.tally_gas_limit,
+            replication_factor: msg.posted_dr.replication_factor,
+            consensus_filter: msg.posted_dr.consensus_filter,
+            gas_price: msg.posted_dr.gas_price,
+            memo: msg.posted_dr.memo,
+
+            payback_address: msg.payback_address,
+            seda_payload: msg.seda_payload,
+            commits: Default::default(),
+
+            height: env.block.height,
+            posted_gas_price,
+            commit_timeout_in_blocks,
+            reveal_timeout_in_blocks,
+            executors: msg.posted_dr.executors,
+        },
+        reveals: Default::default(),
+    };
+    state::post_request(deps.storage, env.block.height, &dr_id, dr)?;
+
+    Ok((payload, event))
 }
@@ -0,0 +1,69 @@
+use super::{
+    post_request::{posted_gas_limit, store_request, validate_request},
+    *,
+};
+use crate::{msgs::data_requests::state::DR_CONFIG, state::TOKEN};
+
+impl ExecuteHandler for execute::post_requests::Execute {
+    /// Posts a batch of data requests to the pool, paid for by a single
+    /// transfer.
+    ///
+    /// Invalid requests are skipped rather than failing the whole batch, and
+    /// any funds not escrowed for a posted request are refunded to the sender.
+    /// A batch larger than `max_requests_per_post` is rejected as a whole.
+    fn execute(self, mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let max_requests_per_post = DR_CONFIG.load(deps.storage)?.max_requests_per_post;
+        if self.requests.len() > max_requests_per_post.get() as usize {
+            return Err(ContractError::TooManyDataRequests(
+                self.requests.len(),
+                max_requests_per_post,
+            ));
+        }
+
+        let token = TOKEN.load(deps.storage)?;
+        let funds = cw_utils::must_pay(&info, &token)?;
+
+        let mut remaining_funds = funds;
+        let mut results = Vec::with_capacity(self.requests.len());
+        let mut events = Vec::new();
+        for request in self.requests {
+            // validation has no side effects, so a skipped request leaves no state behind
+            let validated = validate_request(deps.as_ref(), &request).and_then(|dr_id| {
+                let required = posted_gas_limit(&request).checked_mul(request.posted_dr.gas_price)?;
+                if required > remaining_funds {
+                    return Err(ContractError::InsufficientFunds(required, remaining_funds));
+                }
+                Ok((dr_id, required))
+            });
+
+            match validated {
+                Ok((dr_id, required)) => {
+                    remaining_funds -= required;
+                    let (payload, event) =
+                        store_request(deps.branch(), &env, request, dr_id, info.sender.clone(), required)?;
+                    results.push(PostDataRequestsResult::Posted(payload));
+                    events.push(event);
+                }
+                Err(err) => results.push(PostDataRequestsResult::Skipped {
+                    reason: err.to_string(),
+                }),
+            }
+        }
+
+        let mut response = Response::new()
+            .add_attribute("action", "post_data_requests")
+            .set_data(to_json_binary(&results)?)
+            .add_events(events);
+
+        if !remaining_funds.is_zero() {
+            response = response
+                .add_attribute("refund", remaining_funds.to_string())
+                .add_message(BankMsg::Send {
+                    to_address: info.sender.to_string(),
+                    amount:     coins(remaining_funds.u128(), token),
+                });
+        }
+
+        Ok(response)
+    }
+}
@@ -1,3 +1,5 @@
+use staking::state::{executor_stats::update_executor_stats, jailing::reset_missed_reveals, STAKERS};
+
 use super::*;
 use crate::{msgs::data_requests::state::DR_CONFIG, state::CHAIN_ID};
 
@@ -87,6 +89,14 @@ impl ExecuteHandler for execute::reveal_result::Execute {
             self.reveal_body,
         )?;
 
+        // the executor may have rotated their key since committing
+        let executor = STAKERS.resolve_key(deps.storage, public_key)?;
+        reset_missed_reveals(deps.storage, &executor)?;
+        update_executor_stats(deps.storage, &executor, |stats| {
+            stats.reveals += 1;
+            stats.last_active_height = env.block.height;
+        })?;
+
         Ok(response.add_message(new_refund_msg(env, dr_id_str, self.public_key, true)?))
     }
 }
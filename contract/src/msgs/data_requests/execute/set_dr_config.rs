@@ -9,6 +9,7 @@ impl ExecuteHandler for DrConfig {
         if info.sender != OWNER.load(deps.storage)? {
             return Err(ContractError::NotOwner);
         }
+        validate_dr_config(&self)?;
         DR_CONFIG.save(deps.storage, &self)?;
 
         Ok(Response::new()
@@ -16,3 +17,18 @@ impl ExecuteHandler for DrConfig {
             .add_event(create_dr_config_event(self)))
     }
 }
+
+pub(crate) fn validate_dr_config(config: &DrConfig) -> Result<(), ContractError> {
+    if !(config.min_commit_timeout_in_blocks..=config.max_commit_timeout_in_blocks)
+        .contains(&config.commit_timeout_in_blocks)
+    {
+        return Err(ContractError::InvalidTimeoutBounds("commit"));
+    }
+    if !(config.min_reveal_timeout_in_blocks..=config.max_reveal_timeout_in_blocks)
+        .contains(&config.reveal_timeout_in_blocks)
+    {
+        return Err(ContractError::InvalidTimeoutBounds("reveal"));
+    }
+
+    Ok(())
+}
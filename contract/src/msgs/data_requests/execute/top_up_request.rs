@@ -0,0 +1,48 @@
+use state::{DR_CALLBACKS, DR_ESCROW};
+
+use super::{post_request::total_gas_limit, *};
+use crate::state::TOKEN;
+
+impl ExecuteHandler for execute::top_up_request::Execute {
+    /// Adds funds to the escrow of a pending data request, raising its posted
+    /// gas price.
+    fn execute(self, deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let dr_id = Hash::from_hex_str(&self.dr_id)?;
+        let mut dr = state::load_request(deps.storage, &dr_id)?;
+
+        // a request that is tallying can no longer be picked up by executors
+        if state::request_status(deps.storage, &dr_id)? == DataRequestStatus::Tallying {
+            return Err(ContractError::DataRequestTallying);
+        }
+
+        let token = TOKEN.load(deps.storage)?;
+        let funds = cw_utils::must_pay(&info, &token)?;
+
+        // the escrow keeps its original poster, so any refund still goes to them
+        let mut escrow = DR_ESCROW.load(deps.storage, &dr_id)?;
+        escrow.amount = escrow.amount.checked_add(funds)?;
+        DR_ESCROW.save(deps.storage, &dr_id, &escrow)?;
+
+        // the escrow also covers the callback gas, so price it the same way posting
+        // does
+        let callback_gas_limit = DR_CALLBACKS
+            .may_load(deps.storage, &dr_id)?
+            .map(|callback| callback.gas_limit);
+        dr.base.posted_gas_price =
+            escrow.amount / total_gas_limit(dr.base.exec_gas_limit, dr.base.tally_gas_limit, callback_gas_limit);
+
+        let event = Event::new("seda-data-request-top-up").add_attributes([
+            ("version", CONTRACT_VERSION.to_string()),
+            ("dr_id", self.dr_id),
+            ("sender", info.sender.to_string()),
+            ("amount", funds.to_string()),
+            ("escrow_amount", escrow.amount.to_string()),
+            ("posted_gas_price", dr.base.posted_gas_price.to_string()),
+        ]);
+        state::update_gas_price(deps.storage, &dr_id, dr)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "top_up_data_request")
+            .add_event(event))
+    }
+}
@@ -2,6 +2,7 @@ use msgs::data_requests::*;
 
 use super::*;
 
+pub mod callback;
 pub mod consts;
 pub mod execute;
 pub mod query;
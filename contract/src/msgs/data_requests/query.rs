@@ -5,7 +5,10 @@ use super::{
     state::DR_CONFIG,
     *,
 };
-use crate::{msgs::sorted_set::IndexKey, state::PAUSED};
+use crate::{
+    msgs::{sorted_set::IndexKey, staking::state::is_eligible_for_dr::data_request_executors},
+    state::PAUSED,
+};
 
 impl QueryHandler for QueryMsg {
     fn query(self, deps: Deps, env: Env) -> Result<Binary, ContractError> {
@@ -39,8 +42,9 @@ impl QueryHandler for QueryMsg {
                 let dr_id = &Hash::from_hex_str(&dr_id)?;
                 match state::may_load_request(deps.storage, dr_id)? {
                     Some(dr) => to_json_binary(&DataRequestResponse {
-                        reveals: state::get_reveals(deps.storage, dr_id)?,
-                        base:    dr.base,
+                        reveals:        state::get_reveals(deps.storage, dr_id)?,
+                        timeout_height: state::may_get_dr_expiration_height(deps.storage, dr_id)?,
+                        base:           dr.base,
                     })?,
                     None => to_json_binary(&None::<DataRequestResponse>)?,
                 }
@@ -92,10 +96,22 @@ impl QueryHandler for QueryMsg {
                 };
                 to_json_binary(&response)?
             }
+            QueryMsg::GetDataRequestExecutors { dr_id } => {
+                let executors = data_request_executors(deps, &env, Hash::from_hex_str(&dr_id)?)?;
+                to_json_binary(&executors)?
+            }
             QueryMsg::GetDrConfig {} => {
                 let config = DR_CONFIG.load(deps.storage)?;
                 to_json_binary(&config)?
             }
+            QueryMsg::GetDataResult { dr_id } => {
+                let result = state::may_load_data_result(deps.storage, &Hash::from_hex_str(&dr_id)?)?;
+                to_json_binary(&result)?
+            }
+            QueryMsg::GetDataResults { dr_ids } => {
+                let results = state::load_data_results(deps.storage, dr_ids)?;
+                to_json_binary(&results)?
+            }
         };
 
         Ok(binary)
@@ -85,19 +85,16 @@ impl DataRequestsMap<'_> {
             return Err(StdError::generic_err("Key already exists"));
         }
 
+        let commit_timeout_in_blocks = req.base.commit_timeout_in_blocks.get() as u64;
         self.reqs.save(store, key, &req)?;
         self.add_to_status(store, key, req, status)?;
-        let dr_config = DR_CONFIG.load(store)?;
-        self.timeouts.insert(
-            store,
-            current_height + dr_config.commit_timeout_in_blocks.get() as u64,
-            key,
-        )?;
+        self.timeouts
+            .insert(store, current_height + commit_timeout_in_blocks, key)?;
 
         Ok(())
     }
 
-    fn find_status(&self, store: &dyn Storage, key: &Hash) -> StdResult<DataRequestStatus> {
+    pub fn find_status(&self, store: &dyn Storage, key: &Hash) -> StdResult<DataRequestStatus> {
         if self.committing.has(store, key) {
             return Ok(DataRequestStatus::Committing);
         }
@@ -166,10 +163,9 @@ impl DataRequestsMap<'_> {
 
                     // We change the timeout to the reveal timeout when commit -> reveal
                     self.timeouts.remove_by_dr_id(store, key)?;
-                    let dr_config = DR_CONFIG.load(store)?;
                     self.timeouts.insert(
                         store,
-                        dr_config.reveal_timeout_in_blocks.get() as u64 + current_height,
+                        dr.base.reveal_timeout_in_blocks.get() as u64 + current_height,
                         key,
                     )?;
                 }
@@ -201,6 +197,57 @@ impl DataRequestsMap<'_> {
         Ok(())
     }
 
+    /// Re-inserts a request into its current status set, so a changed
+    /// `posted_gas_price` is reflected in the ordering.
+    pub fn reindex(&self, store: &mut dyn Storage, key: &Hash, dr: DataRequestContract) -> StdResult<()> {
+        let current_status = self.find_status(store, key)?;
+        self.reqs.save(store, key, &dr)?;
+        self.remove_from_status(store, key, &current_status)?;
+        self.add_to_status(store, key, dr, &current_status)?;
+
+        Ok(())
+    }
+
+    /// Gives requests stored before requests had their own timeouts the
+    /// timeouts from `config`, which they were posted under.
+    pub fn backfill_timeouts(&self, store: &mut dyn Storage, config: &DrConfig) -> StdResult<()> {
+        let keys = self
+            .reqs
+            .keys_raw(store, None, None, Order::Ascending)
+            .collect::<Vec<_>>();
+        for key in keys {
+            let key: Hash = key
+                .try_into()
+                .map_err(|_| StdError::generic_err("Invalid data request key"))?;
+            let path = self.reqs.key(&key);
+            let Some(raw) = store.get(&path) else {
+                continue;
+            };
+
+            // the stored request can't be loaded as a `DataRequestContract` until the
+            // timeouts are set
+            let mut req: serde_json::Value =
+                serde_json::from_slice(&raw).map_err(|e| StdError::parse_err("DataRequestContract", e))?;
+            let Some(fields) = req.as_object_mut() else {
+                return Err(StdError::parse_err("DataRequestContract", "expected an object"));
+            };
+            if fields.contains_key("commit_timeout_in_blocks") && fields.contains_key("reveal_timeout_in_blocks") {
+                continue;
+            }
+            fields
+                .entry("commit_timeout_in_blocks")
+                .or_insert(config.commit_timeout_in_blocks.get().into());
+            fields
+                .entry("reveal_timeout_in_blocks")
+                .or_insert(config.reveal_timeout_in_blocks.get().into());
+
+            let req = serde_json::to_vec(&req).map_err(|e| StdError::serialize_err("DataRequestContract", e))?;
+            store.set(&path, &req);
+        }
+
+        Ok(())
+    }
+
     pub fn may_get(&self, store: &dyn Storage, key: &Hash) -> StdResult<Option<DataRequestContract>> {
         self.reqs.may_load(store, key)
     }
@@ -216,13 +263,12 @@ impl DataRequestsMap<'_> {
         }
 
         // world view = we only remove a data request that is done tallying.
-        // Either the concept is fundamentally flawed or the implementation is wrong.
+        // Anything else is returned as an error rather than a panic, so a batch
+        // removing many requests can skip this one.
         let current_status = self.find_status(store, key)?;
-        assert_eq!(
-            current_status,
-            DataRequestStatus::Tallying,
-            "Cannot remove a request that is not tallying"
-        );
+        if current_status != DataRequestStatus::Tallying {
+            return Err(StdError::generic_err("Cannot remove a request that is not tallying"));
+        }
 
         // remove the request
         self.reqs.remove(store, key);
@@ -235,6 +281,21 @@ impl DataRequestsMap<'_> {
         Ok(())
     }
 
+    /// Removes a request that is still waiting for commits, along with its
+    /// timeout.
+    pub fn cancel(&self, store: &mut dyn Storage, key: &Hash) -> StdResult<()> {
+        let current_status = self.find_status(store, key)?;
+        if current_status != DataRequestStatus::Committing {
+            return Err(StdError::generic_err("Cannot cancel a request that is not committing"));
+        }
+
+        self.reqs.remove(store, key);
+        self.remove_from_status(store, key, &current_status)?;
+        self.timeouts.remove_by_dr_id(store, key)?;
+
+        Ok(())
+    }
+
     pub fn get_reveal(&self, store: &dyn Storage, dr_id: &Hash, identity: &str) -> StdResult<Option<RevealBody>> {
         self.reveals.may_load(store, (dr_id.as_slice(), identity))
     }
@@ -289,7 +350,12 @@ impl DataRequestsMap<'_> {
                 let (key, _) = result?;
                 let dr = self.reqs.load(store, &key.dr_id)?;
                 let reveals = self.get_reveals(store, &key.dr_id)?;
-                Ok(DataRequestResponse { base: dr.base, reveals })
+                let timeout_height = self.timeouts.may_get_timeout_by_dr_id(store, &key.dr_id)?;
+                Ok(DataRequestResponse {
+                    base: dr.base,
+                    reveals,
+                    timeout_height,
+                })
             })
             .collect::<StdResult<Vec<_>>>()?;
 
@@ -303,11 +369,18 @@ impl DataRequestsMap<'_> {
         Ok((requests, new_last_seen_index, set_len))
     }
 
-    pub fn expire_data_requests(&self, store: &mut dyn Storage, current_height: u64) -> StdResult<Vec<String>> {
+    /// Moves up to `limit` timed out requests to tallying, returning their IDs
+    /// and whether more timed out requests are left.
+    pub fn expire_data_requests(
+        &self,
+        store: &mut dyn Storage,
+        current_height: u64,
+        limit: usize,
+    ) -> StdResult<(Vec<String>, bool)> {
         // remove them from the timeouts and return the hashes
-        let drs_to_update_to_tally = self.timeouts.remove_by_timeout_height(store, current_height)?;
+        let (drs_to_update_to_tally, backlog) = self.timeouts.remove_expired(store, current_height, limit)?;
 
-        drs_to_update_to_tally
+        let ids = drs_to_update_to_tally
             .into_iter()
             .map(|hash| {
                 // get the dr itself
@@ -323,7 +396,9 @@ impl DataRequestsMap<'_> {
                 )?;
                 Ok(hash.to_hex())
             })
-            .collect::<StdResult<Vec<_>>>()
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok((ids, backlog))
     }
 }
 
@@ -337,6 +412,7 @@ macro_rules! new_enumerable_status_map {
             timeouts:   Timeouts {
                 timeouts:        Map::new(concat!($namespace, "_timeouts")),
                 hash_to_timeout: Map::new(concat!($namespace, "_hash_to_timeout")),
+                cursor:          Item::new(concat!($namespace, "_timeouts_cursor")),
             },
             reveals:    Map::new(concat!($namespace, "_reveals")),
         }
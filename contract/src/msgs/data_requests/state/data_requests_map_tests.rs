@@ -381,6 +381,28 @@ fn remove_from_empty() {
     test_info.remove(&1.to_string().hash());
 }
 
+#[test]
+fn remove_not_tallying_is_an_error() {
+    let mut test_info = TestInfo::init();
+    let (key, req) = create_test_dr(1);
+    test_info.insert(1, &key, req.clone());
+
+    assert!(test_info.map.remove(&mut test_info.store, &key).is_err());
+    test_info.assert_request(&key, Some(req));
+    test_info.assert_status_len(1, &DataRequestStatus::Committing);
+}
+
+#[test]
+fn cancel_not_committing_is_an_error() {
+    let mut test_info = TestInfo::init();
+    let (key, req) = create_test_dr(1);
+    test_info.insert_removable(1, &key, req.clone());
+
+    assert!(test_info.map.cancel(&mut test_info.store, &key).is_err());
+    test_info.assert_request(&key, Some(req));
+    test_info.assert_status_len(1, &DataRequestStatus::Tallying);
+}
+
 #[test]
 fn remove_only_item() {
     let mut test_info = TestInfo::init();
@@ -0,0 +1,52 @@
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use seda_common::{msgs::data_requests::DataResult, types::Hash};
+
+pub struct DataResults<'a> {
+    pub results:        Map<&'a Hash, DataResult>,
+    // Ordered by the height the result was stored at, so expired results can be pruned in order
+    pub by_height:      Map<(u64, &'a Hash), ()>,
+    // Need this so we can remove the old index entry when a result is overwritten
+    pub hash_to_height: Map<&'a Hash, u64>,
+}
+
+impl DataResults<'_> {
+    pub fn insert(&self, store: &mut dyn Storage, height: u64, dr_id: &Hash, result: &DataResult) -> StdResult<()> {
+        // the same data request can be posted again once the previous one was removed
+        if let Some(old_height) = self.hash_to_height.may_load(store, dr_id)? {
+            self.by_height.remove(store, (old_height, dr_id));
+        }
+
+        self.results.save(store, dr_id, result)?;
+        self.by_height.save(store, (height, dr_id), &())?;
+        self.hash_to_height.save(store, dr_id, &height)?;
+        Ok(())
+    }
+
+    pub fn may_get(&self, store: &dyn Storage, dr_id: &Hash) -> StdResult<Option<DataResult>> {
+        self.results.may_load(store, dr_id)
+    }
+
+    /// Removes up to `limit` results that were stored at or before
+    /// `max_height`, oldest first. Returns how many were removed.
+    pub fn prune(&self, store: &mut dyn Storage, max_height: u64, limit: usize) -> StdResult<usize> {
+        let expired = self
+            .by_height
+            .keys(
+                store,
+                None,
+                Some(Bound::exclusive((max_height + 1, &[0; 32]))),
+                Order::Ascending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for (height, dr_id) in &expired {
+            self.by_height.remove(store, (*height, dr_id));
+            self.hash_to_height.remove(store, dr_id);
+            self.results.remove(store, dr_id);
+        }
+
+        Ok(expired.len())
+    }
+}
@@ -4,12 +4,14 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Storage;
 use cw_storage_plus::Bound;
 
-use super::*;
+use super::{consts::MAX_DATA_RESULTS_PRUNED_PER_CALL, *};
 use crate::msgs::sorted_set::IndexKey;
 mod data_requests_map;
 use data_requests_map::{new_enumerable_status_map, DataRequestsMap};
 mod timeouts;
 use timeouts::Timeouts;
+mod data_results;
+use data_results::DataResults;
 
 /// Governance-controlled timeout configuration parameters.
 pub const DR_CONFIG: Item<DrConfig> = Item::new("dr_config");
@@ -25,12 +27,40 @@ pub struct Escrow {
 /// Maps a data request ID to the staked funds.
 pub const DR_ESCROW: Map<&Hash, Escrow> = Map::new("dr_staked_funds");
 
+/// The contract to notify once a data request is removed.
+#[cw_serde]
+pub struct Callback {
+    pub contract:  Addr,
+    pub gas_limit: u64,
+}
+
+/// Maps a data request ID to its callback, if the poster asked for one.
+pub const DR_CALLBACKS: Map<&Hash, Callback> = Map::new("dr_callbacks");
+
+/// Maps a data request ID to the epoch of the executor snapshot it selects its
+/// executors from.
+pub const DR_EXECUTOR_SNAPSHOT: Map<&Hash, u64> = Map::new("dr_executor_snapshot");
+
 const DATA_REQUESTS: DataRequestsMap = new_enumerable_status_map!("data_request_pool");
 
+/// Results of removed data requests, kept for
+/// `data_result_retention_in_blocks`.
+const DATA_RESULTS: DataResults = DataResults {
+    results:        Map::new("data_results"),
+    by_height:      Map::new("data_results_by_height"),
+    hash_to_height: Map::new("data_results_hash_to_height"),
+};
+
 pub fn init_data_requests(store: &mut dyn Storage) -> Result<(), ContractError> {
     Ok(DATA_REQUESTS.initialize(store)?)
 }
 
+/// Migrates data requests posted before requests had their own timeouts.
+pub fn backfill_request_timeouts(store: &mut dyn Storage) -> Result<(), ContractError> {
+    let config = DR_CONFIG.load(store)?;
+    Ok(DATA_REQUESTS.backfill_timeouts(store, &config)?)
+}
+
 pub fn data_request_exists(deps: Deps, dr_id: Hash) -> bool {
     DATA_REQUESTS.has(deps.storage, &dr_id)
 }
@@ -43,10 +73,18 @@ pub fn load_request(store: &dyn Storage, dr_id: &Hash) -> StdResult<DataRequestC
     DATA_REQUESTS.get(store, dr_id)
 }
 
+pub fn request_status(store: &dyn Storage, dr_id: &Hash) -> StdResult<DataRequestStatus> {
+    DATA_REQUESTS.find_status(store, dr_id)
+}
+
 pub fn get_dr_expiration_height(store: &dyn Storage, dr_id: &Hash) -> StdResult<u64> {
     DATA_REQUESTS.timeouts.get_timeout_by_dr_id(store, dr_id)
 }
 
+pub fn may_get_dr_expiration_height(store: &dyn Storage, dr_id: &Hash) -> StdResult<Option<u64>> {
+    DATA_REQUESTS.timeouts.may_get_timeout_by_dr_id(store, dr_id)
+}
+
 pub fn post_request(
     store: &mut dyn Storage,
     current_height: u64,
@@ -70,6 +108,11 @@ pub fn commit(store: &mut dyn Storage, current_height: u64, dr_id: &Hash, dr: Da
     Ok(())
 }
 
+pub fn update_gas_price(store: &mut dyn Storage, dr_id: &Hash, dr: DataRequestContract) -> StdResult<()> {
+    // the sorted sets are keyed by gas price, so the request has to be re-indexed
+    DATA_REQUESTS.reindex(store, dr_id, dr)
+}
+
 pub fn requests_statuses(
     store: &dyn Storage,
     dr_ids: Vec<String>,
@@ -123,8 +166,49 @@ pub fn remove_request(store: &mut dyn Storage, dr_id: &Hash) -> StdResult<()> {
     Ok(())
 }
 
-pub fn expire_data_requests(store: &mut dyn Storage, current_height: u64) -> StdResult<Vec<String>> {
-    DATA_REQUESTS.expire_data_requests(store, current_height)
+pub fn cancel_request(store: &mut dyn Storage, dr_id: &Hash) -> StdResult<()> {
+    DATA_REQUESTS.cancel(store, dr_id)
+}
+
+pub fn save_data_result(
+    store: &mut dyn Storage,
+    current_height: u64,
+    dr_id: &Hash,
+    result: &DataResult,
+) -> StdResult<()> {
+    DATA_RESULTS.insert(store, current_height, dr_id, result)
+}
+
+pub fn may_load_data_result(store: &dyn Storage, dr_id: &Hash) -> StdResult<Option<DataResult>> {
+    DATA_RESULTS.may_get(store, dr_id)
+}
+
+pub fn load_data_results(store: &dyn Storage, dr_ids: Vec<String>) -> StdResult<HashMap<String, Option<DataResult>>> {
+    let mut results = HashMap::with_capacity(dr_ids.len());
+    for dr_id in dr_ids {
+        let result = DATA_RESULTS.may_get(store, &Hash::from_hex_str(&dr_id)?)?;
+        results.insert(dr_id, result);
+    }
+    Ok(results)
+}
+
+/// Removes results that have outlived the retention window, returning how
+/// many were removed.
+pub fn prune_data_results(store: &mut dyn Storage, current_height: u64, retention: u64) -> StdResult<usize> {
+    let Some(max_height) = current_height.checked_sub(retention) else {
+        return Ok(0);
+    };
+    DATA_RESULTS.prune(store, max_height, MAX_DATA_RESULTS_PRUNED_PER_CALL)
+}
+
+/// Expires up to `limit` timed out requests, returning their IDs and whether
+/// more timed out requests are left.
+pub fn expire_data_requests(
+    store: &mut dyn Storage,
+    current_height: u64,
+    limit: usize,
+) -> StdResult<(Vec<String>, bool)> {
+    DATA_REQUESTS.expire_data_requests(store, current_height, limit)
 }
 
 #[cfg(test)]
@@ -1,11 +1,13 @@
-use cosmwasm_std::{StdResult, Storage};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map, PrefixBound};
 use seda_common::types::Hash;
 
 pub struct Timeouts<'a> {
     pub timeouts:        Map<(u64, &'a Hash), ()>,
     // Need this so we can remove the timeout by dr_id
     pub hash_to_timeout: Map<&'a Hash, u64>,
+    /// The lowest timeout height that may still have requests left to expire.
+    pub cursor:          Item<u64>,
 }
 
 impl Timeouts<'_> {
@@ -22,19 +24,49 @@ impl Timeouts<'_> {
         Ok(())
     }
 
-    pub fn remove_by_timeout_height(&self, store: &mut dyn Storage, timeout_height: u64) -> StdResult<Vec<Hash>> {
-        // Once the new rust borrow checker is released:
-        // It would allow us to to not allocate all the dr_ids for the timeout_height at
-        // once.
+    /// Removes up to `limit` timeouts at or below `current_height`, oldest
+    /// first, so heights that were skipped are caught up on. Also returns
+    /// whether timeouts at or below `current_height` are left for a later call.
+    pub fn remove_expired(
+        &self,
+        store: &mut dyn Storage,
+        current_height: u64,
+        limit: usize,
+    ) -> StdResult<(Vec<Hash>, bool)> {
+        let cursor = self.cursor.may_load(store)?.unwrap_or_default();
+        if cursor > current_height {
+            return Ok((vec![], false));
+        }
 
-        // This call allocates all the dr_ids for the timeout_height at once...
-        let removed = self.get_all_by_timeout_height(store, timeout_height)?;
-        removed.iter().for_each(|hash| {
-            self.timeouts.remove(store, (timeout_height, hash));
+        // take one more than the limit to find out whether a backlog remains
+        let mut expired = self
+            .timeouts
+            .prefix_range(
+                store,
+                Some(PrefixBound::inclusive(cursor)),
+                Some(PrefixBound::inclusive(current_height)),
+                Order::Ascending,
+            )
+            .take(limit + 1)
+            .map(|item| item.map(|(key, _)| key))
+            .collect::<StdResult<Vec<_>>>()?;
+        let backlog = expired.len() > limit;
+        expired.truncate(limit);
+
+        for (timeout_height, hash) in &expired {
+            self.timeouts.remove(store, (*timeout_height, hash));
             self.hash_to_timeout.remove(store, hash);
-        });
+        }
+
+        // new timeouts are always above the current height, so once the backlog
+        // is cleared the next call can start right after it
+        let cursor = match expired.last() {
+            Some((timeout_height, _)) if backlog => *timeout_height,
+            _ => current_height + 1,
+        };
+        self.cursor.save(store, &cursor)?;
 
-        Ok(removed)
+        Ok((expired.into_iter().map(|(_, hash)| hash).collect(), backlog))
     }
 
     pub fn get_timeout_by_dr_id(&self, store: &dyn Storage, dr_id: &Hash) -> StdResult<u64> {
@@ -42,6 +74,11 @@ impl Timeouts<'_> {
         Ok(timeout_block)
     }
 
+    pub fn may_get_timeout_by_dr_id(&self, store: &dyn Storage, dr_id: &Hash) -> StdResult<Option<u64>> {
+        self.hash_to_timeout.may_load(store, dr_id)
+    }
+
+    #[cfg(test)]
     pub fn get_all_by_timeout_height(&self, store: &dyn Storage, timeout_height: u64) -> StdResult<Vec<Hash>> {
         let res: StdResult<Vec<_>> = self
             .timeouts
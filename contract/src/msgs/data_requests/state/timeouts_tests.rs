@@ -13,6 +13,7 @@ impl TestInfo<'_> {
         let timeouts: Timeouts = Timeouts {
             timeouts:        Map::new("timeouts"),
             hash_to_timeout: Map::new("hash_to_timeout"),
+            cursor:          Item::new("timeouts_cursor"),
         };
         Self { store, timeouts }
     }
@@ -28,10 +29,10 @@ impl TestInfo<'_> {
     }
 
     #[track_caller]
-    fn remove_by_timeout_height(&mut self, timeout_height: u64) {
+    fn remove_expired(&mut self, current_height: u64, limit: usize) -> (Vec<Hash>, bool) {
         self.timeouts
-            .remove_by_timeout_height(&mut self.store, timeout_height)
-            .unwrap();
+            .remove_expired(&mut self.store, current_height, limit)
+            .unwrap()
     }
 
     #[track_caller]
@@ -111,7 +112,7 @@ fn remove_by_timeout_height() {
     let retrieved = info.get_all_by_timeout_height(timeout);
     assert_eq!(retrieved.len(), num as usize);
 
-    info.remove_by_timeout_height(timeout);
+    info.remove_expired(timeout, 100);
     assert_eq!(info.get_all_by_timeout_height(timeout).len(), 0);
 }
 
@@ -156,7 +157,7 @@ fn remove_one_timeout_from_two() {
     let retrieved2 = info.get_all_by_timeout_height(timeout2);
     assert_eq!(retrieved2.len(), num as usize);
 
-    info.remove_by_timeout_height(timeout1);
+    info.remove_expired(timeout1, 100);
 
     let retrieved1 = info.get_all_by_timeout_height(timeout1);
     assert_eq!(retrieved1.len(), 0);
@@ -164,3 +165,35 @@ fn remove_one_timeout_from_two() {
     let retrieved2 = info.get_all_by_timeout_height(timeout2);
     assert_eq!(retrieved2.len(), num as usize);
 }
+
+#[test]
+fn remove_expired_catches_up_on_skipped_heights() {
+    let mut info = TestInfo::init();
+    info.insert(10, [1; 32]);
+    info.insert(11, [2; 32]);
+    info.insert(12, [3; 32]);
+    info.insert(13, [4; 32]);
+
+    // heights 10 and 11 were skipped
+    assert_eq!(info.remove_expired(12, 100), (vec![[1; 32], [2; 32], [3; 32]], false));
+    assert_eq!(info.get_all_by_timeout_height(13), vec![[4; 32]]);
+    assert_eq!(info.remove_expired(12, 100), (vec![], false));
+}
+
+#[test]
+fn remove_expired_is_bounded_by_limit() {
+    let mut info = TestInfo::init();
+    (0..5).for_each(|i| info.insert(10, [i; 32]));
+    info.insert(11, [5; 32]);
+    info.insert(20, [6; 32]);
+
+    // the cursor stays on a height until all of its timeouts are removed
+    assert_eq!(info.remove_expired(15, 2), (vec![[0; 32], [1; 32]], true));
+    assert_eq!(info.remove_expired(15, 2), (vec![[2; 32], [3; 32]], true));
+    assert_eq!(info.remove_expired(16, 2), (vec![[4; 32], [5; 32]], false));
+    assert_eq!(info.remove_expired(16, 2), (vec![], false));
+
+    // timeouts above the current height are left alone
+    assert_eq!(info.get_timeout_by_dr_id([6; 32]), 20);
+    assert_eq!(info.remove_expired(20, 2), (vec![[6; 32]], false));
+}
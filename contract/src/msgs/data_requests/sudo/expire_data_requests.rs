@@ -1,21 +1,80 @@
-use cosmwasm_std::{to_json_string, DepsMut, Env, Response};
-use seda_common::msgs::data_requests::sudo::expire_data_requests;
+use cosmwasm_std::{to_json_binary, to_json_string, DepsMut, Env, Event, Response};
+use seda_common::{
+    msgs::data_requests::sudo::expire_data_requests::{self, ExpireDataRequestsResponse},
+    types::{Hash, ToHexStr},
+};
 
 use super::{ContractError, SudoHandler};
-use crate::msgs::data_requests::state;
+use crate::{
+    contract::CONTRACT_VERSION,
+    msgs::{
+        data_requests::state,
+        staking::state::{executor_stats::update_executor_stats, jailing::record_missed_reveal, STAKERS},
+        PublicKey,
+    },
+    types::FromHexStr,
+};
 
 impl SudoHandler for expire_data_requests::Sudo {
-    /// Expires all data requests that have timed out
-    /// by moving them from whatever state they are in to the tallying state.
+    /// Expires data requests that have timed out, at this or any earlier
+    /// height, by moving them from whatever state they are in to the tallying
+    /// state. At most `max_expirations_per_call` requests are expired, and the
+    /// response reports whether any are left.
+    ///
+    /// Executors that committed on a request that timed out while revealing,
+    /// but never revealed, are counted as having missed a reveal.
     fn sudo(self, deps: DepsMut, env: Env) -> Result<Response, ContractError> {
-        let ids = state::expire_data_requests(deps.storage, env.block.height)?;
+        let limit = state::DR_CONFIG.load(deps.storage)?.max_expirations_per_call.get() as usize;
+        let (ids, backlog) = state::expire_data_requests(deps.storage, env.block.height, limit)?;
 
-        let response = Response::new().add_attribute("method", "expire-data-requests");
+        let mut response = Response::new().add_attribute("method", "expire-data-requests");
 
         if ids.is_empty() {
-            return Ok(response);
+            return Ok(response.set_data(to_json_binary(&ExpireDataRequestsResponse {
+                timed_out_drs: ids,
+                backlog,
+            })?));
         }
 
-        Ok(response.add_attribute("timed_out_drs", to_json_string(&ids)?))
+        for id in ids.iter() {
+            let dr = state::load_request(deps.storage, &Hash::from_hex_str(id)?)?;
+            // a request that never reached the replication factor timed out while
+            // committing
+            let revealing = dr.base.reveal_started();
+
+            let mut committers = dr.base.commits.keys().collect::<Vec<_>>();
+            committers.sort();
+
+            for committer in committers {
+                let public_key = STAKERS.resolve_key(deps.storage, PublicKey::from_hex_str(committer)?)?;
+                let missed_reveal = revealing && !dr.has_revealer(committer);
+                update_executor_stats(deps.storage, &public_key, |stats| {
+                    stats.timeouts += 1;
+                    if missed_reveal {
+                        stats.missed_reveals += 1;
+                    }
+                })?;
+
+                if !missed_reveal {
+                    continue;
+                }
+                if let Some(jailed_until) = record_missed_reveal(deps.storage, &public_key, env.block.height)? {
+                    response = response.add_event(Event::new("seda-executor-jailed").add_attributes([
+                        ("version", CONTRACT_VERSION.to_string()),
+                        ("identity", public_key.to_hex()),
+                        ("dr_id", id.clone()),
+                        ("jailed_until", jailed_until.to_string()),
+                    ]));
+                }
+            }
+        }
+
+        Ok(response
+            .add_attribute("timed_out_drs", to_json_string(&ids)?)
+            .add_attribute("expiry_backlog", backlog.to_string())
+            .set_data(to_json_binary(&ExpireDataRequestsResponse {
+                timed_out_drs: ids,
+                backlog,
+            })?))
     }
 }
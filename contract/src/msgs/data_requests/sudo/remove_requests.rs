@@ -1,19 +1,58 @@
 use std::collections::HashSet;
 
-use cosmwasm_std::{to_json_binary, BankMsg, Coin, DepsMut, Env, Event, Response, Uint128};
+use cosmwasm_std::{
+    to_json_binary,
+    to_json_string,
+    Api,
+    BankMsg,
+    Coin,
+    CosmosMsg,
+    DepsMut,
+    DistributionMsg,
+    Env,
+    Event,
+    Response,
+    Storage,
+    Uint128,
+};
 use seda_common::{
-    msgs::data_requests::sudo::{remove_requests, DistributionMessage},
+    msgs::data_requests::sudo::{
+        remove_requests,
+        DistributionDataProxyReward,
+        DistributionExecutorSlash,
+        DistributionMessage,
+        DistributionSummary,
+        FailedPayout,
+        FailedPayoutReason,
+        RemoveDataRequestStatus,
+        RemoveDataRequestsResponse,
+        RemovedDataRequest,
+    },
     types::{Hash, ToHexStr},
 };
+use serde::Serialize;
 
 use super::{ContractError, SudoHandler};
 use crate::{
+    consts::BPS_DENOMINATOR,
     msgs::{
-        data_requests::state::{self, Escrow, DR_ESCROW},
+        data_requests::{
+            callback::callback_msg,
+            state::{self, Escrow, DR_CALLBACKS, DR_CONFIG, DR_ESCROW, DR_EXECUTOR_SNAPSHOT},
+        },
+        owner::state::TREASURY,
         staking::{
             execute::staking_events::create_executor_event,
-            state::{STAKERS, STAKING_CONFIG},
+            state::{
+                delegations::distribute_delegator_rewards,
+                effective_stake,
+                executor_snapshots::release_snapshot,
+                executor_stats::update_executor_stats,
+                STAKERS,
+                STAKING_CONFIG,
+            },
         },
+        transactional,
         PublicKey,
     },
     state::TOKEN,
@@ -35,42 +74,168 @@ fn burn(amount: Uint128, token: &str, escrow: &mut Escrow) -> BankMsg {
     }
 }
 
-// TODO: use this everywhere we used to build json strings
-macro_rules! json_str {
-    ($( $key:tt : $value:expr ),* $(,)?) => {
-        format!(
-            "{{ {} }}",
-            [ $( format!("\"{}\": \"{}\"", $key, $value) ),* ].join(", ")
-        )
+/// The `executor_reward` event attribute.
+#[derive(Serialize)]
+struct ExecutorRewardAttribute<'a> {
+    amount:            Uint128,
+    topped_up:         Uint128,
+    delegators_reward: Uint128,
+    restaked:          Uint128,
+    identity:          &'a str,
+}
+
+/// The `treasury` event attribute.
+#[derive(Serialize)]
+struct TreasuryAttribute<'a> {
+    amount:  Uint128,
+    address: &'a str,
+}
+
+fn not_removed(dr_id: String, status: RemoveDataRequestStatus) -> RemovedDataRequest {
+    RemovedDataRequest {
+        dr_id,
+        status,
+        distribution: None,
+    }
+}
+
+/// How an executor reward was paid out.
+struct ExecutorRewardPayout {
+    public_key:        PublicKey,
+    topped_up:         Uint128,
+    /// The reward left after the top up, including the delegators' share.
+    remaining_reward:  Uint128,
+    delegators_reward: Uint128,
+    /// The part of the executor's own reward added to their stake.
+    restaked:          Uint128,
+}
+
+/// Pays `amount` to an executor, topping up their stake first if it is below
+/// the minimum stake. Returns `None` if the executor is not a staker.
+fn reward_executor(
+    store: &mut dyn Storage,
+    public_key: PublicKey,
+    amount: Uint128,
+    minimum_stake: &Uint128,
+) -> Result<Option<ExecutorRewardPayout>, ContractError> {
+    // requests committed to before a key rotation still pay out to the new key
+    let public_key = STAKERS.resolve_key(store, public_key)?;
+    let Some(mut staker) = STAKERS.may_get_staker(store, &public_key)? else {
+        return Ok(None);
+    };
+
+    let (remaining_reward, topped_up) = if &effective_stake(&staker) < minimum_stake {
+        // top the staker up to minimum stake from the amount in the reward & escrow
+        let top_up = minimum_stake.saturating_sub(effective_stake(&staker));
+        let top_up = top_up.min(amount);
+        staker.tokens_staked += top_up;
+
+        // Remaining reward after top-up
+        (amount.saturating_sub(top_up), top_up)
+    } else {
+        (amount, 0u128.into())
+    };
+
+    // delegators share the part of the reward earned by their tokens, minus the
+    // executor's commission
+    let delegators_reward = if staker.tokens_delegated.is_zero() {
+        Uint128::zero()
+    } else {
+        let delegators_share = remaining_reward.multiply_ratio(staker.tokens_delegated, effective_stake(&staker));
+        delegators_share - delegators_share.multiply_ratio(staker.commission_rate_bps, BPS_DENOMINATOR)
+    };
+    if !delegators_reward.is_zero() {
+        distribute_delegator_rewards(store, &public_key, delegators_reward, staker.tokens_delegated)?;
+    }
+
+    // the executor's own reward is restaked according to their reward policy, the
+    // rest is sent to the staker pending withdrawal
+    let executor_reward = remaining_reward - delegators_reward;
+    let restaked = executor_reward.multiply_ratio(staker.restake_ratio_bps, BPS_DENOMINATOR);
+    staker.tokens_staked += restaked;
+    staker.tokens_pending_withdrawal += executor_reward - restaked;
+    STAKERS.update(store, public_key.clone(), &staker)?;
+
+    update_executor_stats(store, &public_key, |stats| {
+        stats.rewards_received += amount;
+    })?;
+
+    Ok(Some(ExecutorRewardPayout {
+        public_key,
+        topped_up,
+        remaining_reward,
+        delegators_reward,
+        restaked,
+    }))
+}
+
+/// Slashes up to `amount` from an executor, returning their current public key
+/// and how much was slashed. Returns `None` if the executor is not a staker.
+///
+/// Only the executor's own tokens are slashed. Delegators are exempt, even
+/// though their tokens count towards the executor's effective stake.
+fn slash_executor(
+    store: &mut dyn Storage,
+    public_key: PublicKey,
+    amount: Uint128,
+) -> Result<Option<(PublicKey, Uint128)>, ContractError> {
+    // requests committed to before a key rotation still pay out to the new key
+    let public_key = STAKERS.resolve_key(store, public_key)?;
+    let Some(mut staker) = STAKERS.may_get_staker(store, &public_key)? else {
+        return Ok(None);
     };
+
+    // take from the stake first, then from what is still unbonding and finally
+    // from what is pending withdrawal
+    let from_staked = amount.min(staker.tokens_staked);
+    staker.tokens_staked -= from_staked;
+    let mut slashed = from_staked;
+    for entry in staker.unbonding.iter_mut() {
+        let from_entry = (amount - slashed).min(entry.amount);
+        entry.amount -= from_entry;
+        slashed += from_entry;
+    }
+    staker.unbonding.retain(|entry| !entry.amount.is_zero());
+    let from_pending = (amount - slashed).min(staker.tokens_pending_withdrawal);
+    staker.tokens_pending_withdrawal -= from_pending;
+    slashed += from_pending;
+
+    STAKERS.update(store, public_key.clone(), &staker)?;
+
+    Ok(Some((public_key, slashed)))
 }
 
+/// Processes the distribution messages of a request in order and removes it.
+/// A message that fails is skipped without touching storage, so it can't
+/// stop the rest of the request from being processed.
 fn remove_request_and_process_distributions(
     dr_id_str: String,
     messages: &[DistributionMessage],
-    deps: &mut DepsMut,
+    store: &mut dyn Storage,
+    api: &dyn Api,
     token: &str,
     minimum_stake: &Uint128,
-) -> Result<(Event, Vec<BankMsg>, HashSet<PublicKey>, u8), ContractError> {
+    has_result: bool,
+) -> Result<(Event, Vec<CosmosMsg>, HashSet<PublicKey>, RemovedDataRequest), ContractError> {
     let mut event = Event::new("seda-remove-dr");
 
     let Ok(dr_id) = Hash::from_hex_str(&dr_id_str) else {
         return Ok((
-            event.add_attribute("invalid_dr_id", dr_id_str),
+            event.add_attribute("invalid_dr_id", dr_id_str.clone()),
             vec![],
             HashSet::new(),
-            1,
+            not_removed(dr_id_str, RemoveDataRequestStatus::InvalidDrId),
         ));
     };
-    let Ok(dr) = state::load_request(deps.storage, &dr_id) else {
+    let Ok(dr) = state::load_request(store, &dr_id) else {
         return Ok((
-            event.add_attribute("dr_not_found", dr_id_str),
+            event.add_attribute("dr_not_found", dr_id_str.clone()),
             vec![],
             HashSet::new(),
-            2,
+            not_removed(dr_id_str, RemoveDataRequestStatus::NotFound),
         ));
     };
-    let mut dr_escrow = DR_ESCROW.load(deps.storage, &dr_id)?;
+    let mut dr_escrow = DR_ESCROW.load(store, &dr_id)?;
 
     event = event.add_attributes([
         ("dr_id", dr_id_str.clone()),
@@ -78,15 +243,28 @@ fn remove_request_and_process_distributions(
     ]);
 
     // add 1 so we can account for the refund message that may be sent
-    let mut bank_messages = Vec::new();
+    let mut cosmos_messages = Vec::new();
     let mut stakers_effected = HashSet::new();
+    let mut summary = DistributionSummary::default();
 
     // We need to send messages in the order given.
-    'process_message: for message in messages {
-        // No reason to keep processing if the escrowed amount is zero
-        if dr_escrow.amount.is_zero() {
-            event = event.add_attribute("escrow-emptied-early", "true");
-            break;
+    let mut escrow_emptied = false;
+    'process_message: for (index, message) in messages.iter().enumerate() {
+        let failed_payout = |recipient: &str, amount: Uint128, reason: FailedPayoutReason| FailedPayout {
+            index: index as u32,
+            recipient: recipient.to_string(),
+            amount,
+            reason,
+        };
+
+        // No reason to keep processing payouts if the escrowed amount is zero, slashes
+        // are paid from the executor's stake so they still apply
+        if dr_escrow.amount.is_zero() && !matches!(message, DistributionMessage::Slash(_)) {
+            if !escrow_emptied {
+                event = event.add_attribute("escrow-emptied-early", "true");
+                escrow_emptied = true;
+            }
+            continue;
         }
 
         // Regardless of the message type we first need to get the min of the escrowed
@@ -95,145 +273,317 @@ fn remove_request_and_process_distributions(
         match &message {
             DistributionMessage::Burn(distribution_burn) => {
                 let amount_to_burn = distribution_burn.amount.min(dr_escrow.amount);
-                bank_messages.push(burn(amount_to_burn, token, &mut dr_escrow));
+                cosmos_messages.push(burn(amount_to_burn, token, &mut dr_escrow).into());
+                summary.burned += amount_to_burn;
                 event = event.add_attribute("burn", amount_to_burn.to_string());
             }
             DistributionMessage::DataProxyReward(distribution_send) => {
                 let amount_to_reward = distribution_send.amount.min(dr_escrow.amount);
 
-                if let Ok(addr) = deps.api.addr_validate(&distribution_send.payout_address) {
-                    bank_messages.push(BankMsg::Send {
-                        to_address: addr.to_string(),
-                        amount:     vec![amount_to_tokens(amount_to_reward, token)],
-                    });
+                if let Ok(addr) = api.addr_validate(&distribution_send.payout_address) {
+                    cosmos_messages.push(
+                        BankMsg::Send {
+                            to_address: addr.to_string(),
+                            amount:     vec![amount_to_tokens(amount_to_reward, token)],
+                        }
+                        .into(),
+                    );
                     dr_escrow.amount = dr_escrow.amount.saturating_sub(amount_to_reward);
+                    summary.rewarded += amount_to_reward;
 
                     event = event.add_attribute(
                         "data_proxy_reward",
-                        json_str!(
-                            "amount": amount_to_reward,
-                            "payout_address": distribution_send.payout_address,
-                            "public_key": distribution_send.public_key,
-                        ),
+                        to_json_string(&DistributionDataProxyReward {
+                            payout_address: distribution_send.payout_address.clone(),
+                            amount:         amount_to_reward,
+                            public_key:     distribution_send.public_key.clone(),
+                        })?,
                     );
                 } else {
-                    bank_messages.push(burn(amount_to_reward, token, &mut dr_escrow));
-                    event = event.add_attribute(
-                        "data_proxy_reward_invalid_address",
-                        json_str!(
-                            "payout_address": distribution_send.payout_address,
-                            "burn_amount": amount_to_reward,
-                            "public_key": distribution_send.public_key,
-                        ),
+                    cosmos_messages.push(burn(amount_to_reward, token, &mut dr_escrow).into());
+                    summary.burned += amount_to_reward;
+                    let failed = failed_payout(
+                        &distribution_send.payout_address,
+                        amount_to_reward,
+                        FailedPayoutReason::InvalidPayoutAddress,
                     );
+                    event = event.add_attribute("data_proxy_reward_invalid_address", to_json_string(&failed)?);
+                    summary.failed_payouts.push(failed);
                 }
             }
             DistributionMessage::ExecutorReward(distribution_executor_reward) => {
                 let amount_to_reward = distribution_executor_reward.amount.min(dr_escrow.amount);
 
                 let Ok(public_key) = PublicKey::from_hex_str(&distribution_executor_reward.identity) else {
-                    bank_messages.push(burn(amount_to_reward, token, &mut dr_escrow));
-                    event = event.add_attribute(
-                        "executor_reward_invalid_identity",
-                        json_str!(
-                            "identity": distribution_executor_reward.identity,
-                            "burn_amount": amount_to_reward,
-                        ),
+                    cosmos_messages.push(burn(amount_to_reward, token, &mut dr_escrow).into());
+                    summary.burned += amount_to_reward;
+                    let failed = failed_payout(
+                        &distribution_executor_reward.identity,
+                        amount_to_reward,
+                        FailedPayoutReason::InvalidIdentity,
                     );
+                    event = event.add_attribute("executor_reward_invalid_identity", to_json_string(&failed)?);
+                    summary.failed_payouts.push(failed);
                     continue 'process_message;
                 };
 
-                let Ok(mut staker) = STAKERS.get_staker(deps.storage, &public_key) else {
-                    bank_messages.push(burn(amount_to_reward, token, &mut dr_escrow));
-                    event = event.add_attribute(
-                        "executor_reward_invalid_identity",
-                        json_str!(
-                            "identity": distribution_executor_reward.identity,
-                            "burn_amount": amount_to_reward,
-                        ),
-                    );
-                    continue 'process_message;
+                let reward = transactional(store, |store| {
+                    reward_executor(store, public_key, amount_to_reward, minimum_stake)
+                });
+                let reward = match reward {
+                    Ok(Some(reward)) => reward,
+                    Ok(None) => {
+                        cosmos_messages.push(burn(amount_to_reward, token, &mut dr_escrow).into());
+                        summary.burned += amount_to_reward;
+                        let failed = failed_payout(
+                            &distribution_executor_reward.identity,
+                            amount_to_reward,
+                            FailedPayoutReason::UnknownExecutor,
+                        );
+                        event = event.add_attribute("executor_reward_invalid_identity", to_json_string(&failed)?);
+                        summary.failed_payouts.push(failed);
+                        continue 'process_message;
+                    }
+                    Err(err) => {
+                        // nothing was written, so the reward is burned like any other failed payout
+                        cosmos_messages.push(burn(amount_to_reward, token, &mut dr_escrow).into());
+                        summary.burned += amount_to_reward;
+                        let failed = failed_payout(
+                            &distribution_executor_reward.identity,
+                            amount_to_reward,
+                            FailedPayoutReason::StorageError,
+                        );
+                        event = event.add_attributes([
+                            ("executor_reward_failed", to_json_string(&failed)?),
+                            ("executor_reward_error", err.to_string()),
+                        ]);
+                        summary.failed_payouts.push(failed);
+                        continue 'process_message;
+                    }
                 };
 
-                let (remaining_reward, topped_up) = if &staker.tokens_staked < minimum_stake {
-                    // top the staker up to minimum stake from the amount in the reward & escrow
-                    let top_up = minimum_stake.saturating_sub(staker.tokens_staked);
-                    let top_up = top_up.min(amount_to_reward);
-                    staker.tokens_staked += top_up;
-                    dr_escrow.amount = dr_escrow.amount.saturating_sub(top_up);
+                dr_escrow.amount = dr_escrow.amount.saturating_sub(amount_to_reward);
+                stakers_effected.insert(reward.public_key);
+                summary.rewarded += reward.remaining_reward;
+                summary.topped_up += reward.topped_up;
 
-                    // Remaining reward after top-up
-                    (amount_to_reward.saturating_sub(top_up), top_up)
-                } else {
-                    (amount_to_reward, 0u128.into())
-                };
+                event = event.add_attribute(
+                    "executor_reward",
+                    to_json_string(&ExecutorRewardAttribute {
+                        amount:            reward.remaining_reward,
+                        topped_up:         reward.topped_up,
+                        delegators_reward: reward.delegators_reward,
+                        restaked:          reward.restaked,
+                        identity:          &distribution_executor_reward.identity,
+                    })?,
+                );
+            }
+            DistributionMessage::CommunityPool(distribution_community_pool) => {
+                let amount_to_fund = distribution_community_pool.amount.min(dr_escrow.amount);
+                cosmos_messages.push(
+                    DistributionMsg::FundCommunityPool {
+                        amount: vec![amount_to_tokens(amount_to_fund, token)],
+                    }
+                    .into(),
+                );
+                dr_escrow.amount = dr_escrow.amount.saturating_sub(amount_to_fund);
+                summary.community_pool += amount_to_fund;
+                event = event.add_attribute("community_pool", amount_to_fund.to_string());
+            }
+            DistributionMessage::Treasury(distribution_treasury) => {
+                let amount_to_pay = distribution_treasury.amount.min(dr_escrow.amount);
 
-                // send remaining reward to the staker pending withdrawal
-                staker.tokens_pending_withdrawal += remaining_reward;
-                dr_escrow.amount = dr_escrow.amount.saturating_sub(remaining_reward);
+                let Some(treasury) = TREASURY.may_load(store)? else {
+                    cosmos_messages.push(burn(amount_to_pay, token, &mut dr_escrow).into());
+                    summary.burned += amount_to_pay;
+                    let failed = failed_payout("", amount_to_pay, FailedPayoutReason::TreasuryNotSet);
+                    event = event.add_attribute("treasury_not_set", to_json_string(&failed)?);
+                    summary.failed_payouts.push(failed);
+                    continue 'process_message;
+                };
 
-                if STAKERS.update(deps.storage, public_key.clone(), &staker).is_err() {
-                    todo!("what should we do here?");
-                    // continue 'process_message;
+                cosmos_messages.push(
+                    BankMsg::Send {
+                        to_address: treasury.to_string(),
+                        amount:     vec![amount_to_tokens(amount_to_pay, token)],
+                    }
+                    .into(),
+                );
+                dr_escrow.amount = dr_escrow.amount.saturating_sub(amount_to_pay);
+                summary.treasury += amount_to_pay;
+                event = event.add_attribute(
+                    "treasury",
+                    to_json_string(&TreasuryAttribute {
+                        amount:  amount_to_pay,
+                        address: treasury.as_str(),
+                    })?,
+                );
+            }
+            DistributionMessage::Slash(distribution_slash) => {
+                let Ok(public_key) = PublicKey::from_hex_str(&distribution_slash.identity) else {
+                    event = event.add_attribute("executor_slash_invalid_identity", to_json_string(distribution_slash)?);
+                    continue 'process_message;
                 };
 
+                let slash = transactional(store, |store| {
+                    slash_executor(store, public_key, distribution_slash.amount)
+                });
+                let (public_key, slashed) = match slash {
+                    Ok(Some(slash)) => slash,
+                    Ok(None) => {
+                        event =
+                            event.add_attribute("executor_slash_invalid_identity", to_json_string(distribution_slash)?);
+                        continue 'process_message;
+                    }
+                    Err(err) => {
+                        event = event.add_attributes([
+                            ("executor_slash_failed", to_json_string(distribution_slash)?),
+                            ("executor_slash_error", err.to_string()),
+                        ]);
+                        continue 'process_message;
+                    }
+                };
                 stakers_effected.insert(public_key);
 
+                if !slashed.is_zero() {
+                    cosmos_messages.push(
+                        BankMsg::Burn {
+                            amount: vec![amount_to_tokens(slashed, token)],
+                        }
+                        .into(),
+                    );
+                }
+                summary.slashed += slashed;
                 event = event.add_attribute(
-                    "executor_reward",
-                    json_str!(
-                        "amount": remaining_reward,
-                        "topped_up": topped_up,
-                        "identity": distribution_executor_reward.identity,
-                    ),
+                    "executor_slash",
+                    to_json_string(&DistributionExecutorSlash {
+                        amount:   slashed,
+                        identity: distribution_slash.identity.clone(),
+                    })?,
                 );
             }
         }
     }
 
+    // the poster paid for the gas of their callback when posting, which is burned
+    // once the callback is sent with the data result. It is taken from what the
+    // distribution messages left, so it never cuts into their payouts.
+    if has_result {
+        if let Some(callback) = DR_CALLBACKS.may_load(store, &dr_id)? {
+            let callback_fee = Uint128::from(callback.gas_limit)
+                .saturating_mul(dr.base.gas_price)
+                .min(dr_escrow.amount);
+            if !callback_fee.is_zero() {
+                cosmos_messages.push(burn(callback_fee, token, &mut dr_escrow).into());
+                summary.burned += callback_fee;
+                event = event.add_attribute("callback_fee", callback_fee.to_string());
+            }
+        }
+    }
+
     if !dr_escrow.amount.is_zero() {
-        bank_messages.push(BankMsg::Send {
-            to_address: dr_escrow.poster.to_string(),
-            amount:     vec![amount_to_tokens(dr_escrow.amount, token)],
-        });
+        cosmos_messages.push(
+            BankMsg::Send {
+                to_address: dr_escrow.poster.to_string(),
+                amount:     vec![amount_to_tokens(dr_escrow.amount, token)],
+            }
+            .into(),
+        );
         event = event.add_attribute("refund", dr_escrow.amount.to_string());
+        summary.refunded = dr_escrow.amount;
     }
 
-    if state::remove_request(deps.storage, &dr_id).is_err() {
-        event = event.add_attribute("failed_to_remove_dr", dr_id_str);
-    };
-    DR_ESCROW.remove(deps.storage, &dr_id);
+    state::remove_request(store, &dr_id)?;
+    DR_ESCROW.remove(store, &dr_id);
+    if let Some(epoch) = DR_EXECUTOR_SNAPSHOT.may_load(store, &dr_id)? {
+        release_snapshot(store, epoch)?;
+        DR_EXECUTOR_SNAPSHOT.remove(store, &dr_id);
+    }
 
-    Ok((event, bank_messages, stakers_effected, 0))
+    Ok((
+        event,
+        cosmos_messages,
+        stakers_effected,
+        RemovedDataRequest {
+            dr_id:        dr_id_str,
+            status:       RemoveDataRequestStatus::Removed,
+            distribution: Some(summary),
+        },
+    ))
 }
 
 impl SudoHandler for remove_requests::Sudo {
-    fn sudo(self, mut deps: DepsMut, _: Env) -> Result<Response, ContractError> {
+    fn sudo(mut self, deps: DepsMut, env: Env) -> Result<Response, ContractError> {
         let token = TOKEN.load(deps.storage)?;
         let minimum_stake = STAKING_CONFIG.load(deps.storage)?.minimum_stake;
         let mut response = Response::new();
 
+        let retention = DR_CONFIG.load(deps.storage)?.data_result_retention_in_blocks.get() as u64;
+        let pruned = state::prune_data_results(deps.storage, env.block.height, retention)?;
+        if pruned > 0 {
+            response = response.add_attribute("pruned_data_results", pruned.to_string());
+        }
+
         let mut all_stakers_effected = HashSet::new();
-        let mut dr_ids_with_status_codes = Vec::new();
-        for (dr_id, removal_details) in self.requests.into_iter().map(|(dr_id, messages)| {
-            (
-                dr_id.clone(),
-                remove_request_and_process_distributions(dr_id, &messages, &mut deps, &token, &minimum_stake),
-            )
-        }) {
-            let (event, bank_messages, stakers_effected, status_code) = removal_details?;
+        let mut removed_requests = Vec::with_capacity(self.requests.len());
+        for (dr_id, messages) in self.requests {
+            // a request that fails is left untouched, so the rest of the batch can still be
+            // removed
+            let processed = transactional(deps.storage, |store| {
+                remove_request_and_process_distributions(
+                    dr_id.clone(),
+                    &messages,
+                    store,
+                    deps.api,
+                    &token,
+                    &minimum_stake,
+                    self.results.contains_key(&dr_id),
+                )
+            });
+            let (mut event, cosmos_messages, stakers_effected, removed) = match processed {
+                Ok(processed) => processed,
+                Err(err) => (
+                    Event::new("seda-remove-dr")
+                        .add_attributes([("dr_id", dr_id.clone()), ("failed", err.to_string())]),
+                    vec![],
+                    HashSet::new(),
+                    not_removed(dr_id, RemoveDataRequestStatus::Failed),
+                ),
+            };
             all_stakers_effected.extend(stakers_effected);
-            response = response.add_event(event).add_messages(bank_messages);
-            dr_ids_with_status_codes.push((dr_id, status_code));
+            response = response.add_messages(cosmos_messages);
+
+            // only requests that were actually removed have a result to keep or deliver
+            if removed.status == RemoveDataRequestStatus::Removed {
+                let hash = Hash::from_hex_str(&removed.dr_id)?;
+                let result = self.results.remove(&removed.dr_id);
+
+                if let Some(callback) = DR_CALLBACKS.may_load(deps.storage, &hash)? {
+                    DR_CALLBACKS.remove(deps.storage, &hash);
+                    event = event.add_attribute("callback_contract", callback.contract.to_string());
+                    match &result {
+                        Some(result) => response = response.add_submessage(callback_msg(callback, &hash, result)?),
+                        None => event = event.add_attribute("callback_skipped", "missing data result"),
+                    }
+                }
+
+                if let Some(result) = result {
+                    state::save_data_result(deps.storage, env.block.height, &hash, &result)?;
+                }
+            }
+            response = response.add_event(event);
+            removed_requests.push(removed);
         }
 
         for staker in all_stakers_effected {
             response = response.add_event(create_executor_event(
                 STAKERS.get_staker(deps.storage, &staker)?,
                 staker.to_hex(),
-            ));
+            )?);
         }
 
-        Ok(response.set_data(to_json_binary(&dr_ids_with_status_codes)?))
+        Ok(response.set_data(to_json_binary(&RemoveDataRequestsResponse {
+            requests: removed_requests,
+        })?))
     }
 }
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use msgs::data_requests::sudo::{expire_data_requests, DistributionMessage};
+use msgs::data_requests::sudo::{
+    expire_data_requests::{self, ExpireDataRequestsResponse},
+    DistributionMessage,
+    RemoveDataRequestsResponse,
+};
 use seda_common::msgs::data_requests::execute::reveal_result::Execute as RevealMessage;
 use semver::{BuildMetadata, Prerelease, Version};
 use sha3::{Digest, Keccak256};
@@ -10,7 +14,11 @@ use super::{
     msgs::data_requests::{execute, query, sudo},
     *,
 };
-use crate::{msgs::data_requests::consts::min_post_dr_cost, TestAccount};
+use crate::{
+    consts::INITIAL_DR_CONFIG,
+    msgs::data_requests::consts::{callback_cost, min_post_dr_cost},
+    TestAccount,
+};
 
 pub fn calculate_dr_id_and_args(nonce: u128, replication_factor: u16) -> PostDataRequestArgs {
     let exec_program_id = nonce.to_string().hash().to_hex();
@@ -52,6 +60,9 @@ pub fn calculate_dr_id_and_args(nonce: u128, replication_factor: u16) -> PostDat
         replication_factor,
         consensus_filter,
         gas_price,
+        commit_timeout_in_blocks: None,
+        reveal_timeout_in_blocks: None,
+        executors: None,
     }
 }
 
@@ -91,6 +102,13 @@ pub fn construct_dr(
             height,
             posted_gas_price: Uint128::from(amount)
                 / (Uint128::from(dr_args.exec_gas_limit) + Uint128::from(dr_args.tally_gas_limit)),
+            commit_timeout_in_blocks: dr_args
+                .commit_timeout_in_blocks
+                .unwrap_or(INITIAL_DR_CONFIG.commit_timeout_in_blocks),
+            reveal_timeout_in_blocks: dr_args
+                .reveal_timeout_in_blocks
+                .unwrap_or(INITIAL_DR_CONFIG.reveal_timeout_in_blocks),
+            executors: dr_args.executors,
         },
         reveals: Default::default(),
     }
@@ -110,6 +128,7 @@ impl TestAccount {
             posted_dr,
             seda_payload: seda_payload.into(),
             payback_address: payback_address.into(),
+            callback: None,
         }
         .into();
 
@@ -131,6 +150,70 @@ impl TestAccount {
         Ok(res.dr_id)
     }
 
+    #[track_caller]
+    pub fn post_data_request_with_callback(
+        &self,
+        posted_dr: PostDataRequestArgs,
+        callback: DataRequestCallback,
+        env_height: u64,
+    ) -> Result<String, ContractError> {
+        let callback_gas_limit = callback.gas_limit;
+        let msg = execute::post_request::Execute {
+            posted_dr,
+            seda_payload: vec![].into(),
+            payback_address: vec![].into(),
+            callback: Some(callback),
+        }
+        .into();
+
+        if env_height < self.test_info.block_height() {
+            panic!("Invalid Test: Cannot post a data request in the past");
+        }
+        self.test_info.set_block_height(env_height);
+
+        let res: PostRequestResponsePayload =
+            self.test_info
+                .execute_with_funds(self, &msg, min_post_dr_cost() + callback_cost(callback_gas_limit))?;
+        Ok(res.dr_id)
+    }
+
+    #[track_caller]
+    pub fn post_data_requests(
+        &self,
+        requests: Vec<execute::post_request::Execute>,
+        env_height: u64,
+        funds: u128,
+    ) -> Result<Vec<PostDataRequestsResult>, ContractError> {
+        let msg = execute::post_requests::Execute { requests }.into();
+
+        if env_height < self.test_info.block_height() {
+            panic!("Invalid Test: Cannot post a data request in the past");
+        }
+        self.test_info.set_block_height(env_height);
+
+        self.test_info.execute_with_funds(self, &msg, funds)
+    }
+
+    #[track_caller]
+    pub fn top_up_data_request(&self, dr_id: &str, funds: u128) -> Result<(), ContractError> {
+        let msg = execute::top_up_request::Execute {
+            dr_id: dr_id.to_string(),
+        }
+        .into();
+
+        self.test_info.execute_with_funds(self, &msg, funds)
+    }
+
+    #[track_caller]
+    pub fn cancel_data_request(&self, dr_id: &str) -> Result<(), ContractError> {
+        let msg = execute::cancel_request::Execute {
+            dr_id: dr_id.to_string(),
+        }
+        .into();
+
+        self.test_info.execute(self, &msg)
+    }
+
     #[track_caller]
     pub fn can_executor_commit(&self, dr_id: &str, reveal_message: &RevealMessage) -> bool {
         let dr = self.get_data_request(dr_id).unwrap();
@@ -231,10 +314,29 @@ impl TestAccount {
         &self,
         dr_id: String,
         msgs: Vec<DistributionMessage>,
-    ) -> Result<Vec<(String, u8)>, ContractError> {
+    ) -> Result<RemoveDataRequestsResponse, ContractError> {
         let mut requests = HashMap::new();
         requests.insert(dr_id, msgs);
-        let msg = sudo::remove_requests::Sudo { requests }.into();
+        let msg = sudo::remove_requests::Sudo {
+            requests,
+            results: HashMap::new(),
+        }
+        .into();
+        self.test_info.sudo(&msg)
+    }
+
+    #[track_caller]
+    pub fn remove_data_request_with_result(
+        &self,
+        dr_id: String,
+        msgs: Vec<DistributionMessage>,
+        result: DataResult,
+    ) -> Result<RemoveDataRequestsResponse, ContractError> {
+        let mut requests = HashMap::new();
+        requests.insert(dr_id.clone(), msgs);
+        let mut results = HashMap::new();
+        results.insert(dr_id, result);
+        let msg = sudo::remove_requests::Sudo { requests, results }.into();
         self.test_info.sudo(&msg)
     }
 
@@ -242,8 +344,12 @@ impl TestAccount {
     pub fn remove_data_requests(
         &self,
         requests: HashMap<String, Vec<DistributionMessage>>,
-    ) -> Result<Vec<(String, u8)>, ContractError> {
-        let msg = sudo::remove_requests::Sudo { requests }.into();
+    ) -> Result<RemoveDataRequestsResponse, ContractError> {
+        let msg = sudo::remove_requests::Sudo {
+            requests,
+            results: HashMap::new(),
+        }
+        .into();
         self.test_info.sudo(&msg)
     }
 
@@ -306,7 +412,7 @@ impl TestAccount {
     }
 
     #[track_caller]
-    pub fn expire_data_requests(&self) -> Result<(), ContractError> {
+    pub fn expire_data_requests(&self) -> Result<ExpireDataRequestsResponse, ContractError> {
         let msg = expire_data_requests::Sudo {}.into();
         self.test_info.sudo(&msg)
     }
@@ -328,4 +434,29 @@ impl TestAccount {
             .query(query::QueryMsg::GetDataRequestsStatuses { dr_ids })
             .unwrap()
     }
+
+    #[track_caller]
+    pub fn get_data_result(&self, dr_id: &str) -> Option<DataResult> {
+        self.test_info
+            .query(query::QueryMsg::GetDataResult {
+                dr_id: dr_id.to_string(),
+            })
+            .unwrap()
+    }
+
+    #[track_caller]
+    pub fn get_data_request_executors(&self, dr_id: &str) -> Option<GetDataRequestExecutorsResponse> {
+        self.test_info
+            .query(query::QueryMsg::GetDataRequestExecutors {
+                dr_id: dr_id.to_string(),
+            })
+            .unwrap()
+    }
+
+    #[track_caller]
+    pub fn get_data_results(&self, dr_ids: Vec<String>) -> HashMap<String, Option<DataResult>> {
+        self.test_info
+            .query(query::QueryMsg::GetDataResults { dr_ids })
+            .unwrap()
+    }
 }
@@ -0,0 +1,349 @@
+use std::{collections::HashMap, rc::Rc};
+
+use cosmwasm_std::{
+    from_json,
+    to_json_binary,
+    Addr,
+    Binary,
+    Deps,
+    DepsMut,
+    Empty,
+    Env,
+    MessageInfo,
+    Response,
+    StdError,
+    StdResult,
+};
+use cw_multi_test::{ContractWrapper, Executor};
+use cw_storage_plus::Item;
+use seda_common::{
+    msgs::{
+        data_requests::{
+            execute,
+            sudo::{
+                self,
+                DistributionDataProxyReward,
+                DistributionMessage,
+                DistributionSummary,
+                RemoveDataRequestsResponse,
+            },
+            CallbackMsg,
+            DataRequestCallback,
+            DataResult,
+            PostRequestResponsePayload,
+            RevealBody,
+        },
+        ExecuteMsg,
+        SudoMsg,
+    },
+    types::HashSelf,
+};
+
+use crate::{
+    error::ContractError,
+    msgs::data_requests::{
+        consts::{callback_cost, min_post_dr_cost, MAX_CALLBACK_GAS_LIMIT},
+        test_helpers,
+    },
+    seda_to_aseda,
+    TestAccount,
+    TestInfo,
+};
+
+const LAST_CALLBACK: Item<CallbackMsg> = Item::new("last_callback");
+
+const CALLBACK_GAS_LIMIT: u64 = 200_000;
+
+/// Stores the last callback it received, and fails for non-zero exit codes.
+fn consumer_execute(deps: DepsMut, _env: Env, _info: MessageInfo, msg: CallbackMsg) -> StdResult<Response> {
+    let CallbackMsg::DataResult { exit_code, .. } = &msg;
+    if *exit_code != 0 {
+        return Err(StdError::generic_err("consumer rejected the result"));
+    }
+    LAST_CALLBACK.save(deps.storage, &msg)?;
+    Ok(Response::new())
+}
+
+fn consumer_instantiate(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn consumer_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&LAST_CALLBACK.may_load(deps.storage)?)
+}
+
+fn new_consumer(test_info: &Rc<TestInfo>) -> Addr {
+    let contract = Box::new(ContractWrapper::new(
+        consumer_execute,
+        consumer_instantiate,
+        consumer_query,
+    ));
+    let code_id = test_info.app_mut().store_code(contract);
+    test_info
+        .app_mut()
+        .instantiate_contract(code_id, test_info.creator().addr(), &Empty {}, &[], "consumer", None)
+        .unwrap()
+}
+
+fn last_callback(test_info: &TestInfo, consumer: &Addr) -> Option<CallbackMsg> {
+    test_info.app().wrap().query_wasm_smart(consumer, &Empty {}).unwrap()
+}
+
+#[track_caller]
+fn post_and_resolve(poster: &TestAccount, executor: &TestAccount, consumer: &Addr) -> String {
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let callback = DataRequestCallback {
+        contract:  consumer.to_string(),
+        gas_limit: CALLBACK_GAS_LIMIT,
+    };
+    let dr_id = poster.post_data_request_with_callback(dr, callback, 1).unwrap();
+
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        exit_code:         0,
+        gas_used:          0,
+        reveal:            "10".hash().into(),
+        proxy_public_keys: vec![],
+    };
+    let reveal_message = executor.create_reveal_message(reveal);
+    executor.commit_result(&dr_id, &reveal_message).unwrap();
+    executor.reveal_result(reveal_message).unwrap();
+
+    dr_id
+}
+
+fn data_result(dr_id: &str, exit_code: u32) -> DataResult {
+    DataResult {
+        id: "id".to_string(),
+        dr_id: dr_id.to_string(),
+        dr_block_height: 1,
+        version: "0.0.1".to_string(),
+        block_height: 2,
+        block_timestamp: 3,
+        exit_code,
+        gas_used: 10u128.into(),
+        result: b"result".into(),
+        payback_address: "".to_string(),
+        seda_payload: "".to_string(),
+        consensus: true,
+    }
+}
+
+#[test]
+fn callback_receives_result() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let consumer = new_consumer(&test_info);
+
+    let dr_id = post_and_resolve(&alice, &executor, &consumer);
+    let msg: SudoMsg = sudo::remove_requests::Sudo {
+        requests: HashMap::from([(dr_id.clone(), vec![])]),
+        results:  HashMap::from([(dr_id.clone(), data_result(&dr_id, 0))]),
+    }
+    .into();
+    let res = test_info.app_mut().wasm_sudo(test_info.contract_addr(), &msg).unwrap();
+
+    assert_eq!(
+        Some(CallbackMsg::DataResult {
+            dr_id:     dr_id.clone(),
+            exit_code: 0,
+            result:    b"result".into(),
+        }),
+        last_callback(&test_info, &consumer)
+    );
+
+    // the success is recorded as well
+    let event = res
+        .events
+        .iter()
+        .find(|e| e.ty == "wasm-seda-data-request-callback")
+        .unwrap();
+    assert!(event.attributes.iter().any(|a| a.key == "dr_id" && a.value == dr_id));
+    assert!(event.attributes.iter().any(|a| a.key == "success" && a.value == "true"));
+}
+
+#[test]
+fn callback_gas_is_paid_by_the_poster() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let consumer = new_consumer(&test_info);
+
+    // the callback's gas limit has to be paid for on top of the execution
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let msg: ExecuteMsg = execute::post_request::Execute {
+        posted_dr:       dr,
+        seda_payload:    vec![].into(),
+        payback_address: vec![].into(),
+        callback:        Some(DataRequestCallback {
+            contract:  consumer.to_string(),
+            gas_limit: CALLBACK_GAS_LIMIT,
+        }),
+    }
+    .into();
+    let res: Result<PostRequestResponsePayload, _> = test_info.execute_with_funds(&alice, &msg, min_post_dr_cost());
+    assert!(res.is_err_and(|e| matches!(e, ContractError::InsufficientFunds(..))));
+
+    // the fee is burned once the callback is sent
+    let dr_id = post_and_resolve(&alice, &executor, &consumer);
+    let removed = test_info
+        .creator()
+        .remove_data_request_with_result(dr_id.clone(), vec![], data_result(&dr_id, 0))
+        .unwrap();
+    let [removed] = removed.requests.try_into().unwrap();
+    let summary = removed.distribution.unwrap();
+    assert_eq!(callback_cost(CALLBACK_GAS_LIMIT), summary.burned.u128());
+    assert_eq!(
+        seda_to_aseda(22.into()) - callback_cost(CALLBACK_GAS_LIMIT),
+        test_info.executor_balance("alice")
+    );
+}
+
+#[test]
+fn callback_fee_is_taken_after_the_distributions() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let consumer = new_consumer(&test_info);
+    let proxy = test_info.new_account("proxy", 1);
+
+    let proxy_reward = |amount: u128| {
+        DistributionMessage::DataProxyReward(DistributionDataProxyReward {
+            payout_address: proxy.addr().to_string(),
+            amount:         amount.into(),
+            public_key:     "proxy".to_string(),
+        })
+    };
+
+    // the distributions are paid in full, the callback fee comes out of what is
+    // left
+    let dr_id = post_and_resolve(&alice, &executor, &consumer);
+    let msg: SudoMsg = sudo::remove_requests::Sudo {
+        requests: HashMap::from([(dr_id.clone(), vec![proxy_reward(min_post_dr_cost())])]),
+        results:  HashMap::from([(dr_id.clone(), data_result(&dr_id, 0))]),
+    }
+    .into();
+    let res = test_info.app_mut().wasm_sudo(test_info.contract_addr(), &msg).unwrap();
+
+    let event = res.events.iter().find(|e| e.ty == "wasm-seda-remove-dr").unwrap();
+    let position = |key: &str| event.attributes.iter().position(|a| a.key == key).unwrap();
+    assert!(position("data_proxy_reward") < position("callback_fee"));
+    let attribute = &event.attributes[position("data_proxy_reward")].value;
+    assert_eq!(
+        proxy_reward(min_post_dr_cost()),
+        DistributionMessage::DataProxyReward(from_json(attribute).unwrap())
+    );
+
+    let response: RemoveDataRequestsResponse = from_json(res.data.unwrap()).unwrap();
+    let [removed] = response.requests.try_into().unwrap();
+    assert_eq!(
+        Some(DistributionSummary {
+            rewarded: min_post_dr_cost().into(),
+            burned: callback_cost(CALLBACK_GAS_LIMIT).into(),
+            ..Default::default()
+        }),
+        removed.distribution
+    );
+
+    // distributions that use up the escrow leave nothing for the callback fee
+    let dr_id = post_and_resolve(&alice, &executor, &consumer);
+    let escrow = min_post_dr_cost() + callback_cost(CALLBACK_GAS_LIMIT);
+    let removed = test_info
+        .creator()
+        .remove_data_request_with_result(dr_id.clone(), vec![proxy_reward(escrow)], data_result(&dr_id, 0))
+        .unwrap();
+    let [removed] = removed.requests.try_into().unwrap();
+    assert_eq!(
+        Some(DistributionSummary {
+            rewarded: escrow.into(),
+            ..Default::default()
+        }),
+        removed.distribution
+    );
+}
+
+#[test]
+fn skipped_callback_gas_is_refunded() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let consumer = new_consumer(&test_info);
+
+    // without a data result the callback is never sent
+    let dr_id = post_and_resolve(&alice, &executor, &consumer);
+    test_info.creator().remove_data_request(dr_id, vec![]).unwrap();
+
+    assert!(last_callback(&test_info, &consumer).is_none());
+    assert_eq!(seda_to_aseda(22.into()), test_info.executor_balance("alice"));
+}
+
+#[test]
+fn failing_callback_does_not_block_removal() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let consumer = new_consumer(&test_info);
+
+    let dr_id = post_and_resolve(&alice, &executor, &consumer);
+
+    let msg: SudoMsg = sudo::remove_requests::Sudo {
+        requests: HashMap::from([(dr_id.clone(), vec![])]),
+        results:  HashMap::from([(dr_id.clone(), data_result(&dr_id, 1))]),
+    }
+    .into();
+    let res = test_info.app_mut().wasm_sudo(test_info.contract_addr(), &msg).unwrap();
+
+    // the failure is recorded
+    let event = res
+        .events
+        .iter()
+        .find(|e| e.ty == "wasm-seda-data-request-callback")
+        .unwrap();
+    assert!(event.attributes.iter().any(|a| a.key == "dr_id" && a.value == dr_id));
+    assert!(event
+        .attributes
+        .iter()
+        .any(|a| a.key == "success" && a.value == "false"));
+
+    // the request was still removed and the poster refunded, except for the
+    // callback's gas
+    assert!(alice.get_data_request(&dr_id).is_none());
+    assert!(last_callback(&test_info, &consumer).is_none());
+    assert_eq!(
+        seda_to_aseda(22.into()) - callback_cost(CALLBACK_GAS_LIMIT),
+        test_info.executor_balance("alice")
+    );
+}
+
+#[test]
+fn callback_gas_limit_too_high_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    test_info.new_executor("exec", 51, 1);
+    let consumer = new_consumer(&test_info);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let callback = DataRequestCallback {
+        contract:  consumer.to_string(),
+        gas_limit: MAX_CALLBACK_GAS_LIMIT + 1,
+    };
+    let res = alice.post_data_request_with_callback(dr, callback, 1);
+    assert!(res.is_err_and(|e| e == ContractError::CallbackGasLimitTooHigh(MAX_CALLBACK_GAS_LIMIT + 1)));
+}
+
+#[test]
+fn callback_to_invalid_address_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    test_info.new_executor("exec", 51, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let callback = DataRequestCallback {
+        contract:  "invalid".to_string(),
+        gas_limit: 200_000,
+    };
+    let res = alice.post_data_request_with_callback(dr, callback, 1);
+    assert!(res.is_err_and(|e| e == ContractError::InvalidAddress("invalid".to_string())));
+}
@@ -0,0 +1,115 @@
+use seda_common::{
+    msgs::data_requests::{DataRequestStatus, RevealBody},
+    types::{Hash, HashSelf},
+};
+
+use crate::{
+    error::ContractError,
+    msgs::data_requests::{consts::min_post_dr_cost, state::DR_ESCROW, test_helpers},
+    types::FromHexStr,
+    TestInfo,
+};
+
+#[test]
+fn cancel_works() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    test_info.new_executor("exec", 22, 1);
+
+    let balance_before = test_info.executor_balance("alice");
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    assert_eq!(balance_before - min_post_dr_cost(), test_info.executor_balance("alice"));
+
+    alice.cancel_data_request(&dr_id).unwrap();
+
+    // the escrow is refunded in full
+    assert_eq!(balance_before, test_info.executor_balance("alice"));
+    let escrow = DR_ESCROW
+        .may_load(
+            &*test_info.app().contract_storage(&test_info.contract_addr()),
+            &Hash::from_hex_str(&dr_id).unwrap(),
+        )
+        .unwrap();
+    assert!(escrow.is_none());
+
+    // the request is gone from the pool
+    assert!(alice.get_data_request(&dr_id).is_none());
+    let drs = alice.get_data_requests_by_status(DataRequestStatus::Committing, None, 10);
+    assert_eq!(0, drs.total);
+    assert!(drs.data_requests.is_empty());
+
+    // and from the timeouts, so expiring at its timeout height does nothing
+    test_info.set_block_height(51);
+    test_info.creator().expire_data_requests().unwrap();
+    let statuses = alice.get_data_requests_statuses(vec![dr_id.clone()]);
+    assert_eq!(None, statuses[&dr_id]);
+}
+
+#[test]
+fn cancelled_request_can_be_posted_again() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    test_info.new_executor("exec", 22, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr.clone(), vec![], vec![], 1, None).unwrap();
+    alice.cancel_data_request(&dr_id).unwrap();
+
+    let reposted_dr_id = alice.post_data_request(dr, vec![], vec![], 2, None).unwrap();
+    assert_eq!(dr_id, reposted_dr_id);
+}
+
+#[test]
+fn cancel_by_non_poster_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let res = bob.cancel_data_request(&dr_id);
+    assert!(res.is_err_and(|e| e == ContractError::NotDataRequestPoster));
+}
+
+#[test]
+fn cancel_after_commit_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let bob = test_info.new_executor("bob", 22, 1);
+    test_info.new_executor("carol", 22, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 2);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        exit_code:         0,
+        gas_used:          0,
+        reveal:            "10".hash().into(),
+        proxy_public_keys: vec![],
+    };
+    bob.commit_result(&dr_id, &bob.create_reveal_message(reveal)).unwrap();
+
+    // still committing, but an executor has already committed
+    let res = alice.cancel_data_request(&dr_id);
+    assert!(res.is_err_and(|e| e == ContractError::DataRequestHasCommits));
+}
+
+#[test]
+fn cancel_after_timeout_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    test_info.new_executor("exec", 22, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    test_info.set_block_height(51);
+    test_info.creator().expire_data_requests().unwrap();
+
+    let res = alice.cancel_data_request(&dr_id);
+    assert!(res.is_err_and(|e| e == ContractError::DataRequestNotCommitting));
+}
@@ -1,12 +1,18 @@
 use seda_common::{
     msgs::{
         data_requests::{DataRequestStatus, RevealBody},
-        staking::StakingConfig,
+        staking::{SelectionMode, StakingConfig},
     },
-    types::HashSelf,
+    types::{HashSelf, ToHexStr},
 };
 
-use crate::{consts::INITIAL_DR_CONFIG, msgs::data_requests::test_helpers, TestInfo};
+use crate::{
+    consts::INITIAL_DR_CONFIG,
+    error::ContractError,
+    msgs::data_requests::test_helpers,
+    new_public_key,
+    TestInfo,
+};
 
 #[test]
 #[should_panic(expected = "not found")]
@@ -94,8 +100,12 @@ fn fails_if_not_enough_staked() {
     let test_info = TestInfo::init();
 
     let new_config = StakingConfig {
-        minimum_stake:     200u8.into(),
-        allowlist_enabled: false,
+        minimum_stake:                 200u8.into(),
+        allowlist_enabled:             false,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
 
     // owner sets staking config
@@ -119,6 +129,42 @@ fn fails_if_not_enough_staked() {
     anyone.commit_result(&dr_id, &anyone_reveal_message).unwrap();
 }
 
+#[test]
+fn delegated_stake_counts_toward_commit() {
+    let test_info = TestInfo::init();
+    test_info
+        .creator()
+        .set_staking_config(StakingConfig {
+            minimum_stake:                 200u8.into(),
+            allowlist_enabled:             false,
+            unbonding_period_in_blocks:    0,
+            missed_reveals_jail_threshold: 0,
+            jail_duration_in_blocks:       0,
+            selection_mode:                SelectionMode::Uniform,
+        })
+        .unwrap();
+
+    // the executor only meets the minimum stake with a delegation
+    let anyone = test_info.new_executor("anyone", 22, 200);
+    let bob = test_info.new_account("bob", 1);
+    anyone.unstake_amount(Some(200)).unwrap();
+    bob.delegate(&anyone, 200).unwrap();
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = anyone.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let anyone_reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    let anyone_reveal_message = anyone.create_reveal_message(anyone_reveal);
+    anyone.commit_result(&dr_id, &anyone_reveal_message).unwrap();
+}
+
 #[test]
 fn works() {
     let test_info = TestInfo::init();
@@ -287,8 +333,12 @@ fn must_be_on_allowlist_to_commit() {
 
     // then we enable the allowlist
     let new_config = StakingConfig {
-        minimum_stake:     1u128.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 1u128.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
 
     // owner sets staking config
@@ -306,3 +356,88 @@ fn must_be_on_allowlist_to_commit() {
     let anyone_reveal_message = anyone.create_reveal_message(anyone_reveal);
     anyone.commit_result(&dr_id, &anyone_reveal_message).unwrap();
 }
+
+#[test]
+fn only_listed_executors_can_commit() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 22, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    // post a data request that only bob may execute
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    dr.executors = Some(vec![bob.pub_key_hex()]);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    assert!(!alice.is_executor_eligible(dr_id.clone()));
+    assert!(bob.is_executor_eligible(dr_id.clone()));
+
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    let alice_reveal_message = alice.create_reveal_message(reveal.clone());
+    let res = alice.commit_result(&dr_id, &alice_reveal_message);
+    assert!(res.is_err_and(|e| e == ContractError::NotAllowedExecutor));
+
+    let bob_reveal_message = bob.create_reveal_message(reveal);
+    bob.commit_result(&dr_id, &bob_reveal_message).unwrap();
+}
+
+#[test]
+fn backup_executors_come_from_listed_executors() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 22, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+    let carol = test_info.new_executor("carol", 22, 1);
+
+    // with enough blocks passed every listed executor becomes eligible, but
+    // never an unlisted one
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    dr.executors = Some(vec![bob.pub_key_hex(), carol.pub_key_hex()]);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    test_info.set_block_height(1 + 2 * INITIAL_DR_CONFIG.backup_delay_in_blocks.get() as u64 + 1);
+
+    assert!(!alice.is_executor_eligible(dr_id.clone()));
+    assert!(bob.is_executor_eligible(dr_id.clone()));
+    assert!(carol.is_executor_eligible(dr_id));
+}
+
+#[test]
+fn executors_limit_replication_factor() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 22, 1);
+    test_info.new_executor("bob", 22, 1);
+
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 2);
+    dr.executors = Some(vec![alice.pub_key_hex()]);
+    let res = alice.post_data_request(dr, vec![], vec![], 1, None);
+    assert!(res.is_err_and(|e| e == ContractError::DataRequestReplicationFactorTooHigh(1)));
+}
+
+#[test]
+fn duplicate_executors_fail() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 22, 1);
+
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    dr.executors = Some(vec![alice.pub_key_hex(), alice.pub_key_hex()]);
+    let res = alice.post_data_request(dr, vec![], vec![], 1, None);
+    assert!(res.is_err_and(|e| e == ContractError::DuplicateExecutor(alice.pub_key_hex())));
+}
+
+#[test]
+fn too_many_executors_fail() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 22, 1);
+
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let executors_limit = INITIAL_DR_CONFIG.executors_limit.get() as usize;
+    dr.executors = Some((0..=executors_limit).map(|_| new_public_key().1.to_hex()).collect());
+    let res = alice.post_data_request(dr, vec![], vec![], 1, None);
+    assert!(res
+        .is_err_and(|e| e == ContractError::TooManyExecutors(executors_limit + 1, INITIAL_DR_CONFIG.executors_limit)));
+}
@@ -0,0 +1,156 @@
+use seda_common::{
+    msgs::data_requests::{
+        sudo::{RemoveDataRequestStatus, RemovedDataRequest},
+        DataResult,
+        DrConfig,
+        RevealBody,
+    },
+    types::{HashSelf, ToHexStr, TryHashSelf},
+};
+
+use crate::{consts::INITIAL_DR_CONFIG, msgs::data_requests::test_helpers, TestAccount, TestInfo};
+
+fn data_result(dr_id: &str) -> DataResult {
+    DataResult {
+        id:              "id".to_string(),
+        dr_id:           dr_id.to_string(),
+        dr_block_height: 1,
+        version:         "0.0.1".to_string(),
+        block_height:    2,
+        block_timestamp: 3,
+        exit_code:       0,
+        gas_used:        10u128.into(),
+        result:          b"result".into(),
+        payback_address: "".to_string(),
+        seda_payload:    "".to_string(),
+        consensus:       true,
+    }
+}
+
+#[track_caller]
+fn post_and_resolve(test_info: &TestInfo, poster: &TestAccount, executor: &TestAccount, nonce: u128) -> String {
+    let height = test_info.block_height();
+    let dr = test_helpers::calculate_dr_id_and_args(nonce, 1);
+    let dr_id = poster.post_data_request(dr, vec![], vec![], height, None).unwrap();
+
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   height,
+        exit_code:         0,
+        gas_used:          0,
+        reveal:            "10".hash().into(),
+        proxy_public_keys: vec![],
+    };
+    let reveal_message = executor.create_reveal_message(reveal);
+    executor.commit_result(&dr_id, &reveal_message).unwrap();
+    executor.reveal_result(reveal_message).unwrap();
+
+    dr_id
+}
+
+#[test]
+fn removal_stores_data_result() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+
+    let dr_id = post_and_resolve(&test_info, &alice, &executor, 1);
+    assert_eq!(None, alice.get_data_result(&dr_id));
+
+    test_info
+        .creator()
+        .remove_data_request_with_result(dr_id.clone(), vec![], data_result(&dr_id))
+        .unwrap();
+
+    // the request is gone but its result is kept
+    assert!(alice.get_data_request(&dr_id).is_none());
+    assert_eq!(Some(data_result(&dr_id)), alice.get_data_result(&dr_id));
+}
+
+#[test]
+fn result_of_unknown_request_is_not_stored() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+
+    let dr_id = test_helpers::calculate_dr_id_and_args(1, 1)
+        .try_hash()
+        .unwrap()
+        .to_hex();
+    let res = test_info
+        .creator()
+        .remove_data_request_with_result(dr_id.clone(), vec![], data_result(&dr_id))
+        .unwrap();
+
+    assert_eq!(
+        vec![RemovedDataRequest {
+            dr_id:        dr_id.clone(),
+            status:       RemoveDataRequestStatus::NotFound,
+            distribution: None,
+        }],
+        res.requests
+    );
+    assert_eq!(None, alice.get_data_result(&dr_id));
+}
+
+#[test]
+fn get_data_results_works() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+
+    let dr_id1 = post_and_resolve(&test_info, &alice, &executor, 1);
+    let dr_id2 = post_and_resolve(&test_info, &alice, &executor, 2);
+    test_info
+        .creator()
+        .remove_data_request_with_result(dr_id1.clone(), vec![], data_result(&dr_id1))
+        .unwrap();
+
+    let results = alice.get_data_results(vec![dr_id1.clone(), dr_id2.clone()]);
+    assert_eq!(2, results.len());
+    assert_eq!(Some(data_result(&dr_id1)), results[&dr_id1]);
+    assert_eq!(None, results[&dr_id2]);
+}
+
+#[test]
+fn data_results_are_pruned_after_retention() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+
+    test_info
+        .creator()
+        .set_dr_config(DrConfig {
+            data_result_retention_in_blocks: 10.try_into().unwrap(),
+            ..INITIAL_DR_CONFIG
+        })
+        .unwrap();
+
+    // stored at height 1
+    test_info.set_block_height(1);
+    let dr_id1 = post_and_resolve(&test_info, &alice, &executor, 1);
+    test_info
+        .creator()
+        .remove_data_request_with_result(dr_id1.clone(), vec![], data_result(&dr_id1))
+        .unwrap();
+
+    // still within the retention window
+    test_info.set_block_height(10);
+    let dr_id2 = post_and_resolve(&test_info, &alice, &executor, 2);
+    test_info
+        .creator()
+        .remove_data_request_with_result(dr_id2.clone(), vec![], data_result(&dr_id2))
+        .unwrap();
+    assert!(alice.get_data_result(&dr_id1).is_some());
+
+    // the next removal prunes the first result
+    test_info.set_block_height(11);
+    let dr_id3 = post_and_resolve(&test_info, &alice, &executor, 3);
+    test_info
+        .creator()
+        .remove_data_request_with_result(dr_id3.clone(), vec![], data_result(&dr_id3))
+        .unwrap();
+
+    assert_eq!(None, alice.get_data_result(&dr_id1));
+    assert!(alice.get_data_result(&dr_id2).is_some());
+    assert!(alice.get_data_result(&dr_id3).is_some());
+}
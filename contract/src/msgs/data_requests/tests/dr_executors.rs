@@ -0,0 +1,91 @@
+use seda_common::{
+    msgs::data_requests::DataRequestExecutorRole,
+    types::{ToHexStr, TryHashSelf},
+};
+
+use crate::{consts::INITIAL_DR_CONFIG, msgs::data_requests::test_helpers, TestInfo};
+
+#[test]
+fn executors_are_listed_in_selection_order() {
+    let test_info = TestInfo::init();
+    let executors = [
+        test_info.new_executor("alice", 22, 1),
+        test_info.new_executor("bob", 22, 1),
+        test_info.new_executor("carol", 22, 1),
+        test_info.new_executor("dave", 22, 1),
+    ];
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 2);
+    let dr_id = executors[0].post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let response = executors[0].get_data_request_executors(&dr_id).unwrap();
+    assert_eq!(4, response.executors.len());
+
+    let backup_delay = INITIAL_DR_CONFIG.backup_delay_in_blocks.get() as u64;
+    let roles_and_heights: Vec<_> = response
+        .executors
+        .iter()
+        .map(|executor| (executor.role.clone(), executor.eligible_from))
+        .collect();
+    assert_eq!(
+        vec![
+            (DataRequestExecutorRole::Primary, 1),
+            (DataRequestExecutorRole::Primary, 1),
+            (DataRequestExecutorRole::Backup, 2 + backup_delay),
+            (DataRequestExecutorRole::Backup, 2 + 2 * backup_delay),
+        ],
+        roles_and_heights
+    );
+
+    // the listing agrees with the signed eligibility query at every slot opening
+    for height in [1, 1 + backup_delay, 2 + backup_delay, 2 + 2 * backup_delay] {
+        test_info.set_block_height(height);
+        let response = executors[0].get_data_request_executors(&dr_id).unwrap();
+        for listed in response.executors {
+            assert_eq!(height >= listed.eligible_from, listed.eligible);
+            let executor = executors
+                .iter()
+                .find(|executor| executor.pub_key_hex() == listed.public_key)
+                .unwrap();
+            assert_eq!(
+                listed.eligible,
+                executor.is_executor_eligible(dr_id.clone()),
+                "height {height}"
+            );
+        }
+    }
+}
+
+#[test]
+fn executors_of_restricted_request() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 22, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+    test_info.new_executor("carol", 22, 1);
+
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    dr.executors = Some(vec![alice.pub_key_hex(), bob.pub_key_hex()]);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let mut listed: Vec<_> = alice
+        .get_data_request_executors(&dr_id)
+        .unwrap()
+        .executors
+        .into_iter()
+        .map(|executor| executor.public_key)
+        .collect();
+    listed.sort();
+    let mut expected = vec![alice.pub_key_hex(), bob.pub_key_hex()];
+    expected.sort();
+    assert_eq!(expected, listed);
+}
+
+#[test]
+fn executors_of_unknown_request() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 22, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = dr.try_hash().unwrap().to_hex();
+    assert_eq!(None, alice.get_data_request_executors(&dr_id));
+}
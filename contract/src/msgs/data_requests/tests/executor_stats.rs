@@ -0,0 +1,193 @@
+use seda_common::{
+    msgs::{
+        data_requests::{
+            sudo::{DistributionExecutorReward, DistributionMessage},
+            RevealBody,
+        },
+        staking::{ExecutorStats, ExecutorStatsSortBy},
+    },
+    types::HashSelf,
+};
+
+use crate::{consts::INITIAL_DR_CONFIG, msgs::data_requests::test_helpers, TestInfo};
+
+#[test]
+fn stats_track_commits_reveals_and_timeouts() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    // both commit, but only alice reveals before the request times out
+    let dr = test_helpers::calculate_dr_id_and_args(1, 2);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    let alice_reveal_message = alice.create_reveal_message(reveal.clone());
+    alice.commit_result(&dr_id, &alice_reveal_message).unwrap();
+    test_info.set_block_height(2);
+    bob.commit_result(&dr_id, &bob.create_reveal_message(reveal)).unwrap();
+    test_info.set_block_height(3);
+    alice.reveal_result(alice_reveal_message).unwrap();
+
+    test_info.set_block_height(2 + INITIAL_DR_CONFIG.reveal_timeout_in_blocks.get() as u64);
+    test_info.creator().expire_data_requests().unwrap();
+
+    let stats = alice.get_executor_stats(ExecutorStatsSortBy::Reveals, 0, 10).stats;
+    assert_eq!(
+        vec![
+            ExecutorStats {
+                public_key:         alice.pub_key_hex(),
+                commits:            1,
+                reveals:            1,
+                missed_reveals:     0,
+                timeouts:           1,
+                rewards_received:   0u128.into(),
+                last_active_height: 3,
+            },
+            ExecutorStats {
+                public_key:         bob.pub_key_hex(),
+                commits:            1,
+                reveals:            0,
+                missed_reveals:     1,
+                timeouts:           1,
+                rewards_received:   0u128.into(),
+                last_active_height: 2,
+            },
+        ],
+        stats
+    );
+}
+
+#[test]
+fn stats_track_rewards() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 10);
+
+    for nonce in 1..=2 {
+        let dr = test_helpers::calculate_dr_id_and_args(nonce, 1);
+        let dr_id = alice.post_data_request(dr, vec![], vec![], nonce as u64, None).unwrap();
+        let reveal = RevealBody {
+            dr_id:             dr_id.clone(),
+            dr_block_height:   nonce as u64,
+            reveal:            "10".hash().into(),
+            gas_used:          0,
+            exit_code:         0,
+            proxy_public_keys: vec![],
+        };
+        let reveal_message = executor.create_reveal_message(reveal);
+        executor.commit_result(&dr_id, &reveal_message).unwrap();
+        executor.reveal_result(reveal_message).unwrap();
+
+        test_info
+            .creator()
+            .remove_data_request(
+                dr_id,
+                vec![DistributionMessage::ExecutorReward(DistributionExecutorReward {
+                    identity: executor.pub_key_hex(),
+                    amount:   100u128.into(),
+                })],
+            )
+            .unwrap();
+    }
+
+    let stats = executor
+        .get_executor_stats(ExecutorStatsSortBy::RewardsReceived, 0, 1)
+        .stats;
+    assert_eq!(1, stats.len());
+    assert_eq!(executor.pub_key_hex(), stats[0].public_key);
+    assert_eq!(2, stats[0].commits);
+    assert_eq!(2, stats[0].reveals);
+    assert_eq!(200, stats[0].rewards_received.u128());
+}
+
+#[test]
+fn stats_are_sorted_and_paginated() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+    let carol = test_info.new_executor("carol", 22, 1);
+
+    // alice commits on three requests, bob on two and carol on one
+    for (nonce, executors) in [
+        (1, vec![&alice, &bob, &carol]),
+        (2, vec![&alice, &bob]),
+        (3, vec![&alice]),
+    ] {
+        let dr = test_helpers::calculate_dr_id_and_args(nonce, 3);
+        let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+        for executor in executors {
+            let reveal = RevealBody {
+                dr_id:             dr_id.clone(),
+                dr_block_height:   1,
+                reveal:            "10".hash().into(),
+                gas_used:          0,
+                exit_code:         0,
+                proxy_public_keys: vec![],
+            };
+            executor
+                .commit_result(&dr_id, &executor.create_reveal_message(reveal))
+                .unwrap();
+        }
+    }
+
+    let by_commits = |offset, limit| {
+        alice
+            .get_executor_stats(ExecutorStatsSortBy::Commits, offset, limit)
+            .stats
+            .into_iter()
+            .map(|stats| (stats.public_key, stats.commits))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec![(alice.pub_key_hex(), 3), (bob.pub_key_hex(), 2)], by_commits(0, 2));
+    assert_eq!(vec![(carol.pub_key_hex(), 1)], by_commits(2, 2));
+    assert!(by_commits(3, 2).is_empty());
+}
+
+#[test]
+fn stats_ranking_follows_updates_and_key_rotation() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+    let alice_new = test_info.new_account("alice_new", 0);
+
+    let commit = |executor: &crate::TestAccount, nonce| {
+        let dr = test_helpers::calculate_dr_id_and_args(nonce, 1);
+        let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+        let reveal = RevealBody {
+            dr_id:             dr_id.clone(),
+            dr_block_height:   1,
+            reveal:            "10".hash().into(),
+            gas_used:          0,
+            exit_code:         0,
+            proxy_public_keys: vec![],
+        };
+        executor
+            .commit_result(&dr_id, &executor.create_reveal_message(reveal))
+            .unwrap();
+    };
+    let by_commits = || {
+        alice
+            .get_executor_stats(ExecutorStatsSortBy::Commits, 0, 10)
+            .stats
+            .into_iter()
+            .map(|stats| (stats.public_key, stats.commits))
+            .collect::<Vec<_>>()
+    };
+
+    commit(&alice, 1);
+    assert_eq!(vec![(alice.pub_key_hex(), 1)], by_commits());
+
+    // the rotated key takes the old key's place
+    alice.rotate_key(&alice_new).unwrap();
+    assert_eq!(vec![(alice_new.pub_key_hex(), 1)], by_commits());
+
+    // the previous count does not linger once it changed
+    commit(&alice_new, 2);
+    assert_eq!(vec![(alice_new.pub_key_hex(), 2)], by_commits());
+}
@@ -0,0 +1,179 @@
+use std::rc::Rc;
+
+use seda_common::{
+    msgs::{
+        data_requests::RevealBody,
+        staking::{SelectionMode, StakingConfig},
+    },
+    types::HashSelf,
+};
+
+use crate::{
+    consts::INITIAL_DR_CONFIG,
+    error::ContractError,
+    msgs::data_requests::test_helpers,
+    TestAccount,
+    TestInfo,
+};
+
+fn set_jail_config(test_info: &Rc<TestInfo>, missed_reveals_jail_threshold: u32) {
+    test_info
+        .creator()
+        .set_staking_config(StakingConfig {
+            minimum_stake: 1u8.into(),
+            allowlist_enabled: false,
+            unbonding_period_in_blocks: 0,
+            missed_reveals_jail_threshold,
+            jail_duration_in_blocks: 10,
+            selection_mode: SelectionMode::Uniform,
+        })
+        .unwrap();
+}
+
+/// Posts a data request both executors commit on, which only `revealer`
+/// reveals, and lets it time out.
+fn miss_reveal(test_info: &Rc<TestInfo>, revealer: &TestAccount, other: &TestAccount, nonce: u128) {
+    let height = test_info.block_height() + 1;
+    let dr = test_helpers::calculate_dr_id_and_args(nonce, 2);
+    let dr_id = revealer.post_data_request(dr, vec![], vec![], height, None).unwrap();
+
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   height,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    let revealer_message = revealer.create_reveal_message(reveal.clone());
+    revealer.commit_result(&dr_id, &revealer_message).unwrap();
+    other
+        .commit_result(&dr_id, &other.create_reveal_message(reveal))
+        .unwrap();
+    revealer.reveal_result(revealer_message).unwrap();
+
+    test_info.set_block_height(height + INITIAL_DR_CONFIG.reveal_timeout_in_blocks.get() as u64);
+    test_info.creator().expire_data_requests().unwrap();
+}
+
+#[test]
+fn missed_reveals_jail_executor() {
+    let test_info = TestInfo::init();
+    set_jail_config(&test_info, 2);
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    miss_reveal(&test_info, &alice, &bob, 1);
+    let bob_info = bob.get_staker_info().unwrap();
+    assert_eq!(1, bob_info.missed_reveals);
+    assert_eq!(None, bob_info.jailed_until);
+    assert_eq!(0, alice.get_staker_info().unwrap().missed_reveals);
+    assert!(bob.is_staker_executor());
+
+    // the second missed reveal reaches the threshold
+    miss_reveal(&test_info, &alice, &bob, 2);
+    let bob_info = bob.get_staker_info().unwrap();
+    assert_eq!(0, bob_info.missed_reveals);
+    assert_eq!(Some(test_info.block_height() + 10), bob_info.jailed_until);
+    assert!(!bob.is_staker_executor());
+    assert!(alice.is_staker_executor());
+}
+
+#[test]
+fn reveal_resets_missed_reveals() {
+    let test_info = TestInfo::init();
+    set_jail_config(&test_info, 2);
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    miss_reveal(&test_info, &alice, &bob, 1);
+    assert_eq!(1, bob.get_staker_info().unwrap().missed_reveals);
+
+    // bob reveals this time, so alice misses the reveal instead
+    miss_reveal(&test_info, &bob, &alice, 2);
+    assert_eq!(0, bob.get_staker_info().unwrap().missed_reveals);
+    assert_eq!(1, alice.get_staker_info().unwrap().missed_reveals);
+}
+
+#[test]
+fn zero_threshold_disables_jailing() {
+    let test_info = TestInfo::init();
+    set_jail_config(&test_info, 0);
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    miss_reveal(&test_info, &alice, &bob, 1);
+    miss_reveal(&test_info, &alice, &bob, 2);
+
+    let bob_info = bob.get_staker_info().unwrap();
+    assert_eq!(0, bob_info.missed_reveals);
+    assert_eq!(None, bob_info.jailed_until);
+}
+
+#[test]
+fn jailed_executor_cannot_commit() {
+    let test_info = TestInfo::init();
+    set_jail_config(&test_info, 1);
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    miss_reveal(&test_info, &alice, &bob, 1);
+    let jailed_until = bob.get_staker_info().unwrap().jailed_until.unwrap();
+
+    let height = test_info.block_height() + 1;
+    let dr = test_helpers::calculate_dr_id_and_args(2, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], height, None).unwrap();
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   height,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    let res = bob.commit_result(&dr_id, &bob.create_reveal_message(reveal));
+    assert!(res.is_err_and(|e| e == ContractError::ExecutorJailed(jailed_until)));
+}
+
+#[test]
+fn jailed_executor_is_not_selected() {
+    let test_info = TestInfo::init();
+    set_jail_config(&test_info, 1);
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    miss_reveal(&test_info, &alice, &bob, 1);
+    assert!(bob.get_staker_info().unwrap().jailed_until.is_some());
+
+    // with every backup executor eligible, only alice is selected
+    let height = test_info.block_height() + 1;
+    let dr = test_helpers::calculate_dr_id_and_args(2, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], height, None).unwrap();
+    test_info.set_block_height(height + 5);
+    assert!(alice.is_executor_eligible(dr_id.clone()));
+    assert!(!bob.is_executor_eligible(dr_id));
+}
+
+#[test]
+fn unjail_after_jail_period() {
+    let test_info = TestInfo::init();
+    set_jail_config(&test_info, 1);
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    // alice was never jailed
+    let res = alice.unjail();
+    assert!(res.is_err_and(|e| e == ContractError::NotJailed));
+
+    miss_reveal(&test_info, &alice, &bob, 1);
+    let jailed_until = bob.get_staker_info().unwrap().jailed_until.unwrap();
+
+    // bob has to wait out the jail period
+    let res = bob.unjail();
+    assert!(res.is_err_and(|e| e == ContractError::JailPeriodNotOver(jailed_until)));
+
+    test_info.set_block_height(jailed_until);
+    bob.unjail().unwrap();
+    assert_eq!(None, bob.get_staker_info().unwrap().jailed_until);
+    assert!(bob.is_staker_executor());
+}
@@ -2,13 +2,20 @@ use seda_common::types::TryHashSelf;
 
 use crate::msgs::data_requests::test_helpers;
 
+mod callback;
+mod cancel_dr;
 mod commit_dr;
+mod data_results;
+mod dr_executors;
+mod executor_stats;
+mod jailing;
 mod pause_behavior;
 mod post_dr;
 mod query_dr_status;
 mod remove_dr;
 mod reveal_dr;
 mod timeout_actions;
+mod top_up_dr;
 
 #[test]
 fn check_data_request_id() {
@@ -82,6 +82,14 @@ pub fn execute_messages_get_paused() {
         memo_limit_in_bytes:             512.try_into().unwrap(),
         payback_address_limit_in_bytes:  128.try_into().unwrap(),
         seda_payload_limit_in_bytes:     512.try_into().unwrap(),
+        min_commit_timeout_in_blocks:    1.try_into().unwrap(),
+        max_commit_timeout_in_blocks:    1.try_into().unwrap(),
+        min_reveal_timeout_in_blocks:    1.try_into().unwrap(),
+        max_reveal_timeout_in_blocks:    1.try_into().unwrap(),
+        data_result_retention_in_blocks: 1.try_into().unwrap(),
+        executors_limit:                 1.try_into().unwrap(),
+        max_expirations_per_call:        1.try_into().unwrap(),
+        max_requests_per_post:           1.try_into().unwrap(),
     };
     test_info.creator().set_dr_config(dr_config).unwrap();
 }
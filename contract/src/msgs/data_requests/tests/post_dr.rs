@@ -1,5 +1,8 @@
 use cosmwasm_std::{Binary, Uint128};
-use seda_common::{msgs::data_requests::DataRequestStatus, types::Hash};
+use seda_common::{
+    msgs::data_requests::{execute::post_request, DataRequestStatus, DrConfig, PostDataRequestsResult},
+    types::{Hash, ToHexStr, TryHashSelf},
+};
 use semver::{BuildMetadata, Prerelease};
 
 use crate::{
@@ -324,3 +327,136 @@ fn fails_if_version_has_both_pre_and_build() {
     dr.version.build = BuildMetadata::new("build.1").unwrap();
     executor.post_data_request(dr, vec![], vec![1, 2, 3], 1, None).unwrap();
 }
+
+fn batch_entry(nonce: u128, replication_factor: u16) -> post_request::Execute {
+    post_request::Execute {
+        posted_dr:       test_helpers::calculate_dr_id_and_args(nonce, replication_factor),
+        seda_payload:    vec![].into(),
+        payback_address: vec![1, 2, 3].into(),
+        callback:        None,
+    }
+}
+
+#[test]
+fn batch_works() {
+    let test_info = TestInfo::init();
+    let relayer = test_info.new_executor("relayer", 1_000, 1);
+
+    // attach a bit more than needed, the rest is refunded
+    let balance_before = test_info.executor_balance("relayer");
+    let requests = vec![batch_entry(1, 1), batch_entry(2, 1), batch_entry(3, 1)];
+    let results = relayer
+        .post_data_requests(requests, 1, 3 * min_post_dr_cost() + 100)
+        .unwrap();
+    assert_eq!(
+        balance_before - 3 * min_post_dr_cost(),
+        test_info.executor_balance("relayer")
+    );
+
+    assert_eq!(3, results.len());
+    for (nonce, result) in (1..=3).zip(results) {
+        let dr_id = test_helpers::calculate_dr_id_and_args(nonce, 1).try_hash().unwrap();
+        let PostDataRequestsResult::Posted(payload) = result else {
+            panic!("expected data request {nonce} to be posted");
+        };
+        assert_eq!(dr_id.to_hex(), payload.dr_id);
+        assert_eq!(1, payload.height);
+
+        // each request escrows exactly its own cost
+        let escrow = DR_ESCROW
+            .load(&*test_info.app().contract_storage(&test_info.contract_addr()), &dr_id)
+            .unwrap();
+        assert_eq!(min_post_dr_cost(), escrow.amount.u128());
+        assert_eq!(relayer.addr(), escrow.poster);
+    }
+
+    let drs = relayer.get_data_requests_by_status(DataRequestStatus::Committing, None, 10);
+    assert_eq!(3, drs.total);
+}
+
+#[test]
+fn batch_skips_invalid_requests() {
+    let test_info = TestInfo::init();
+    let relayer = test_info.new_executor("relayer", 1_000, 1);
+
+    let mut low_gas_price = batch_entry(4, 1);
+    low_gas_price.posted_dr.gas_price = Uint128::new(1);
+
+    let balance_before = test_info.executor_balance("relayer");
+    let requests = vec![
+        batch_entry(1, 1),
+        // same request again
+        batch_entry(1, 1),
+        batch_entry(2, 0),
+        low_gas_price,
+        batch_entry(3, 1),
+        // not enough funds left for this one
+        batch_entry(5, 1),
+    ];
+    let results = relayer.post_data_requests(requests, 1, 2 * min_post_dr_cost()).unwrap();
+
+    // only the two valid requests were paid for
+    assert_eq!(
+        balance_before - 2 * min_post_dr_cost(),
+        test_info.executor_balance("relayer")
+    );
+
+    let reasons: Vec<_> = results
+        .iter()
+        .map(|result| match result {
+            PostDataRequestsResult::Posted(_) => None,
+            PostDataRequestsResult::Skipped { reason } => Some(reason.clone()),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            None,
+            Some(ContractError::DataRequestAlreadyExists.to_string()),
+            Some(ContractError::DataRequestReplicationFactorZero.to_string()),
+            Some(ContractError::GasPriceTooLow(Uint128::new(1)).to_string()),
+            None,
+            Some(ContractError::InsufficientFunds(Uint128::new(min_post_dr_cost()), Uint128::zero()).to_string()),
+        ],
+        reasons
+    );
+
+    let drs = relayer.get_data_requests_by_status(DataRequestStatus::Committing, None, 10);
+    assert_eq!(2, drs.total);
+}
+
+#[test]
+fn batch_refunds_everything_if_all_skipped() {
+    let test_info = TestInfo::init();
+    let relayer = test_info.new_executor("relayer", 1_000, 1);
+
+    let balance_before = test_info.executor_balance("relayer");
+    let results = relayer
+        .post_data_requests(vec![batch_entry(1, 0)], 1, min_post_dr_cost())
+        .unwrap();
+
+    assert!(matches!(results[0], PostDataRequestsResult::Skipped { .. }));
+    assert_eq!(balance_before, test_info.executor_balance("relayer"));
+}
+
+#[test]
+fn batch_larger_than_max_fails() {
+    let test_info = TestInfo::init();
+    let relayer = test_info.new_executor("relayer", 1_000, 1);
+    test_info
+        .creator()
+        .set_dr_config(DrConfig {
+            max_requests_per_post: 2.try_into().unwrap(),
+            ..INITIAL_DR_CONFIG
+        })
+        .unwrap();
+
+    let balance_before = test_info.executor_balance("relayer");
+    let requests = vec![batch_entry(1, 1), batch_entry(2, 1), batch_entry(3, 1)];
+    let res = relayer.post_data_requests(requests, 1, 3 * min_post_dr_cost());
+    assert!(res.is_err_and(|e| e == ContractError::TooManyDataRequests(3, 2.try_into().unwrap())));
+    assert_eq!(balance_before, test_info.executor_balance("relayer"));
+
+    // a batch at the limit is still posted
+    let requests = vec![batch_entry(1, 1), batch_entry(2, 1)];
+    relayer.post_data_requests(requests, 1, 2 * min_post_dr_cost()).unwrap();
+}
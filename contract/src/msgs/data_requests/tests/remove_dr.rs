@@ -3,20 +3,65 @@ use std::collections::HashMap;
 use cosmwasm_std::Uint128;
 use seda_common::{
     msgs::data_requests::{
-        sudo::{DistributionBurn, DistributionDataProxyReward, DistributionExecutorReward, DistributionMessage},
+        sudo::{
+            DistributionBurn,
+            DistributionCommunityPool,
+            DistributionDataProxyReward,
+            DistributionExecutorReward,
+            DistributionExecutorSlash,
+            DistributionMessage,
+            DistributionSummary,
+            DistributionTreasury,
+            FailedPayout,
+            FailedPayoutReason,
+            RemoveDataRequestStatus,
+        },
         DataRequestStatus,
         RevealBody,
     },
-    types::{HashSelf, ToHexStr},
+    types::{Hash, HashSelf, ToHexStr},
 };
 
 use crate::{
-    msgs::data_requests::{consts::min_post_dr_cost, test_helpers},
+    msgs::{
+        data_requests::{consts::min_post_dr_cost, state::DR_ESCROW, test_helpers},
+        staking::state::executor_stats::EXECUTOR_STATS,
+        PublicKey,
+    },
     new_public_key,
     seda_to_aseda,
+    types::FromHexStr,
+    TestAccount,
     TestInfo,
 };
 
+/// Posts a data request that `executor` commits and reveals on.
+fn revealed_request(poster: &TestAccount, executor: &TestAccount, nonce: u128) -> String {
+    let dr = test_helpers::calculate_dr_id_and_args(nonce, 1);
+    let dr_id = poster.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    let reveal_message = executor.create_reveal_message(reveal);
+    executor.commit_result(&dr_id, &reveal_message).unwrap();
+    executor.reveal_result(reveal_message).unwrap();
+    dr_id
+}
+
+/// Overwrites a stored value with bytes that can't be deserialized.
+fn corrupt_storage(test_info: &TestInfo, key: &[u8]) {
+    test_info
+        .app_mut()
+        .contract_storage_mut(&test_info.contract_addr())
+        .set(key, b"corrupted");
+}
+
 #[test]
 fn basic_workflow_works() {
     let test_info = TestInfo::init();
@@ -48,10 +93,10 @@ fn basic_workflow_works() {
     // remainder refunds to alice
     let (_, proxy) = new_public_key();
     let bob = test_info.new_account("bob", 2);
-    test_info
+    let removed = test_info
         .creator()
         .remove_data_request(
-            dr_id,
+            dr_id.clone(),
             vec![
                 DistributionMessage::Burn(DistributionBurn { amount: 1u128.into() }),
                 // valid data proxy reward
@@ -98,6 +143,34 @@ fn basic_workflow_works() {
     // should have 5 seda pending withdrawal from executor reward
     let staker = executor.get_staker_info().unwrap();
     assert_eq!(5, staker.tokens_pending_withdrawal.u128());
+
+    // the response sums up where the escrow went
+    let [removed] = removed.requests.try_into().unwrap();
+    assert_eq!(dr_id, removed.dr_id);
+    assert_eq!(RemoveDataRequestStatus::Removed, removed.status);
+    let failed_payout = |index, recipient: String, reason| FailedPayout {
+        index,
+        recipient,
+        amount: 2u128.into(),
+        reason,
+    };
+    assert_eq!(
+        Some(DistributionSummary {
+            burned:         7u128.into(),
+            rewarded:       10u128.into(),
+            topped_up:      0u128.into(),
+            refunded:       (min_post_dr_cost() - 17).into(),
+            slashed:        0u128.into(),
+            community_pool: 0u128.into(),
+            treasury:       0u128.into(),
+            failed_payouts: vec![
+                failed_payout(3, "invalid".to_string(), FailedPayoutReason::InvalidPayoutAddress),
+                failed_payout(4, "invalid".to_string(), FailedPayoutReason::InvalidIdentity),
+                failed_payout(5, bob.pub_key_hex(), FailedPayoutReason::UnknownExecutor),
+            ],
+        }),
+        removed.distribution
+    );
 }
 
 #[test]
@@ -207,7 +280,10 @@ fn status_codes_work() {
         })],
     );
     let removed = test_info.creator().remove_data_requests(to_remove).unwrap();
-    removed.iter().for_each(|r| assert_eq!(0, r.1));
+    removed
+        .requests
+        .iter()
+        .for_each(|r| assert_eq!(RemoveDataRequestStatus::Removed, r.status));
 }
 
 #[test]
@@ -224,14 +300,15 @@ fn invalid_status_codes_work() {
     let removed = test_info.creator().remove_data_requests(to_remove).unwrap();
     // test this way since tests are not in wasm so hashmap order is non
     // deterministic
-    assert_eq!(1, removed.iter().find(|r| r.0 == "does_not_exist").unwrap().1);
+    let status = |dr_id: &str| {
+        let removed = removed.requests.iter().find(|r| r.dr_id == dr_id).unwrap();
+        assert_eq!(None, removed.distribution);
+        removed.status.clone()
+    };
+    assert_eq!(RemoveDataRequestStatus::InvalidDrId, status("does_not_exist"));
     assert_eq!(
-        2,
-        removed
-            .iter()
-            .find(|r| r.0 == "2404059f879876ad51abe32ad9099d5fe4085c473d54571f109d637a25d62885")
-            .unwrap()
-            .1
+        RemoveDataRequestStatus::NotFound,
+        status("2404059f879876ad51abe32ad9099d5fe4085c473d54571f109d637a25d62885")
     );
 }
 
@@ -436,11 +513,406 @@ fn unstake_before_dr_removal_still_rewards_staker() {
         )
         .unwrap();
 
-    // bob should still get the reward
+    // bob should still get the reward, minus the top up back to the minimum stake
     // get the staker info for the executor
     let staker = bob.get_staker_info().unwrap();
-    assert_eq!(5, staker.tokens_pending_withdrawal.u128());
+    assert_eq!(4, staker.tokens_pending_withdrawal.u128());
+    assert_eq!(1, staker.tokens_staked.u128());
 
     // bob can withdraw the reward
     bob.withdraw().unwrap();
 }
+
+#[test]
+fn slash_takes_stake_then_pending() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 5);
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    // the executor is rewarded 3, then slashed 7 of their 5 staked + 3 pending
+    test_info
+        .creator()
+        .remove_data_request(
+            dr_id.clone(),
+            vec![
+                DistributionMessage::ExecutorReward(DistributionExecutorReward {
+                    identity: executor.pub_key_hex(),
+                    amount:   3u128.into(),
+                }),
+                DistributionMessage::Slash(DistributionExecutorSlash {
+                    identity: executor.pub_key_hex(),
+                    amount:   7u128.into(),
+                }),
+            ],
+        )
+        .unwrap();
+
+    let staker = executor.get_staker_info().unwrap();
+    assert_eq!(0, staker.tokens_staked.u128());
+    assert_eq!(1, staker.tokens_pending_withdrawal.u128());
+
+    // the slash does not come out of the escrow
+    let alice_expected_balance = seda_to_aseda(22.into()) - 3;
+    assert_eq!(alice_expected_balance, test_info.executor_balance("alice"));
+
+    // below the minimum stake the executor is no longer eligible
+    assert!(!executor.is_staker_executor());
+}
+
+#[test]
+fn slash_applies_after_escrow_is_emptied() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 5);
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    // the whole escrow is burned before the slash
+    test_info
+        .creator()
+        .remove_data_request(
+            dr_id.clone(),
+            vec![
+                DistributionMessage::Burn(DistributionBurn {
+                    amount: min_post_dr_cost().into(),
+                }),
+                DistributionMessage::Slash(DistributionExecutorSlash {
+                    identity: executor.pub_key_hex(),
+                    amount:   2u128.into(),
+                }),
+            ],
+        )
+        .unwrap();
+
+    let staker = executor.get_staker_info().unwrap();
+    assert_eq!(3, staker.tokens_staked.u128());
+    assert!(executor.is_staker_executor());
+}
+
+#[test]
+fn slash_does_not_touch_delegations() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 5);
+    let bob = test_info.new_account("bob", 1);
+    bob.delegate(&executor, 30).unwrap();
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    test_info
+        .creator()
+        .remove_data_request(
+            dr_id,
+            vec![DistributionMessage::Slash(DistributionExecutorSlash {
+                identity: executor.pub_key_hex(),
+                amount:   20u128.into(),
+            })],
+        )
+        .unwrap();
+
+    // only the executor's own tokens are slashed, the delegated tokens are kept
+    let staker = executor.get_staker_info().unwrap();
+    assert_eq!(0, staker.tokens_staked.u128());
+    assert_eq!(30, staker.tokens_delegated.u128());
+    assert_eq!(30, bob.get_delegation(&executor).unwrap().amount.u128());
+}
+
+#[test]
+fn executor_reward_is_shared_with_delegators() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 10);
+    let bob = test_info.new_account("bob", 1);
+    let carol = test_info.new_account("carol", 1);
+    executor.set_commission_rate(1_000).unwrap();
+    bob.delegate(&executor, 30).unwrap();
+    carol.delegate(&executor, 10).unwrap();
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    test_info
+        .creator()
+        .remove_data_request(
+            dr_id,
+            vec![DistributionMessage::ExecutorReward(DistributionExecutorReward {
+                identity: executor.pub_key_hex(),
+                amount:   100u128.into(),
+            })],
+        )
+        .unwrap();
+
+    // delegators earn 80 of the reward, of which the executor keeps a 10%
+    // commission
+    let staker = executor.get_staker_info().unwrap();
+    assert_eq!(28, staker.tokens_pending_withdrawal.u128());
+    assert_eq!(54, bob.get_delegation(&executor).unwrap().accrued_rewards.u128());
+    assert_eq!(18, carol.get_delegation(&executor).unwrap().accrued_rewards.u128());
+
+    // bob withdraws their rewards while keeping the delegation
+    bob.withdraw_delegation(&executor).unwrap();
+    assert_eq!(seda_to_aseda(1.into()) - 30 + 54, test_info.executor_balance("bob"));
+    let delegation = bob.get_delegation(&executor).unwrap();
+    assert_eq!(30, delegation.amount.u128());
+    assert!(delegation.accrued_rewards.is_zero());
+}
+
+#[test]
+fn executor_reward_is_restaked_by_policy() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 10);
+    executor.set_reward_policy(2_500).unwrap();
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    test_info
+        .creator()
+        .remove_data_request(
+            dr_id,
+            vec![DistributionMessage::ExecutorReward(DistributionExecutorReward {
+                identity: executor.pub_key_hex(),
+                amount:   100u128.into(),
+            })],
+        )
+        .unwrap();
+
+    // a quarter of the reward is added to the stake, the rest can be withdrawn
+    let staker = executor.get_staker_info().unwrap();
+    assert_eq!(35, staker.tokens_staked.u128());
+    assert_eq!(75, staker.tokens_pending_withdrawal.u128());
+}
+
+#[test]
+fn reveal_after_key_rotation_rewards_new_key() {
+    let test_info = TestInfo::init();
+
+    // post a data request
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 5);
+    let executor_new = test_info.new_account("exec_new", 0);
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let executor_reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    let executor_reveal_message = executor.create_reveal_message(executor_reveal);
+    executor.commit_result(&dr_id, &executor_reveal_message).unwrap();
+
+    // the executor rotates their key between commit and reveal
+    executor.rotate_key(&executor_new).unwrap();
+    executor.reveal_result(executor_reveal_message).unwrap();
+
+    test_info
+        .creator()
+        .remove_data_request(
+            dr_id,
+            vec![DistributionMessage::ExecutorReward(DistributionExecutorReward {
+                identity: executor.pub_key_hex(),
+                amount:   3u128.into(),
+            })],
+        )
+        .unwrap();
+
+    // the reward for the old identity is credited to the new key
+    let staker = executor_new.get_staker_info().unwrap();
+    assert_eq!(3, staker.tokens_pending_withdrawal.u128());
+}
+
+#[test]
+fn failed_executor_reward_is_burned() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    // the executor stats are updated after the staker, so the staker update has
+    // to be rolled back
+    let public_key = PublicKey::from_hex_str(&executor.pub_key_hex()).unwrap();
+    corrupt_storage(&test_info, &EXECUTOR_STATS.key(&public_key));
+
+    let removed = test_info
+        .creator()
+        .remove_data_request(
+            dr_id.clone(),
+            vec![
+                DistributionMessage::ExecutorReward(DistributionExecutorReward {
+                    identity: executor.pub_key_hex(),
+                    amount:   5u128.into(),
+                }),
+                DistributionMessage::Burn(DistributionBurn { amount: 1u128.into() }),
+            ],
+        )
+        .unwrap();
+
+    // the reward is burned and the rest of the request is still processed
+    let [removed] = removed.requests.try_into().unwrap();
+    assert_eq!(RemoveDataRequestStatus::Removed, removed.status);
+    assert_eq!(
+        Some(DistributionSummary {
+            burned:         6u128.into(),
+            rewarded:       0u128.into(),
+            topped_up:      0u128.into(),
+            refunded:       (min_post_dr_cost() - 6).into(),
+            slashed:        0u128.into(),
+            community_pool: 0u128.into(),
+            treasury:       0u128.into(),
+            failed_payouts: vec![FailedPayout {
+                index:     0,
+                recipient: executor.pub_key_hex(),
+                amount:    5u128.into(),
+                reason:    FailedPayoutReason::StorageError,
+            }],
+        }),
+        removed.distribution
+    );
+    assert!(alice.get_data_request(&dr_id).is_none());
+    assert_eq!(seda_to_aseda(22.into()) - 6, test_info.executor_balance("alice"));
+
+    let staker = executor.get_staker_info().unwrap();
+    assert!(staker.tokens_pending_withdrawal.is_zero());
+}
+
+#[test]
+fn failed_request_does_not_stop_the_batch() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let dr_id1 = revealed_request(&alice, &executor, 1);
+    let dr_id2 = revealed_request(&alice, &executor, 2);
+
+    corrupt_storage(&test_info, &DR_ESCROW.key(&Hash::from_hex_str(&dr_id1).unwrap()));
+
+    let reward = vec![DistributionMessage::ExecutorReward(DistributionExecutorReward {
+        identity: executor.pub_key_hex(),
+        amount:   5u128.into(),
+    })];
+    let removed = test_info
+        .creator()
+        .remove_data_requests(HashMap::from([
+            (dr_id1.clone(), reward.clone()),
+            (dr_id2.clone(), reward),
+        ]))
+        .unwrap();
+
+    let removed = |dr_id: &str| removed.requests.iter().find(|r| r.dr_id == dr_id).unwrap().clone();
+    assert_eq!(RemoveDataRequestStatus::Failed, removed(&dr_id1).status);
+    assert_eq!(None, removed(&dr_id1).distribution);
+    assert_eq!(RemoveDataRequestStatus::Removed, removed(&dr_id2).status);
+    assert_eq!(5, removed(&dr_id2).distribution.unwrap().rewarded.u128());
+
+    // the failed request is left in place, the other one is removed and paid out
+    assert!(alice.get_data_request(&dr_id1).is_some());
+    assert!(alice.get_data_request(&dr_id2).is_none());
+    let staker = executor.get_staker_info().unwrap();
+    assert_eq!(5, staker.tokens_pending_withdrawal.u128());
+}
+
+#[test]
+fn committing_request_does_not_stop_the_batch() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 1);
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let committing = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    let revealed = revealed_request(&alice, &executor, 2);
+
+    let removed = test_info
+        .creator()
+        .remove_data_requests(HashMap::from([
+            (committing.clone(), vec![]),
+            (revealed.clone(), vec![]),
+        ]))
+        .unwrap();
+
+    let removed = |dr_id: &str| removed.requests.iter().find(|r| r.dr_id == dr_id).unwrap().clone();
+    assert_eq!(RemoveDataRequestStatus::Failed, removed(&committing).status);
+    assert_eq!(None, removed(&committing).distribution);
+    assert_eq!(RemoveDataRequestStatus::Removed, removed(&revealed).status);
+
+    // the committing request keeps its escrow and can still be executed
+    assert!(alice.get_data_request(&committing).is_some());
+    assert!(alice.get_data_request(&revealed).is_none());
+    let reveal = RevealBody {
+        dr_id:             committing.clone(),
+        dr_block_height:   1,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    executor
+        .commit_result(&committing, &executor.create_reveal_message(reveal))
+        .unwrap();
+}
+
+#[test]
+fn community_pool_and_treasury_are_paid_from_escrow() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 10);
+    let treasury = test_info.new_account("treasury", 0);
+    test_info.creator().set_treasury(&treasury).unwrap();
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    let removed = test_info
+        .creator()
+        .remove_data_request(
+            dr_id,
+            vec![
+                DistributionMessage::CommunityPool(DistributionCommunityPool { amount: 30u128.into() }),
+                DistributionMessage::Treasury(DistributionTreasury { amount: 20u128.into() }),
+            ],
+        )
+        .unwrap();
+
+    assert_eq!(30, test_info.community_pool_balance());
+    assert_eq!(20, test_info.executor_balance("treasury"));
+
+    let [removed] = removed.requests.try_into().unwrap();
+    let summary = removed.distribution.unwrap();
+    assert_eq!(30, summary.community_pool.u128());
+    assert_eq!(20, summary.treasury.u128());
+    assert_eq!(min_post_dr_cost() - 50, summary.refunded.u128());
+}
+
+#[test]
+fn treasury_payout_is_burned_when_unset() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_account("alice", 22);
+    let executor = test_info.new_executor("exec", 51, 10);
+    let dr_id = revealed_request(&alice, &executor, 1);
+
+    let removed = test_info
+        .creator()
+        .remove_data_request(
+            dr_id,
+            vec![DistributionMessage::Treasury(DistributionTreasury {
+                amount: 20u128.into(),
+            })],
+        )
+        .unwrap();
+
+    let [removed] = removed.requests.try_into().unwrap();
+    let summary = removed.distribution.unwrap();
+    assert!(summary.treasury.is_zero());
+    assert_eq!(20, summary.burned.u128());
+    assert_eq!(
+        vec![FailedPayout {
+            index:     0,
+            recipient: String::new(),
+            amount:    20u128.into(),
+            reason:    FailedPayoutReason::TreasuryNotSet,
+        }],
+        summary.failed_payouts
+    );
+}
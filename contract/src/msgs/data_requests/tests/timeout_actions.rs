@@ -3,7 +3,7 @@ use seda_common::{
     types::HashSelf,
 };
 
-use crate::{consts::INITIAL_DR_CONFIG, msgs::data_requests::test_helpers, TestInfo};
+use crate::{consts::INITIAL_DR_CONFIG, error::ContractError, msgs::data_requests::test_helpers, TestInfo};
 
 #[test]
 fn owner_can_update_dr_config() {
@@ -20,6 +20,14 @@ fn owner_can_update_dr_config() {
         memo_limit_in_bytes:             1.try_into().unwrap(),
         payback_address_limit_in_bytes:  1.try_into().unwrap(),
         seda_payload_limit_in_bytes:     1.try_into().unwrap(),
+        min_commit_timeout_in_blocks:    1.try_into().unwrap(),
+        max_commit_timeout_in_blocks:    1.try_into().unwrap(),
+        min_reveal_timeout_in_blocks:    1.try_into().unwrap(),
+        max_reveal_timeout_in_blocks:    1.try_into().unwrap(),
+        data_result_retention_in_blocks: 1.try_into().unwrap(),
+        executors_limit:                 1.try_into().unwrap(),
+        max_expirations_per_call:        1.try_into().unwrap(),
+        max_requests_per_post:           1.try_into().unwrap(),
     };
 
     test_info.creator().set_dr_config(dr_config).unwrap();
@@ -41,6 +49,14 @@ fn only_owner_can_change_dr_config() {
         memo_limit_in_bytes:             1.try_into().unwrap(),
         payback_address_limit_in_bytes:  1.try_into().unwrap(),
         seda_payload_limit_in_bytes:     1.try_into().unwrap(),
+        min_commit_timeout_in_blocks:    1.try_into().unwrap(),
+        max_commit_timeout_in_blocks:    1.try_into().unwrap(),
+        min_reveal_timeout_in_blocks:    1.try_into().unwrap(),
+        max_reveal_timeout_in_blocks:    1.try_into().unwrap(),
+        data_result_retention_in_blocks: 1.try_into().unwrap(),
+        executors_limit:                 1.try_into().unwrap(),
+        max_expirations_per_call:        1.try_into().unwrap(),
+        max_requests_per_post:           1.try_into().unwrap(),
     };
 
     let alice = test_info.new_account("alice", 2);
@@ -110,3 +126,139 @@ fn timed_out_requests_move_to_tally() {
     assert_eq!(tallying[0], dr_id);
     assert_eq!(tallying[1], dr_id2);
 }
+
+#[test]
+fn dr_config_default_timeouts_must_be_within_bounds() {
+    let test_info = TestInfo::init();
+
+    let mut dr_config = INITIAL_DR_CONFIG;
+    dr_config.commit_timeout_in_blocks = dr_config.max_commit_timeout_in_blocks.saturating_add(1);
+    let res = test_info.creator().set_dr_config(dr_config);
+    assert!(res.is_err_and(|e| e == ContractError::InvalidTimeoutBounds("commit")));
+
+    let mut dr_config = INITIAL_DR_CONFIG;
+    dr_config.min_reveal_timeout_in_blocks = dr_config.reveal_timeout_in_blocks.saturating_add(1);
+    let res = test_info.creator().set_dr_config(dr_config);
+    assert!(res.is_err_and(|e| e == ContractError::InvalidTimeoutBounds("reveal")));
+}
+
+#[test]
+fn requested_timeouts_are_used() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 42, 1);
+
+    // post a data request with longer timeouts than the defaults
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    dr.commit_timeout_in_blocks = Some(100.try_into().unwrap());
+    dr.reveal_timeout_in_blocks = Some(20.try_into().unwrap());
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let dr = alice.get_data_request(&dr_id).unwrap();
+    assert_eq!(100, dr.base.commit_timeout_in_blocks.get());
+    assert_eq!(20, dr.base.reveal_timeout_in_blocks.get());
+    assert_eq!(Some(101), dr.timeout_height);
+
+    // the default commit timeout has no effect on it
+    test_info.set_block_height(INITIAL_DR_CONFIG.commit_timeout_in_blocks.get() as u64 + 1);
+    test_info.creator().expire_data_requests().unwrap();
+    assert_eq!(
+        DataRequestStatus::Committing,
+        alice.get_data_requests_statuses(vec![dr_id.clone()])[&dr_id]
+            .clone()
+            .unwrap()
+    );
+
+    // once committed, the requested reveal timeout applies
+    let alice_reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        reveal:            "10".hash().into(),
+        gas_used:          0,
+        exit_code:         0,
+        proxy_public_keys: vec![],
+    };
+    alice
+        .commit_result(&dr_id, &alice.create_reveal_message(alice_reveal))
+        .unwrap();
+    let dr = alice.get_data_request(&dr_id).unwrap();
+    let reveal_timeout_height = test_info.block_height() + 20;
+    assert_eq!(Some(reveal_timeout_height), dr.timeout_height);
+
+    test_info.set_block_height(reveal_timeout_height);
+    test_info.creator().expire_data_requests().unwrap();
+    let dr = alice.get_data_request(&dr_id).unwrap();
+    assert_eq!(None, dr.timeout_height);
+    assert_eq!(
+        DataRequestStatus::Tallying,
+        alice.get_data_requests_statuses(vec![dr_id.clone()])[&dr_id]
+            .clone()
+            .unwrap()
+    );
+}
+
+#[test]
+fn requested_timeouts_are_clamped() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 42, 1);
+
+    let mut dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    dr.commit_timeout_in_blocks = Some(u8::MAX.try_into().unwrap());
+    dr.reveal_timeout_in_blocks = Some(1.try_into().unwrap());
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let dr = alice.get_data_request(&dr_id).unwrap();
+    assert_eq!(
+        INITIAL_DR_CONFIG.max_commit_timeout_in_blocks,
+        dr.base.commit_timeout_in_blocks
+    );
+    assert_eq!(
+        INITIAL_DR_CONFIG.min_reveal_timeout_in_blocks,
+        dr.base.reveal_timeout_in_blocks
+    );
+    assert_eq!(
+        Some(1 + INITIAL_DR_CONFIG.max_commit_timeout_in_blocks.get() as u64),
+        dr.timeout_height
+    );
+}
+
+#[test]
+fn expiry_catches_up_on_skipped_heights() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 42, 1);
+    test_info
+        .creator()
+        .set_dr_config(DrConfig {
+            max_expirations_per_call: 2.try_into().unwrap(),
+            ..INITIAL_DR_CONFIG
+        })
+        .unwrap();
+
+    // requests posted at heights 1 to 3 time out at different heights
+    let mut dr_ids = vec![];
+    for height in 1..=3 {
+        test_info.set_block_height(height);
+        let dr = test_helpers::calculate_dr_id_and_args(height as u128, 1);
+        dr_ids.push(alice.post_data_request(dr, vec![], vec![], height, None).unwrap());
+    }
+
+    // no expiry ran at any of their timeout heights
+    let commit_timeout = INITIAL_DR_CONFIG.commit_timeout_in_blocks.get() as u64;
+    test_info.set_block_height(commit_timeout + 10);
+
+    let response = test_info.creator().expire_data_requests().unwrap();
+    assert_eq!(dr_ids[..2], response.timed_out_drs);
+    assert!(response.backlog);
+
+    let response = test_info.creator().expire_data_requests().unwrap();
+    assert_eq!(dr_ids[2..], response.timed_out_drs);
+    assert!(!response.backlog);
+
+    let statuses = alice.get_data_requests_statuses(dr_ids.clone());
+    for dr_id in &dr_ids {
+        assert_eq!(Some(DataRequestStatus::Tallying), statuses[dr_id]);
+    }
+
+    let response = test_info.creator().expire_data_requests().unwrap();
+    assert!(response.timed_out_drs.is_empty());
+    assert!(!response.backlog);
+}
@@ -0,0 +1,149 @@
+use seda_common::{
+    msgs::data_requests::{execute, DataRequestCallback, DataRequestStatus, RevealBody},
+    types::{Hash, HashSelf, ToHexStr, TryHashSelf},
+};
+
+use crate::{
+    error::ContractError,
+    msgs::data_requests::{
+        consts::{callback_cost, min_post_dr_cost},
+        state::DR_ESCROW,
+        test_helpers,
+    },
+    types::FromHexStr,
+    TestInfo,
+};
+
+#[test]
+fn top_up_works() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_account("bob", 22);
+
+    // post two data requests, the second one with a higher gas price
+    let dr1 = test_helpers::calculate_dr_id_and_args(1, 1);
+    let total_gas_limit = (dr1.exec_gas_limit + dr1.tally_gas_limit) as u128;
+    let dr_id1 = alice
+        .post_data_request(dr1, vec![], vec![], 1, Some(2001 * total_gas_limit))
+        .unwrap();
+    let dr2 = test_helpers::calculate_dr_id_and_args(2, 1);
+    let dr_id2 = alice
+        .post_data_request(dr2, vec![], vec![], 2, Some(2002 * total_gas_limit))
+        .unwrap();
+
+    let drs = alice.get_data_requests_by_status(DataRequestStatus::Committing, None, 2);
+    assert_eq!(dr_id2, drs.data_requests[0].base.id);
+    assert_eq!(dr_id1, drs.data_requests[1].base.id);
+
+    // anyone can top up the first data request
+    bob.top_up_data_request(&dr_id1, 2 * total_gas_limit).unwrap();
+
+    let dr1 = alice.get_data_request(&dr_id1).unwrap();
+    assert_eq!(2003, dr1.base.posted_gas_price.u128());
+
+    // the escrow grew but still belongs to the poster
+    let escrow = DR_ESCROW
+        .load(
+            &*test_info.app().contract_storage(&test_info.contract_addr()),
+            &Hash::from_hex_str(&dr_id1).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(2003 * total_gas_limit, escrow.amount.u128());
+    assert_eq!(alice.addr(), escrow.poster);
+
+    // the first data request now comes first
+    let drs = alice.get_data_requests_by_status(DataRequestStatus::Committing, None, 2);
+    assert_eq!(2, drs.total);
+    assert_eq!(dr_id1, drs.data_requests[0].base.id);
+    assert_eq!(dr_id2, drs.data_requests[1].base.id);
+}
+
+#[test]
+fn top_up_works_while_revealing() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+    let bob = test_info.new_executor("bob", 22, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let reveal = RevealBody {
+        dr_id:             dr_id.clone(),
+        dr_block_height:   1,
+        exit_code:         0,
+        gas_used:          0,
+        reveal:            "10".hash().into(),
+        proxy_public_keys: vec![],
+    };
+    bob.commit_result(&dr_id, &bob.create_reveal_message(reveal)).unwrap();
+
+    alice.top_up_data_request(&dr_id, min_post_dr_cost()).unwrap();
+
+    let drs = alice.get_data_requests_by_status(DataRequestStatus::Revealing, None, 10);
+    assert_eq!(1, drs.total);
+    assert_eq!(2 * 2000, drs.data_requests[0].base.posted_gas_price.u128());
+}
+
+#[test]
+fn top_up_prices_the_callback_gas() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+
+    let callback = DataRequestCallback {
+        contract:  alice.addr().to_string(),
+        gas_limit: 100_000,
+    };
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request_with_callback(dr, callback, 1).unwrap();
+    let dr = alice.get_data_request(&dr_id).unwrap();
+    assert_eq!(2000, dr.base.posted_gas_price.u128());
+
+    // doubling the escrow doubles the gas price, callback gas included
+    alice
+        .top_up_data_request(&dr_id, min_post_dr_cost() + callback_cost(100_000))
+        .unwrap();
+
+    let dr = alice.get_data_request(&dr_id).unwrap();
+    assert_eq!(2 * 2000, dr.base.posted_gas_price.u128());
+}
+
+#[test]
+fn top_up_while_tallying_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    // let the data request time out
+    test_info.set_block_height(51);
+    test_info.creator().expire_data_requests().unwrap();
+
+    let res = alice.top_up_data_request(&dr_id, min_post_dr_cost());
+    assert!(res.is_err_and(|e| e == ContractError::DataRequestTallying));
+}
+
+#[test]
+#[should_panic(expected = "NoFunds")]
+fn top_up_without_funds_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let msg = execute::top_up_request::Execute { dr_id }.into();
+    test_info.execute::<()>(&alice, &msg).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "not found")]
+fn top_up_non_existent_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 62, 1);
+
+    let dr = test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = dr.try_hash().unwrap().to_hex();
+
+    alice.top_up_data_request(&dr_id, min_post_dr_cost()).unwrap();
+}
@@ -88,6 +88,24 @@ where
         self.len.save(store, &last_index)?;
         Ok(())
     }
+
+    /// Replaces a key in the set with a new one at the same index in O(1)
+    /// time.
+    pub fn replace(&self, store: &mut dyn Storage, key: Key, new_key: Key) -> StdResult<()> {
+        if self.has(store, new_key.clone()) {
+            return Err(StdError::generic_err("Key already exists"));
+        }
+
+        let index = self
+            .key_to_index
+            .may_load(store, key.clone())?
+            .ok_or_else(|| StdError::generic_err("Key does not exist"))?;
+
+        self.key_to_index.remove(store, key);
+        self.index_to_key.save(store, index, &new_key)?;
+        self.key_to_index.save(store, new_key, &index)?;
+        Ok(())
+    }
 }
 
 #[macro_export]
@@ -13,8 +13,10 @@ mod enumerable_set;
 pub mod owner;
 mod sorted_set;
 pub mod staking;
+mod storage_transaction;
 pub use enumerable_set::EnumerableSet;
 pub use sorted_set::SortedSet;
+pub use storage_transaction::{transactional, StorageTransaction};
 
 pub trait QueryHandler {
     fn query(self, deps: Deps, env: Env) -> Result<Binary, ContractError>;
@@ -55,3 +57,10 @@ impl SudoHandler for SudoMsg {
         }
     }
 }
+
+#[cfg(test)]
+#[path = ""]
+mod tests {
+    use super::*;
+    mod storage_transaction_tests;
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::msgs::staking::state::STAKERS;
 
 impl ExecuteHandler for execute::add_to_allowlist::Execute {
     /// Add a `Secp256k1PublicKey` to the allow list
@@ -12,6 +13,7 @@ impl ExecuteHandler for execute::add_to_allowlist::Execute {
         // add the address to the allowlist
         let public_key = PublicKey::from_hex_str(&self.public_key)?;
         ALLOWLIST.save(deps.storage, &public_key, &true)?;
+        STAKERS.refresh_active(deps.storage, &public_key)?;
 
         Ok(Response::new().add_attribute("action", "add-to-allowlist").add_event(
             Event::new("seda-contract").add_attributes([
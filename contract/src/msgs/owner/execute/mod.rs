@@ -1,4 +1,4 @@
-use state::{ALLOWLIST, OWNER, PENDING_OWNER};
+use state::{ALLOWLIST, OWNER, PENDING_OWNER, TREASURY};
 
 use super::{
     msgs::owner::execute::{self, ExecuteMsg},
@@ -9,6 +9,7 @@ pub(in crate::msgs::owner) mod accept_ownership;
 pub(in crate::msgs::owner) mod add_to_allowlist;
 pub mod pause;
 pub(in crate::msgs::owner) mod remove_from_allowlist;
+pub(in crate::msgs::owner) mod set_treasury;
 pub(in crate::msgs::owner) mod transfer_ownership;
 pub mod unpause;
 
@@ -21,6 +22,7 @@ impl ExecuteHandler for ExecuteMsg {
             ExecuteMsg::RemoveFromAllowlist(msg) => msg.execute(deps, env, info),
             ExecuteMsg::Pause(msg) => msg.execute(deps, env, info),
             ExecuteMsg::Unpause(msg) => msg.execute(deps, env, info),
+            ExecuteMsg::SetTreasury(msg) => msg.execute(deps, env, info),
         }
     }
 }
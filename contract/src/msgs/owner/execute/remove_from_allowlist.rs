@@ -1,9 +1,11 @@
+use seda_common::msgs::staking::UnbondingEntry;
+
 use super::*;
-use crate::msgs::staking::state::STAKERS;
+use crate::msgs::staking::state::{STAKERS, STAKING_CONFIG};
 
 impl ExecuteHandler for execute::remove_from_allowlist::Execute {
     /// Remove a `Secp256k1PublicKey` to the allow list
-    fn execute(self, deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
         // require the sender to be the OWNER
         let owner = OWNER.load(deps.storage)?;
         if info.sender != owner {
@@ -13,20 +15,25 @@ impl ExecuteHandler for execute::remove_from_allowlist::Execute {
         // we need to remove the address from the allowlist
         let public_key = PublicKey::from_hex_str(&self.public_key)?;
 
-        if let Some(staker) = STAKERS.may_get_staker(deps.storage, &public_key)? {
-            // we move their staked tokens to the pending withdrawal
-            // so that they can withdraw them and no longer be a staker
-            let staker = Staker {
-                memo:                      staker.memo,
-                tokens_staked:             Uint128::new(0),
-                tokens_pending_withdrawal: staker.tokens_staked.checked_add(staker.tokens_pending_withdrawal)?,
-            };
+        if let Some(mut staker) = STAKERS.may_get_staker(deps.storage, &public_key)? {
+            // we unbond their staked tokens like an unstake would, so that they can
+            // withdraw them once the unbonding period has passed and no longer be a
+            // staker
+            if !staker.tokens_staked.is_zero() {
+                let unbonding_period_in_blocks = STAKING_CONFIG.load(deps.storage)?.unbonding_period_in_blocks;
+                staker.unbonding.push(UnbondingEntry {
+                    amount:         staker.tokens_staked,
+                    release_height: env.block.height + unbonding_period_in_blocks,
+                });
+                staker.tokens_staked = Uint128::zero();
+            }
 
             STAKERS.update(deps.storage, public_key.clone(), &staker)?;
         }
 
         // do this at the end in case we fail above
         ALLOWLIST.remove(deps.storage, &public_key);
+        STAKERS.refresh_active(deps.storage, &public_key)?;
 
         Ok(Response::new()
             .add_attribute("action", "remove-from-allowlist")
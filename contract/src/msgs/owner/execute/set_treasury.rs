@@ -0,0 +1,24 @@
+use super::*;
+
+impl ExecuteHandler for execute::set_treasury::Execute {
+    /// Set the address that treasury distributions are paid to
+    fn execute(self, deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        if info.sender != OWNER.load(deps.storage)? {
+            return Err(ContractError::NotOwner);
+        }
+        let treasury = deps.api.addr_validate(&self.treasury)?;
+        let previous_treasury = TREASURY.may_load(deps.storage)?;
+        TREASURY.save(deps.storage, &treasury)?;
+
+        Ok(Response::new().add_attribute("action", "set_treasury").add_event(
+            Event::new("seda-treasury").add_attributes([
+                ("version", CONTRACT_VERSION.to_string()),
+                ("treasury", treasury.into_string()),
+                (
+                    "previous_treasury",
+                    previous_treasury.map(Addr::into_string).unwrap_or_default(),
+                ),
+            ]),
+        ))
+    }
+}
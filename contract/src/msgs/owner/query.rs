@@ -1,6 +1,6 @@
 use super::{
     msgs::owner::query::QueryMsg,
-    state::{ALLOWLIST, OWNER, PENDING_OWNER},
+    state::{ALLOWLIST, OWNER, PENDING_OWNER, TREASURY},
     *,
 };
 use crate::state::PAUSED;
@@ -18,6 +18,7 @@ impl QueryHandler for QueryMsg {
                     .collect::<Vec<_>>();
                 to_json_binary(&allowlist)?
             }
+            QueryMsg::GetTreasury {} => to_json_binary(&TREASURY.may_load(deps.storage)?)?,
         };
 
         Ok(binary)
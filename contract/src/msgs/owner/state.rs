@@ -6,5 +6,8 @@ pub const OWNER: Item<Addr> = Item::new("owner");
 /// Address of pending staking contract owner.
 pub const PENDING_OWNER: Item<Option<Addr>> = Item::new("pending_owner");
 
+/// Address paid by treasury distributions, unset until the owner sets it.
+pub const TREASURY: Item<Addr> = Item::new("treasury");
+
 /// Allowlist of public keys that can register as a staker.
 pub const ALLOWLIST: Map<&PublicKey, bool> = Map::new("allowlist");
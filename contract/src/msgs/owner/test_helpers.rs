@@ -30,6 +30,20 @@ impl TestAccount {
         self.test_info.execute(self, &msg)
     }
 
+    #[track_caller]
+    pub fn set_treasury(&self, treasury: &TestAccount) -> Result<(), ContractError> {
+        let msg = execute::set_treasury::Execute {
+            treasury: treasury.addr().into_string(),
+        }
+        .into();
+        self.test_info.execute(self, &msg)
+    }
+
+    #[track_caller]
+    pub fn get_treasury(&self) -> Option<Addr> {
+        self.test_info.query(query::QueryMsg::GetTreasury {}).unwrap()
+    }
+
     #[track_caller]
     pub fn add_to_allowlist(&self, public_key: PublicKey) -> Result<(), ContractError> {
         let msg = execute::add_to_allowlist::Execute {
@@ -1,5 +1,8 @@
 use cosmwasm_std::Uint128;
-use seda_common::{msgs::staking::StakingConfig, types::ToHexStr};
+use seda_common::{
+    msgs::staking::{SelectionMode, StakingConfig, UnbondingEntry},
+    types::ToHexStr,
+};
 
 use crate::{error::ContractError, TestInfo};
 
@@ -87,8 +90,12 @@ fn allowlist_works() {
 
     // update the config with allowlist enabled
     let new_config = StakingConfig {
-        minimum_stake:     10u8.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 10u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     test_info.creator().set_staking_config(new_config).unwrap();
 
@@ -118,8 +125,12 @@ fn allowlist_works() {
 
     // update the config to disable the allowlist
     let new_config = StakingConfig {
-        minimum_stake:     10u8.into(),
-        allowlist_enabled: false,
+        minimum_stake:                 10u8.into(),
+        allowlist_enabled:             false,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     test_info.creator().set_staking_config(new_config).unwrap();
 
@@ -191,8 +202,12 @@ fn removing_from_allowlist_unstakes() {
 
     // update the config with allowlist enabled
     let new_config = StakingConfig {
-        minimum_stake:     10u8.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 10u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     test_info.creator().set_staking_config(new_config).unwrap();
     let alice = test_info.new_account("alice", 100);
@@ -209,5 +224,53 @@ fn removing_from_allowlist_unstakes() {
     // alice should no longer have any stake bu only tokens to withdraw
     let staker = alice.get_staker_info().unwrap();
     assert_eq!(staker.tokens_staked, Uint128::new(0));
-    assert_eq!(staker.tokens_pending_withdrawal, Uint128::new(10));
+    assert_eq!(
+        staker.unbonding,
+        vec![UnbondingEntry {
+            amount:         10u8.into(),
+            release_height: test_info.block_height(),
+        }]
+    );
+}
+
+#[test]
+fn remove_from_allowlist_waits_for_unbonding_period() {
+    let test_info = TestInfo::init();
+
+    let new_config = StakingConfig {
+        minimum_stake:                 10u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    10,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
+    };
+    test_info.creator().set_staking_config(new_config).unwrap();
+    let alice = test_info.new_account("alice", 100);
+    test_info.creator().add_to_allowlist(alice.pub_key()).unwrap();
+    alice.stake(10).unwrap();
+
+    // removing alice unbonds her stake instead of releasing it right away
+    test_info.set_block_height(5);
+    test_info.creator().remove_from_allowlist(alice.pub_key()).unwrap();
+    let res = alice.withdraw();
+    assert!(res.is_err_and(|x| x == ContractError::NothingToWithdraw));
+
+    test_info.set_block_height(15);
+    alice.withdraw().unwrap();
+    assert!(alice.get_staker_info().is_none());
+}
+
+#[test]
+fn set_treasury() {
+    let test_info = TestInfo::init();
+    let treasury = test_info.new_account("treasury", 0);
+    assert_eq!(None, treasury.get_treasury());
+
+    // non-owner cannot set the treasury
+    let res = treasury.set_treasury(&treasury);
+    assert!(res.is_err_and(|x| x == ContractError::NotOwner));
+
+    test_info.creator().set_treasury(&treasury).unwrap();
+    assert_eq!(Some(treasury.addr()), treasury.get_treasury());
 }
@@ -12,7 +12,7 @@ pub struct IndexKey {
 }
 
 impl Prefixer<'_> for IndexKey {
-    fn prefix(&self) -> Vec<cw_storage_plus::Key> {
+    fn prefix(&self) -> Vec<cw_storage_plus::Key<'_>> {
         let mut res = self.gas_price.prefix();
         res.extend(self.height.prefix());
         res
@@ -25,7 +25,7 @@ impl PrimaryKey<'_> for IndexKey {
     type Suffix = Hash;
     type SuperSuffix = (u64, Hash);
 
-    fn key(&self) -> Vec<cw_storage_plus::Key> {
+    fn key(&self) -> Vec<cw_storage_plus::Key<'_>> {
         let mut key = self.gas_price.key();
         key.extend(self.height.key());
         key.extend(self.dr_id.key());
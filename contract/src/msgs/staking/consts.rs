@@ -0,0 +1,2 @@
+/// Bounds the data requests a single eligibility batch query checks.
+pub const MAX_ELIGIBILITY_BATCH_SIZE: usize = 100;
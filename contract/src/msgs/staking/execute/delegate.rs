@@ -0,0 +1,44 @@
+use owner::utils::is_staker_allowed;
+use staking_events::{create_delegation_event, create_executor_event};
+use state::delegations::{load_reward_index, DELEGATIONS};
+
+use super::*;
+use crate::{state::*, utils::get_attached_funds};
+
+impl ExecuteHandler for execute::delegate::Execute {
+    /// Delegates the attached tokens to an executor, adding to their stake.
+    fn execute(self, deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+
+        // if allowlist is on, only allowed executors can receive delegations
+        is_staker_allowed(&deps, &public_key)?;
+        let mut executor = state::STAKERS.get_staker(deps.storage, &public_key)?;
+
+        // require token deposit
+        let token = TOKEN.load(deps.storage)?;
+        let amount = get_attached_funds(&info.funds, &token)?;
+
+        // accrue the rewards earned so far before the delegated amount changes
+        let reward_index = load_reward_index(deps.storage, &public_key)?;
+        let mut delegation = DELEGATIONS
+            .may_load(deps.storage, (public_key.as_ref(), &info.sender))?
+            .unwrap_or_default();
+        delegation.settle(reward_index)?;
+        delegation.amount += amount;
+        DELEGATIONS.save(deps.storage, (public_key.as_ref(), &info.sender), &delegation)?;
+
+        executor.tokens_delegated += amount;
+        state::STAKERS.update(deps.storage, public_key, &executor)?;
+
+        Ok(Response::new().add_attribute("action", "delegate").add_events([
+            create_delegation_event(
+                "delegate",
+                self.public_key.clone(),
+                info.sender.to_string(),
+                amount,
+                &delegation,
+            )?,
+            create_executor_event(executor, self.public_key)?,
+        ]))
+    }
+}
@@ -4,11 +4,18 @@ use super::{
 };
 use crate::state::PAUSED;
 
+pub(in crate::msgs::staking) mod delegate;
+pub(in crate::msgs::staking) mod rotate_key;
+pub(in crate::msgs::staking) mod set_commission_rate;
+pub(in crate::msgs::staking) mod set_reward_policy;
 pub(in crate::msgs::staking) mod set_staking_config;
 pub(in crate::msgs::staking) mod stake;
 pub(crate) mod staking_events;
+pub(in crate::msgs::staking) mod undelegate;
+pub(in crate::msgs::staking) mod unjail;
 pub(in crate::msgs::staking) mod unstake;
 pub(in crate::msgs::staking) mod withdraw;
+pub(in crate::msgs::staking) mod withdraw_delegation;
 
 impl ExecuteHandler for ExecuteMsg {
     fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
@@ -22,6 +29,13 @@ impl ExecuteHandler for ExecuteMsg {
             ExecuteMsg::Unstake(msg) => ExecuteHandler::execute(msg, deps, env, info),
             ExecuteMsg::Withdraw(msg) => ExecuteHandler::execute(msg, deps, env, info),
             ExecuteMsg::SetStakingConfig(msg) => ExecuteHandler::execute(msg, deps, env, info),
+            ExecuteMsg::Delegate(msg) => ExecuteHandler::execute(msg, deps, env, info),
+            ExecuteMsg::Undelegate(msg) => ExecuteHandler::execute(msg, deps, env, info),
+            ExecuteMsg::WithdrawDelegation(msg) => ExecuteHandler::execute(msg, deps, env, info),
+            ExecuteMsg::SetCommissionRate(msg) => ExecuteHandler::execute(msg, deps, env, info),
+            ExecuteMsg::RotateKey(msg) => ExecuteHandler::execute(msg, deps, env, info),
+            ExecuteMsg::Unjail(msg) => ExecuteHandler::execute(msg, deps, env, info),
+            ExecuteMsg::SetRewardPolicy(msg) => ExecuteHandler::execute(msg, deps, env, info),
         }
     }
 }
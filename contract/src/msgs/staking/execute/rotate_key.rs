@@ -0,0 +1,52 @@
+use owner::state::ALLOWLIST;
+use staking_events::create_executor_event;
+use state::{delegations::rotate_delegations, executor_stats::rotate_executor_stats};
+
+use super::*;
+use crate::state::*;
+
+impl ExecuteHandler for execute::rotate_key::Execute {
+    /// Moves a staker, along with their sequence, allowlist entry, delegations
+    /// and stats, to a new public key.
+    fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        // verify the proofs of both keys
+        let chain_id = CHAIN_ID.load(deps.storage)?;
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+        let new_public_key = PublicKey::from_hex_str(&self.new_public_key)?;
+        let seq = inc_get_seq(deps.storage, &public_key)?;
+        self.verify(
+            public_key.as_ref(),
+            new_public_key.as_ref(),
+            &chain_id,
+            env.contract.address.as_str(),
+            seq,
+        )?;
+
+        // a key that was rotated away from can't be reused, as it still redirects
+        if state::STAKERS.is_key_used(deps.storage, &new_public_key) {
+            return Err(ContractError::PublicKeyAlreadyUsed(self.new_public_key));
+        }
+
+        // the allowlist entry moves first, so the new key is indexed as active
+        if let Some(allowed) = ALLOWLIST.may_load(deps.storage, &public_key)? {
+            ALLOWLIST.remove(deps.storage, &public_key);
+            ALLOWLIST.save(deps.storage, &new_public_key, &allowed)?;
+        }
+        state::STAKERS.rotate(deps.storage, public_key.clone(), new_public_key.clone())?;
+        rotate_seq(deps.storage, &public_key, &new_public_key)?;
+        rotate_delegations(deps.storage, &public_key, &new_public_key)?;
+        rotate_executor_stats(deps.storage, &public_key, &new_public_key)?;
+
+        let executor = state::STAKERS.get_staker(deps.storage, &new_public_key)?;
+        Ok(Response::new().add_attribute("action", "rotate_key").add_events([
+            Event::new("seda-executor-key-rotation").add_attributes([
+                ("version", CONTRACT_VERSION.to_string()),
+                ("identity", self.public_key),
+                ("new_identity", self.new_public_key.clone()),
+                ("sender", info.sender.to_string()),
+                ("seq", seq.to_string()),
+            ]),
+            create_executor_event(executor, self.new_public_key)?,
+        ]))
+    }
+}
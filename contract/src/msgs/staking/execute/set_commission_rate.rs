@@ -0,0 +1,27 @@
+use staking_events::create_executor_event;
+
+use super::*;
+use crate::{consts::BPS_DENOMINATOR, state::*};
+
+impl ExecuteHandler for execute::set_commission_rate::Execute {
+    /// Sets the share of the delegators' rewards an executor keeps.
+    fn execute(self, deps: DepsMut, env: Env, _info: MessageInfo) -> Result<Response, ContractError> {
+        // verify the proof
+        let chain_id = CHAIN_ID.load(deps.storage)?;
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+        let seq = inc_get_seq(deps.storage, &public_key)?;
+        self.verify(public_key.as_ref(), &chain_id, env.contract.address.as_str(), seq)?;
+
+        if self.commission_rate_bps > BPS_DENOMINATOR {
+            return Err(ContractError::InvalidCommissionRate(self.commission_rate_bps));
+        }
+
+        let mut executor = state::STAKERS.get_staker(deps.storage, &public_key)?;
+        executor.commission_rate_bps = self.commission_rate_bps;
+        state::STAKERS.update(deps.storage, public_key, &executor)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "set_commission_rate")
+            .add_event(create_executor_event(executor, self.public_key)?))
+    }
+}
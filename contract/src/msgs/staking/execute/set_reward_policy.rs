@@ -0,0 +1,27 @@
+use staking_events::create_executor_event;
+
+use super::*;
+use crate::{consts::BPS_DENOMINATOR, state::*};
+
+impl ExecuteHandler for execute::set_reward_policy::Execute {
+    /// Sets the share of an executor's rewards that is added to their stake.
+    fn execute(self, deps: DepsMut, env: Env, _info: MessageInfo) -> Result<Response, ContractError> {
+        // verify the proof
+        let chain_id = CHAIN_ID.load(deps.storage)?;
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+        let seq = inc_get_seq(deps.storage, &public_key)?;
+        self.verify(public_key.as_ref(), &chain_id, env.contract.address.as_str(), seq)?;
+
+        if self.restake_ratio_bps > BPS_DENOMINATOR {
+            return Err(ContractError::InvalidRestakeRatio(self.restake_ratio_bps));
+        }
+
+        let mut executor = state::STAKERS.get_staker(deps.storage, &public_key)?;
+        executor.restake_ratio_bps = self.restake_ratio_bps;
+        state::STAKERS.update(deps.storage, public_key, &executor)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "set_reward_policy")
+            .add_event(create_executor_event(executor, self.public_key)?))
+    }
+}
@@ -33,6 +33,12 @@ impl ExecuteHandler for execute::stake::Execute {
                     memo:                      self.memo.clone(),
                     tokens_staked:             amount,
                     tokens_pending_withdrawal: Uint128::zero(),
+                    unbonding:                 vec![],
+                    tokens_delegated:          Uint128::zero(),
+                    commission_rate_bps:       0,
+                    missed_reveals:            0,
+                    jailed_until:              None,
+                    restake_ratio_bps:         0,
                 };
                 state::STAKERS.insert(deps.storage, public_key, &executor)?;
                 executor
@@ -52,7 +58,7 @@ impl ExecuteHandler for execute::stake::Execute {
 
         Ok(Response::new().add_attribute("action", "stake").add_events([
             create_executor_action_event("stake", self.public_key.clone(), info.sender.to_string(), amount, seq),
-            create_executor_event(executor, self.public_key),
+            create_executor_event(executor, self.public_key)?,
         ]))
     }
 }
@@ -1,10 +1,10 @@
-use cosmwasm_std::{Event, Uint128};
-use seda_common::msgs::staking::{Staker, StakingConfig};
+use cosmwasm_std::{to_json_string, Event, StdResult, Uint128};
+use seda_common::msgs::staking::{SelectionMode, Staker, StakingConfig};
 
-use super::CONTRACT_VERSION;
+use super::{state::delegations::DelegationInfo, CONTRACT_VERSION};
 
-pub fn create_executor_event(staker: Staker, public_key: String) -> Event {
-    Event::new("seda-executor").add_attributes([
+pub fn create_executor_event(staker: Staker, public_key: String) -> StdResult<Event> {
+    Ok(Event::new("seda-executor").add_attributes([
         ("version", CONTRACT_VERSION.to_string()),
         ("identity", public_key),
         ("tokens_staked", staker.tokens_staked.to_string()),
@@ -12,8 +12,14 @@ pub fn create_executor_event(staker: Staker, public_key: String) -> Event {
             "tokens_pending_withdrawal",
             staker.tokens_pending_withdrawal.to_string(),
         ),
+        ("unbonding", to_json_string(&staker.unbonding)?),
+        ("missed_reveals", staker.missed_reveals.to_string()),
+        (
+            "jailed_until",
+            staker.jailed_until.map(|height| height.to_string()).unwrap_or_default(),
+        ),
         ("memo", staker.memo.map(|m| m.to_base64()).unwrap_or_default()),
-    ])
+    ]))
 }
 
 pub(in crate::msgs::staking::execute) fn create_executor_action_event(
@@ -38,5 +44,41 @@ pub fn create_staking_config_event(config: StakingConfig) -> Event {
         ("version", CONTRACT_VERSION.to_string()),
         ("minimum_stake", config.minimum_stake.to_string()),
         ("allowlist_enabled", config.allowlist_enabled.to_string()),
+        (
+            "unbonding_period_in_blocks",
+            config.unbonding_period_in_blocks.to_string(),
+        ),
+        (
+            "missed_reveals_jail_threshold",
+            config.missed_reveals_jail_threshold.to_string(),
+        ),
+        ("jail_duration_in_blocks", config.jail_duration_in_blocks.to_string()),
+        (
+            "selection_mode",
+            match config.selection_mode {
+                SelectionMode::Uniform => "uniform",
+                SelectionMode::StakeWeighted => "stake_weighted",
+            }
+            .to_string(),
+        ),
     ])
 }
+
+pub(in crate::msgs::staking::execute) fn create_delegation_event(
+    action: &str,
+    public_key: String,
+    delegator: String,
+    amount: Uint128,
+    delegation: &DelegationInfo,
+) -> StdResult<Event> {
+    Ok(Event::new("seda-delegation").add_attributes([
+        ("version", CONTRACT_VERSION.to_string()),
+        ("action", action.to_string()),
+        ("identity", public_key),
+        ("delegator", delegator),
+        ("amount", amount.to_string()),
+        ("tokens_delegated", delegation.amount.to_string()),
+        ("accrued_rewards", delegation.accrued_rewards.to_string()),
+        ("unbonding", to_json_string(&delegation.unbonding)?),
+    ]))
+}
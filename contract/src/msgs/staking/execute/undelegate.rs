@@ -0,0 +1,48 @@
+use seda_common::msgs::staking::UnbondingEntry;
+use staking_events::{create_delegation_event, create_executor_event};
+use state::delegations::{load_reward_index, DELEGATIONS};
+
+use super::*;
+
+impl ExecuteHandler for execute::undelegate::Execute {
+    /// Undelegates tokens from an executor, locking them until the unbonding
+    /// period has passed.
+    fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+        let mut delegation = DELEGATIONS
+            .may_load(deps.storage, (public_key.as_ref(), &info.sender))?
+            .ok_or(ContractError::NoDelegation)?;
+
+        if self.amount > delegation.amount {
+            return Err(ContractError::InsufficientStake(self.amount, delegation.amount));
+        }
+
+        // accrue the rewards earned so far before the delegated amount changes
+        let reward_index = load_reward_index(deps.storage, &public_key)?;
+        delegation.settle(reward_index)?;
+        delegation.amount -= self.amount;
+        if !self.amount.is_zero() {
+            let unbonding_period = state::STAKING_CONFIG.load(deps.storage)?.unbonding_period_in_blocks;
+            delegation.unbonding.push(UnbondingEntry {
+                amount:         self.amount,
+                release_height: env.block.height + unbonding_period,
+            });
+        }
+        DELEGATIONS.save(deps.storage, (public_key.as_ref(), &info.sender), &delegation)?;
+
+        let mut executor = state::STAKERS.get_staker(deps.storage, &public_key)?;
+        executor.tokens_delegated -= self.amount;
+        state::STAKERS.update(deps.storage, public_key, &executor)?;
+
+        Ok(Response::new().add_attribute("action", "undelegate").add_events([
+            create_delegation_event(
+                "undelegate",
+                self.public_key.clone(),
+                info.sender.to_string(),
+                self.amount,
+                &delegation,
+            )?,
+            create_executor_event(executor, self.public_key)?,
+        ]))
+    }
+}
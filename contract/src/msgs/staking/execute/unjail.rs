@@ -0,0 +1,37 @@
+use staking_events::{create_executor_action_event, create_executor_event};
+
+use super::*;
+use crate::state::*;
+
+impl ExecuteHandler for execute::unjail::Execute {
+    /// Releases a jailed executor once their jail period has passed.
+    fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        // verify the proof
+        let chain_id = CHAIN_ID.load(deps.storage)?;
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+        let seq = inc_get_seq(deps.storage, &public_key)?;
+        self.verify(public_key.as_ref(), &chain_id, env.contract.address.as_str(), seq)?;
+
+        let mut executor = state::STAKERS.get_staker(deps.storage, &public_key)?;
+        let Some(jailed_until) = executor.jailed_until else {
+            return Err(ContractError::NotJailed);
+        };
+        if env.block.height < jailed_until {
+            return Err(ContractError::JailPeriodNotOver(jailed_until));
+        }
+
+        executor.jailed_until = None;
+        state::STAKERS.update(deps.storage, public_key, &executor)?;
+
+        Ok(Response::new().add_attribute("action", "unjail").add_events([
+            create_executor_action_event(
+                "unjail",
+                self.public_key.clone(),
+                info.sender.to_string(),
+                Uint128::zero(),
+                seq,
+            ),
+            create_executor_event(executor, self.public_key)?,
+        ]))
+    }
+}
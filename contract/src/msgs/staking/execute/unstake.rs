@@ -1,10 +1,12 @@
+use seda_common::msgs::staking::UnbondingEntry;
 use staking_events::{create_executor_action_event, create_executor_event};
 
 use super::*;
 use crate::state::*;
 
 impl ExecuteHandler for execute::unstake::Execute {
-    /// Unstakes all tokens from a given staker.
+    /// Unstakes the requested amount, or all tokens, from a given staker,
+    /// locking them until the unbonding period has passed.
     fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
         // verify the proof
         let chain_id = CHAIN_ID.load(deps.storage)?;
@@ -13,18 +15,44 @@ impl ExecuteHandler for execute::unstake::Execute {
         self.verify(public_key.as_ref(), &chain_id, env.contract.address.as_str(), seq)?;
 
         let mut executor = state::STAKERS.get_staker(deps.storage, &public_key)?;
+        let staking_config = state::STAKING_CONFIG.load(deps.storage)?;
+
+        let amount = self.amount.unwrap_or(executor.tokens_staked);
+        if amount > executor.tokens_staked {
+            return Err(ContractError::InsufficientStake(amount, executor.tokens_staked));
+        }
+
+        // a partial unstake must leave the executor with at least the minimum stake
+        let remaining = executor.tokens_staked - amount;
+        if !remaining.is_zero() && remaining < staking_config.minimum_stake {
+            return Err(ContractError::RemainingStakeBelowMinimum(
+                staking_config.minimum_stake,
+                remaining,
+            ));
+        }
 
         // update the executor
-        let amount = executor.tokens_staked;
-        executor.tokens_staked -= amount;
-        executor.tokens_pending_withdrawal += amount;
+        executor.tokens_staked = remaining;
+        if !amount.is_zero() {
+            executor.unbonding.push(UnbondingEntry {
+                amount,
+                release_height: env.block.height + staking_config.unbonding_period_in_blocks,
+            });
+        }
         state::STAKERS.update(deps.storage, public_key, &executor)?;
 
-        // TODO: emit when pending tokens can be withdrawn
-
         Ok(Response::new().add_attribute("action", "unstake").add_events([
-            create_executor_action_event("unstake", self.public_key.clone(), info.sender.to_string(), amount, seq),
-            create_executor_event(executor, self.public_key),
+            create_executor_action_event("unstake", self.public_key.clone(), info.sender.to_string(), amount, seq)
+                .add_attributes([
+                    // without an amount everything staked was requested
+                    (
+                        "requested_amount",
+                        self.amount
+                            .map_or_else(|| "all".to_string(), |amount| amount.to_string()),
+                    ),
+                    ("remaining_stake", remaining.to_string()),
+                ]),
+            create_executor_event(executor, self.public_key)?,
         ]))
     }
 }
@@ -4,7 +4,8 @@ use super::*;
 use crate::state::*;
 
 impl ExecuteHandler for execute::withdraw::Execute {
-    /// Sends tokens back to the sender that are marked as pending withdrawal.
+    /// Sends tokens back to the sender that are marked as pending withdrawal,
+    /// along with any unbonding tokens that have reached their release height.
     fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
         // verify the proof
         let chain_id = CHAIN_ID.load(deps.storage)?;
@@ -12,16 +13,32 @@ impl ExecuteHandler for execute::withdraw::Execute {
         let seq = inc_get_seq(deps.storage, &public_key)?;
         self.verify(public_key.as_ref(), &chain_id, env.contract.address.as_str(), seq)?;
 
-        // TODO: add delay after calling unstake
         let token = TOKEN.load(deps.storage)?;
 
-        // error if amount is greater than pending tokens
+        // release the unbonding entries that have matured
         let mut executor = state::STAKERS.get_staker(deps.storage, &public_key)?;
-        let amount = executor.tokens_pending_withdrawal;
+        let (matured, unbonding): (Vec<_>, Vec<_>) = executor
+            .unbonding
+            .into_iter()
+            .partition(|entry| entry.release_height <= env.block.height);
+        executor.unbonding = unbonding;
+        let amount = matured
+            .into_iter()
+            .try_fold(executor.tokens_pending_withdrawal, |total, entry| {
+                total.checked_add(entry.amount)
+            })?;
+        if amount.is_zero() {
+            return Err(ContractError::NothingToWithdraw);
+        }
 
-        // update the executor (remove if balances are zero)
-        executor.tokens_pending_withdrawal -= amount;
-        if executor.tokens_pending_withdrawal.is_zero() && executor.tokens_staked.is_zero() {
+        // update the executor (remove if balances are zero, nothing is delegated to
+        // them and they are not jailed)
+        executor.tokens_pending_withdrawal = Uint128::zero();
+        if executor.tokens_staked.is_zero()
+            && executor.unbonding.is_empty()
+            && executor.tokens_delegated.is_zero()
+            && executor.jailed_until.is_none()
+        {
             state::STAKERS.remove(deps.storage, public_key)?;
         } else {
             state::STAKERS.update(deps.storage, public_key, &executor)?;
@@ -48,7 +65,7 @@ impl ExecuteHandler for execute::withdraw::Execute {
                     amount,
                     seq,
                 ),
-                create_executor_event(executor, self.public_key),
+                create_executor_event(executor, self.public_key)?,
             ]))
     }
 }
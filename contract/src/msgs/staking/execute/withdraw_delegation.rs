@@ -0,0 +1,59 @@
+use staking_events::create_delegation_event;
+use state::delegations::{load_reward_index, DELEGATIONS};
+
+use super::*;
+use crate::state::*;
+
+impl ExecuteHandler for execute::withdraw_delegation::Execute {
+    /// Sends the accrued rewards and any undelegated tokens that have reached
+    /// their release height back to the delegator.
+    fn execute(self, deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let public_key = PublicKey::from_hex_str(&self.public_key)?;
+        let mut delegation = DELEGATIONS
+            .may_load(deps.storage, (public_key.as_ref(), &info.sender))?
+            .ok_or(ContractError::NoDelegation)?;
+
+        let reward_index = load_reward_index(deps.storage, &public_key)?;
+        delegation.settle(reward_index)?;
+
+        // release the unbonding entries that have matured
+        let (matured, unbonding): (Vec<_>, Vec<_>) = delegation
+            .unbonding
+            .into_iter()
+            .partition(|entry| entry.release_height <= env.block.height);
+        delegation.unbonding = unbonding;
+        let amount = matured
+            .into_iter()
+            .try_fold(delegation.accrued_rewards, |total, entry| {
+                total.checked_add(entry.amount)
+            })?;
+        if amount.is_zero() {
+            return Err(ContractError::NothingToWithdraw);
+        }
+
+        // update the delegation (remove if nothing is left)
+        delegation.accrued_rewards = Uint128::zero();
+        if delegation.is_empty() {
+            DELEGATIONS.remove(deps.storage, (public_key.as_ref(), &info.sender));
+        } else {
+            DELEGATIONS.save(deps.storage, (public_key.as_ref(), &info.sender), &delegation)?;
+        }
+
+        let token = TOKEN.load(deps.storage)?;
+        let bank_msg = BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount:     coins(amount.u128(), token),
+        };
+
+        Ok(Response::new()
+            .add_message(bank_msg)
+            .add_attribute("action", "withdraw_delegation")
+            .add_event(create_delegation_event(
+                "withdraw",
+                self.public_key,
+                info.sender.to_string(),
+                amount,
+                &delegation,
+            )?))
+    }
+}
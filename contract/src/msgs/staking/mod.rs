@@ -1,5 +1,6 @@
 use super::*;
 
+pub mod consts;
 pub mod execute;
 pub mod query;
 
@@ -1,15 +1,26 @@
 use cw_storage_plus::Bound;
-pub use seda_common::msgs::staking::query::{is_executor_eligible, QueryMsg};
+pub use seda_common::msgs::staking::query::{is_executor_eligible, is_executor_eligible_batch, QueryMsg};
 use seda_common::msgs::staking::{
+    DataRequestEligibility,
+    Delegation,
     Executor,
     ExecutorEligibilityStatus,
+    ExecutorStats,
+    GetDelegationsResponse,
+    GetExecutorEligibilityBatchResponse,
     GetExecutorEligibilityResponse,
+    GetExecutorStatsResponse,
     GetExecutorsResponse,
     StakerAndSeq,
 };
-use state::{is_eligible_for_dr::is_eligible_for_dr, STAKERS};
+use state::{
+    delegations::{load_reward_index, DelegationInfo, DELEGATIONS},
+    executor_stats::{ranked_executors, EXECUTOR_STATS},
+    is_eligible_for_dr::is_eligible_for_dr,
+    STAKERS,
+};
 
-use super::*;
+use super::{consts::MAX_ELIGIBILITY_BATCH_SIZE, *};
 use crate::state::get_seq;
 
 impl QueryHandler for QueryMsg {
@@ -57,36 +68,91 @@ impl QueryHandler for QueryMsg {
                 to_json_binary(&response)?
             }
             QueryMsg::GetExecutorEligibility(query) => get_executor_eligibility(query, deps, env)?,
+            QueryMsg::GetExecutorEligibilityBatch(query) => get_executor_eligibility_batch(query, deps, env)?,
+            QueryMsg::GetDelegation { delegator, public_key } => {
+                let delegator = deps.api.addr_validate(&delegator)?;
+                let executor = PublicKey::from_hex_str(&public_key)?;
+                let reward_index = load_reward_index(deps.storage, &executor)?;
+                let delegation = DELEGATIONS
+                    .may_load(deps.storage, (executor.as_ref(), &delegator))?
+                    .map(|delegation| to_delegation(delegation, reward_index, &delegator, &public_key))
+                    .transpose()?;
+                to_json_binary(&delegation)?
+            }
+            QueryMsg::GetDelegations {
+                public_key,
+                start_after,
+                limit,
+            } => {
+                let executor = PublicKey::from_hex_str(&public_key)?;
+                let reward_index = load_reward_index(deps.storage, &executor)?;
+                let start_after = start_after.map(|addr| deps.api.addr_validate(&addr)).transpose()?;
+                let delegations = DELEGATIONS
+                    .prefix(executor.as_ref())
+                    .range(
+                        deps.storage,
+                        start_after.as_ref().map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit as usize)
+                    .map(|r| {
+                        let (delegator, delegation) = r?;
+                        to_delegation(delegation, reward_index, &delegator, &public_key)
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+
+                to_json_binary(&GetDelegationsResponse { delegations })?
+            }
+            QueryMsg::GetExecutorStats { sort_by, offset, limit } => {
+                let stats = ranked_executors(deps.storage, sort_by)
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .map(|public_key| {
+                        let public_key = PublicKey::try_from(public_key?.as_slice())?;
+                        let stats = EXECUTOR_STATS.load(deps.storage, &public_key)?;
+                        Ok(ExecutorStats {
+                            public_key:         public_key.to_hex(),
+                            commits:            stats.commits,
+                            reveals:            stats.reveals,
+                            missed_reveals:     stats.missed_reveals,
+                            timeouts:           stats.timeouts,
+                            rewards_received:   stats.rewards_received,
+                            last_active_height: stats.last_active_height,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, ContractError>>()?;
+
+                to_json_binary(&GetExecutorStatsResponse { stats })?
+            }
         };
 
         Ok(binary)
     }
 }
 
+/// Builds the query response for a delegation, including the rewards accrued
+/// since it was last settled.
+fn to_delegation(
+    mut delegation: DelegationInfo,
+    reward_index: Decimal256,
+    delegator: &Addr,
+    public_key: &str,
+) -> StdResult<Delegation> {
+    delegation.settle(reward_index)?;
+    Ok(Delegation {
+        delegator:       delegator.to_string(),
+        public_key:      public_key.to_string(),
+        amount:          delegation.amount,
+        accrued_rewards: delegation.accrued_rewards,
+        unbonding:       delegation.unbonding,
+    })
+}
+
 impl QueryHandler for is_executor_eligible::Query {
     fn query(self, deps: Deps, env: Env) -> Result<Binary, ContractError> {
-        let (executor, dr_id, _) = self.parts()?;
-        let executor = PublicKey(executor);
-
-        // Validate signature
-        let chain_id = crate::state::CHAIN_ID.load(deps.storage)?;
-        if self
-            .verify(&executor, &chain_id, env.contract.address.as_str())
-            .is_err()
-        {
-            return Ok(to_json_binary(&false)?);
-        }
-
-        // Check DR is in data_request_pool
-        if data_requests::state::load_request(deps.storage, &dr_id).is_err() {
-            return Ok(to_json_binary(&false)?);
-        }
-
-        if !STAKERS.is_staker_executor(deps.storage, &executor)? {
-            return Ok(to_json_binary(&false)?);
-        }
-
-        Ok(to_json_binary(&is_eligible_for_dr(deps, env, dr_id, executor)?)?)
+        let status = verified_eligibility_status(&self, deps, &env)?;
+        Ok(to_json_binary(&(status == ExecutorEligibilityStatus::Eligible))?)
     }
 }
 
@@ -94,9 +160,23 @@ impl QueryHandler for is_executor_eligible::Query {
 // `is_executor_eligible::Query` but returns a more detailed response that
 // includes both the eligibility status and the current block height.
 fn get_executor_eligibility(query: is_executor_eligible::Query, deps: Deps, env: Env) -> Result<Binary, ContractError> {
-    let (executor, dr_id, _) = query.parts()?;
-    let executor = PublicKey(executor);
-    let block_height = env.block.height;
+    Ok(to_json_binary(&GetExecutorEligibilityResponse {
+        status:       verified_eligibility_status(&query, deps, &env)?,
+        block_height: env.block.height,
+    })?)
+}
+
+/// Decodes and authenticates an eligibility query before checking the
+/// executor's eligibility.
+fn verified_eligibility_status(
+    query: &is_executor_eligible::Query,
+    deps: Deps,
+    env: &Env,
+) -> Result<ExecutorEligibilityStatus, ContractError> {
+    let Ok(payload) = query.payload() else {
+        return Ok(ExecutorEligibilityStatus::MalformedRequest);
+    };
+    let executor = PublicKey(payload.public_key);
 
     // Validate signature
     let chain_id = crate::state::CHAIN_ID.load(deps.storage)?;
@@ -104,35 +184,79 @@ fn get_executor_eligibility(query: is_executor_eligible::Query, deps: Deps, env:
         .verify(&executor, &chain_id, env.contract.address.as_str())
         .is_err()
     {
-        return Ok(to_json_binary(&GetExecutorEligibilityResponse {
-            status: ExecutorEligibilityStatus::InvalidSignature,
-            block_height,
-        })?);
+        return Ok(ExecutorEligibilityStatus::InvalidSignature);
+    }
+
+    // an expired query is treated like a signature that is no longer valid
+    if payload
+        .expiry_height
+        .is_some_and(|expiry_height| env.block.height > expiry_height)
+    {
+        return Ok(ExecutorEligibilityStatus::InvalidSignature);
     }
 
+    eligibility_status(deps, env, payload.dr_id, executor)
+}
+
+/// Checks the eligibility of one executor for many data requests, authenticated
+/// by a single proof over the whole list of data request IDs.
+fn get_executor_eligibility_batch(
+    query: is_executor_eligible_batch::Query,
+    deps: Deps,
+    env: Env,
+) -> Result<Binary, ContractError> {
+    if query.dr_ids.len() > MAX_ELIGIBILITY_BATCH_SIZE {
+        return Err(ContractError::EligibilityBatchTooLarge(
+            query.dr_ids.len(),
+            MAX_ELIGIBILITY_BATCH_SIZE,
+        ));
+    }
+    let executor = PublicKey::from_hex_str(&query.public_key)?;
+
+    // Validate signature
+    let chain_id = crate::state::CHAIN_ID.load(deps.storage)?;
+    let valid_signature = query
+        .verify(&executor, &chain_id, env.contract.address.as_str())
+        .is_ok();
+
+    let eligibilities = query
+        .dr_ids
+        .into_iter()
+        .map(|dr_id| {
+            let status = match Hash::from_hex_str(&dr_id) {
+                _ if !valid_signature => ExecutorEligibilityStatus::InvalidSignature,
+                Ok(hash) => eligibility_status(deps, &env, hash, executor.clone())?,
+                Err(_) => ExecutorEligibilityStatus::MalformedRequest,
+            };
+            Ok(DataRequestEligibility { dr_id, status })
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(to_json_binary(&GetExecutorEligibilityBatchResponse {
+        eligibilities,
+        block_height: env.block.height,
+    })?)
+}
+
+/// The eligibility of an executor whose signature was already validated.
+fn eligibility_status(
+    deps: Deps,
+    env: &Env,
+    dr_id: Hash,
+    executor: PublicKey,
+) -> Result<ExecutorEligibilityStatus, ContractError> {
     // Check DR is in data_request_pool
     if data_requests::state::load_request(deps.storage, &dr_id).is_err() {
-        return Ok(to_json_binary(&GetExecutorEligibilityResponse {
-            status: ExecutorEligibilityStatus::DataRequestNotFound,
-            block_height,
-        })?);
+        return Ok(ExecutorEligibilityStatus::DataRequestNotFound);
     }
 
     if !STAKERS.is_staker_executor(deps.storage, &executor)? {
-        return Ok(to_json_binary(&GetExecutorEligibilityResponse {
-            status: ExecutorEligibilityStatus::NotStaker,
-            block_height,
-        })?);
+        return Ok(ExecutorEligibilityStatus::NotStaker);
     }
 
-    let is_eligible = is_eligible_for_dr(deps, env, dr_id, executor)?;
-
-    Ok(to_json_binary(&GetExecutorEligibilityResponse {
-        status: if is_eligible {
-            ExecutorEligibilityStatus::Eligible
-        } else {
-            ExecutorEligibilityStatus::NotEligible
-        },
-        block_height,
-    })?)
+    Ok(if is_eligible_for_dr(deps, env.clone(), dr_id, executor)? {
+        ExecutorEligibilityStatus::Eligible
+    } else {
+        ExecutorEligibilityStatus::NotEligible
+    })
 }
@@ -0,0 +1,77 @@
+use cosmwasm_schema::cw_serde;
+use seda_common::msgs::staking::UnbondingEntry;
+
+use super::*;
+
+/// Tokens a delegator has delegated to an executor.
+#[cw_serde]
+#[derive(Default)]
+pub struct DelegationInfo {
+    pub amount:          Uint128,
+    /// The executor's reward index when the rewards were last accrued.
+    pub reward_index:    Decimal256,
+    pub accrued_rewards: Uint128,
+    pub unbonding:       Vec<UnbondingEntry>,
+}
+
+impl DelegationInfo {
+    /// Accrues the rewards earned since the last settlement, so the amount can
+    /// safely change afterwards.
+    pub fn settle(&mut self, reward_index: Decimal256) -> StdResult<()> {
+        let earned = (reward_index - self.reward_index).checked_mul(Decimal256::from_ratio(self.amount, 1u128))?;
+        self.accrued_rewards += Uint128::try_from(earned.to_uint_floor())?;
+        self.reward_index = reward_index;
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.amount.is_zero() && self.accrued_rewards.is_zero() && self.unbonding.is_empty()
+    }
+}
+
+/// Maps an executor public key and delegator address to the delegation.
+pub const DELEGATIONS: Map<(&[u8], &Addr), DelegationInfo> = Map::new("delegations");
+
+/// Maps an executor public key to the rewards earned per delegated token.
+pub const DELEGATOR_REWARD_INDEX: Map<&[u8], Decimal256> = Map::new("delegator_reward_index");
+
+pub fn load_reward_index(store: &dyn Storage, public_key: &PublicKey) -> StdResult<Decimal256> {
+    Ok(DELEGATOR_REWARD_INDEX
+        .may_load(store, public_key.as_ref())?
+        .unwrap_or_default())
+}
+
+/// Shares `amount` between everyone delegating to the executor.
+pub fn distribute_delegator_rewards(
+    store: &mut dyn Storage,
+    public_key: &PublicKey,
+    amount: Uint128,
+    tokens_delegated: Uint128,
+) -> StdResult<()> {
+    let reward_index = load_reward_index(store, public_key)? + Decimal256::from_ratio(amount, tokens_delegated);
+    DELEGATOR_REWARD_INDEX.save(store, public_key.as_ref(), &reward_index)
+}
+
+/// Moves all delegations and the reward index of an executor to their new
+/// public key.
+pub fn rotate_delegations(
+    store: &mut dyn Storage,
+    public_key: &PublicKey,
+    new_public_key: &PublicKey,
+) -> StdResult<()> {
+    let delegations = DELEGATIONS
+        .prefix(public_key.as_ref())
+        .range(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (delegator, delegation) in delegations {
+        DELEGATIONS.remove(store, (public_key.as_ref(), &delegator));
+        DELEGATIONS.save(store, (new_public_key.as_ref(), &delegator), &delegation)?;
+    }
+
+    if let Some(reward_index) = DELEGATOR_REWARD_INDEX.may_load(store, public_key.as_ref())? {
+        DELEGATOR_REWARD_INDEX.remove(store, public_key.as_ref());
+        DELEGATOR_REWARD_INDEX.save(store, new_public_key.as_ref(), &reward_index)?;
+    }
+
+    Ok(())
+}
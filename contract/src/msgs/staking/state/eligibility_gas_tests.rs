@@ -0,0 +1,173 @@
+use std::cell::Cell;
+
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use seda_common::msgs::staking::SelectionMode;
+
+use super::{executor_snapshots::reference_snapshot, is_eligible_for_dr::is_eligible_for_dr, *};
+use crate::{
+    consts::INITIAL_DR_CONFIG,
+    msgs::data_requests::{
+        state::{init_data_requests, post_request, DR_CONFIG, DR_EXECUTOR_SNAPSHOT},
+        test_helpers::{calculate_dr_id_and_args, construct_dr},
+    },
+};
+
+/// Cosmos SDK gas charged for a read, on top of 3 gas per byte read.
+const READ_COST_FLAT: u64 = 1000;
+/// Cosmos SDK gas charged for each step of an iterator, on top of 3 gas per
+/// byte read.
+const ITER_NEXT_COST_FLAT: u64 = 30;
+const READ_COST_PER_BYTE: u64 = 3;
+
+/// Read only storage that adds up the gas the Cosmos SDK charges for every
+/// access.
+struct GasMeteredStorage<'a> {
+    inner: &'a MockStorage,
+    gas:   Cell<u64>,
+}
+
+impl Storage for GasMeteredStorage<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        let value = self.inner.get(key);
+        let bytes = key.len() + value.as_ref().map_or(0, Vec::len);
+        self.gas
+            .set(self.gas.get() + READ_COST_FLAT + READ_COST_PER_BYTE * bytes as u64);
+        value
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        Box::new(self.inner.range(start, end, order).inspect(|(key, value)| {
+            let bytes = key.len() + value.len();
+            self.gas
+                .set(self.gas.get() + ITER_NEXT_COST_FLAT + READ_COST_PER_BYTE * bytes as u64);
+        }))
+    }
+
+    fn set(&mut self, _key: &[u8], _value: &[u8]) {
+        unreachable!("eligibility checks only read from storage")
+    }
+
+    fn remove(&mut self, _key: &[u8]) {
+        unreachable!("eligibility checks only read from storage")
+    }
+}
+
+fn public_key(i: u32) -> PublicKey {
+    let mut key = [2; 33];
+    key[29..].copy_from_slice(&i.to_be_bytes());
+    PublicKey(key)
+}
+
+/// Stores `executors` active executors and a data request posted at height 1
+/// with a replication factor of 1.
+fn setup(executors: u32) -> (MockStorage, Hash) {
+    let mut store = MockStorage::new();
+    STAKING_CONFIG
+        .save(
+            &mut store,
+            &StakingConfig {
+                minimum_stake:                 1u8.into(),
+                allowlist_enabled:             false,
+                unbonding_period_in_blocks:    0,
+                missed_reveals_jail_threshold: 0,
+                jail_duration_in_blocks:       0,
+                selection_mode:                SelectionMode::Uniform,
+            },
+        )
+        .unwrap();
+    DR_CONFIG.save(&mut store, &INITIAL_DR_CONFIG).unwrap();
+    STAKERS.initialize(&mut store).unwrap();
+    init_data_requests(&mut store).unwrap();
+
+    for i in 0..executors {
+        let staker = Staker {
+            memo:                      None,
+            tokens_staked:             100u8.into(),
+            tokens_pending_withdrawal: 0u8.into(),
+            unbonding:                 vec![],
+            tokens_delegated:          0u8.into(),
+            commission_rate_bps:       0,
+            missed_reveals:            0,
+            jailed_until:              None,
+            restake_ratio_bps:         0,
+        };
+        STAKERS.insert(&mut store, public_key(i), &staker).unwrap();
+    }
+
+    let dr = construct_dr(calculate_dr_id_and_args(1, 1), vec![], 1, 0);
+    let dr_id = Hash::from_hex_str(&dr.base.id).unwrap();
+    post_request(&mut store, 1, &dr_id, dr).unwrap();
+    let epoch = reference_snapshot(&mut store).unwrap();
+    DR_EXECUTOR_SNAPSHOT.save(&mut store, &dr_id, &epoch).unwrap();
+
+    (store, dr_id)
+}
+
+/// The storage gas of checking whether the executor is eligible at `height`.
+fn eligibility_gas(store: &MockStorage, dr_id: Hash, executor: PublicKey, height: u64) -> u64 {
+    let storage = GasMeteredStorage {
+        inner: store,
+        gas:   Cell::new(0),
+    };
+    let api = MockApi::default();
+    let querier = MockQuerier::default();
+    let deps = Deps {
+        storage: &storage,
+        api:     &api,
+        querier: QuerierWrapper::new(&querier),
+    };
+    let mut env = cosmwasm_std::testing::mock_env();
+    env.block.height = height;
+
+    is_eligible_for_dr(deps, env, dr_id, executor).unwrap();
+    storage.gas.get()
+}
+
+/// Executor counts the gas is measured for.
+const EXECUTOR_COUNTS: [u32; 3] = [10, 100, 1000];
+
+#[test]
+fn eligibility_lookup_gas_does_not_grow_with_executors() {
+    let mut unknown = Vec::new();
+    let mut all_needed = Vec::new();
+    for executors in EXECUTOR_COUNTS {
+        let (store, dr_id) = setup(executors);
+
+        // an executor that is not staked is rejected without ranking
+        unknown.push(eligibility_gas(&store, dr_id, public_key(executors), 1));
+        // once the backup executors cover the whole snapshot no ranking is needed
+        all_needed.push(eligibility_gas(
+            &store,
+            dr_id,
+            public_key(0),
+            1 + 256 * executors as u64,
+        ));
+    }
+
+    // lookups cost the same no matter how many executors there are, up to the
+    // few bytes the executor count grows by
+    for gas in [&unknown, &all_needed] {
+        assert!(gas[2] - gas[0] < 100, "{gas:?}");
+    }
+}
+
+#[test]
+fn eligibility_ranking_gas_grows_linearly_with_executors() {
+    let ranked = EXECUTOR_COUNTS.map(|executors| {
+        let (store, dr_id) = setup(executors);
+        eligibility_gas(&store, dr_id, public_key(0), 1)
+    });
+
+    // ranking reads every stake in the snapshot once, without loading every
+    // staker
+    let per_executor = [(ranked[1] - ranked[0]) / 90, (ranked[2] - ranked[1]) / 900];
+    for gas in per_executor {
+        assert!(gas < 500, "{gas} gas per executor");
+    }
+    assert!(per_executor[0].abs_diff(per_executor[1]) < 50, "{per_executor:?}");
+}
@@ -0,0 +1,261 @@
+use cosmwasm_schema::cw_serde;
+use cw_storage_plus::{Bound, PrefixBound};
+use seda_common::msgs::staking::SelectionMode;
+
+use super::*;
+
+/// The active executors at the time a data request was posted, shared by every
+/// data request posted while the executor set did not change.
+#[cw_serde]
+pub struct ExecutorSnapshot {
+    pub selection_mode: SelectionMode,
+    /// Number of executors in the snapshot.
+    pub executor_count: u32,
+    /// Number of data requests selecting their executors from the snapshot.
+    pub references:     u32,
+}
+
+/// The epoch of the latest snapshot, increased every time a snapshot is taken.
+pub const SNAPSHOT_EPOCH: Item<u64> = Item::new("executor_snapshot_epoch");
+
+/// Whether the executor set changed since the latest snapshot was taken.
+pub const EXECUTOR_SET_CHANGED: Item<bool> = Item::new("executor_set_changed");
+
+/// Maps an epoch to its snapshot.
+pub const SNAPSHOTS: Map<u64, ExecutorSnapshot> = Map::new("executor_snapshots");
+
+/// The stake an executor had in every snapshot from `from_epoch` up to the
+/// epoch the stake is stored under.
+#[cw_serde]
+pub struct SnapshotStake {
+    pub from_epoch: u64,
+    pub stake:      Uint128,
+}
+
+/// The epoch stakes of currently active executors are stored under, as they
+/// apply to every snapshot taken from now on.
+pub const CURRENT_STAKE_EPOCH: u64 = u64::MAX;
+
+/// Maps the epoch a stake stopped applying at and a public key to the stake.
+///
+/// Only changes are stored, so taking a snapshot does not copy the active
+/// executors. Keying by the epoch first lets a snapshot read just the stakes
+/// that still applied to it, and stakes no live snapshot can see anymore are
+/// dropped once the snapshots that saw them are pruned.
+pub const SNAPSHOT_STAKES: Map<(u64, &[u8]), SnapshotStake> = Map::new("executor_snapshot_stakes");
+
+/// Maps a public key and the epoch a stake stopped applying at to nothing, so
+/// the stake of one executor in a snapshot can be looked up directly.
+pub const SNAPSHOT_STAKE_EPOCHS: Map<(&[u8], u64), ()> = Map::new("executor_snapshot_stake_epochs");
+
+fn save_snapshot_stake(store: &mut dyn Storage, until: u64, public_key: &[u8], stake: &SnapshotStake) -> StdResult<()> {
+    SNAPSHOT_STAKES.save(store, (until, public_key), stake)?;
+    SNAPSHOT_STAKE_EPOCHS.save(store, (public_key, until), &())
+}
+
+fn remove_snapshot_stake(store: &mut dyn Storage, until: u64, public_key: &[u8]) {
+    SNAPSHOT_STAKES.remove(store, (until, public_key));
+    SNAPSHOT_STAKE_EPOCHS.remove(store, (public_key, until));
+}
+
+/// Marks the executor set as changed, so the next data request takes a new
+/// snapshot.
+pub fn mark_executor_set_changed(store: &mut dyn Storage) -> StdResult<()> {
+    EXECUTOR_SET_CHANGED.save(store, &true)
+}
+
+/// Records a change to the stake of an active executor, which applies from the
+/// next snapshot on.
+pub fn record_stake_change(store: &mut dyn Storage, public_key: &[u8], stake: Option<Uint128>) -> StdResult<()> {
+    let next_epoch = SNAPSHOT_EPOCH.may_load(store)?.map_or(0, |epoch| epoch + 1);
+
+    // the previous stake is only kept if a live snapshot was taken while it applied
+    if let Some(previous) = SNAPSHOT_STAKES.may_load(store, (CURRENT_STAKE_EPOCH, public_key))? {
+        remove_snapshot_stake(store, CURRENT_STAKE_EPOCH, public_key);
+        let seen = SNAPSHOTS
+            .keys(
+                store,
+                Some(Bound::inclusive(previous.from_epoch)),
+                Some(Bound::exclusive(next_epoch)),
+                Order::Ascending,
+            )
+            .next()
+            .transpose()?
+            .is_some();
+        if seen {
+            save_snapshot_stake(store, next_epoch, public_key, &previous)?;
+        }
+    }
+
+    if let Some(stake) = stake {
+        let current = SnapshotStake {
+            from_epoch: next_epoch,
+            stake,
+        };
+        save_snapshot_stake(store, CURRENT_STAKE_EPOCH, public_key, &current)?;
+    }
+
+    mark_executor_set_changed(store)
+}
+
+/// Returns the epoch of the snapshot a new data request selects its executors
+/// from, taking a new snapshot if the executor set changed since the latest
+/// one.
+pub fn reference_snapshot(store: &mut dyn Storage) -> StdResult<u64> {
+    let latest = SNAPSHOT_EPOCH.may_load(store)?;
+    let changed = EXECUTOR_SET_CHANGED.may_load(store)?.unwrap_or(true);
+    let epoch = match latest {
+        Some(epoch) if !changed => epoch,
+        _ => take_snapshot(store, latest)?,
+    };
+
+    let mut snapshot = SNAPSHOTS.load(store, epoch)?;
+    snapshot.references += 1;
+    SNAPSHOTS.save(store, epoch, &snapshot)?;
+
+    Ok(epoch)
+}
+
+/// Drops a data request's reference to a snapshot, pruning the snapshot once
+/// it is no longer referenced or the latest one.
+pub fn release_snapshot(store: &mut dyn Storage, epoch: u64) -> StdResult<()> {
+    let Some(mut snapshot) = SNAPSHOTS.may_load(store, epoch)? else {
+        return Ok(());
+    };
+    snapshot.references = snapshot.references.saturating_sub(1);
+    SNAPSHOTS.save(store, epoch, &snapshot)?;
+
+    // the latest snapshot is kept for the next data request
+    if SNAPSHOT_EPOCH.may_load(store)? != Some(epoch) {
+        prune_if_unreferenced(store, epoch)?;
+    }
+
+    Ok(())
+}
+
+/// The executors a data request selects from.
+pub enum ExecutorSet {
+    /// The executors that were active when the data request was posted.
+    Snapshot { epoch: u64, snapshot: ExecutorSnapshot },
+    /// The currently active executors, for data requests posted before
+    /// snapshots existed.
+    Live {
+        selection_mode: SelectionMode,
+        count:          u32,
+    },
+}
+
+impl ExecutorSet {
+    pub fn load(store: &dyn Storage, snapshot_epoch: Option<u64>) -> StdResult<Self> {
+        Ok(match snapshot_epoch {
+            Some(epoch) => Self::Snapshot {
+                epoch,
+                snapshot: SNAPSHOTS.load(store, epoch)?,
+            },
+            None => Self::Live {
+                selection_mode: STAKING_CONFIG.load(store)?.selection_mode,
+                count:          STAKERS.active_count(store)?,
+            },
+        })
+    }
+
+    pub fn selection_mode(&self) -> &SelectionMode {
+        match self {
+            Self::Snapshot { snapshot, .. } => &snapshot.selection_mode,
+            Self::Live { selection_mode, .. } => selection_mode,
+        }
+    }
+
+    pub fn executor_count(&self) -> u32 {
+        match self {
+            Self::Snapshot { snapshot, .. } => snapshot.executor_count,
+            Self::Live { count, .. } => *count,
+        }
+    }
+
+    /// The stake of an executor, if they are part of the set.
+    pub fn stake(&self, store: &dyn Storage, public_key: &[u8]) -> StdResult<Option<Uint128>> {
+        match self {
+            Self::Snapshot { epoch, .. } => {
+                // the stakes of an executor never overlap, so the first one that still
+                // applied after the snapshot is the only one that can contain it
+                let Some(until) = SNAPSHOT_STAKE_EPOCHS
+                    .prefix(public_key)
+                    .keys(store, Some(Bound::exclusive(*epoch)), None, Order::Ascending)
+                    .next()
+                    .transpose()?
+                else {
+                    return Ok(None);
+                };
+                let stake = SNAPSHOT_STAKES.load(store, (until, public_key))?;
+                Ok((stake.from_epoch <= *epoch).then_some(stake.stake))
+            }
+            Self::Live { .. } => STAKERS.active.may_load(store, public_key),
+        }
+    }
+
+    /// Every executor in the set with their stake.
+    pub fn stakes(&self, store: &dyn Storage) -> StdResult<Vec<(Vec<u8>, Uint128)>> {
+        match self {
+            Self::Snapshot { epoch, .. } => SNAPSHOT_STAKES
+                .prefix_range(store, Some(PrefixBound::exclusive(*epoch)), None, Order::Ascending)
+                .filter_map(|stake| match stake {
+                    Ok(((_, public_key), stake)) => {
+                        (stake.from_epoch <= *epoch).then_some(Ok((public_key, stake.stake)))
+                    }
+                    Err(err) => Some(Err(err)),
+                })
+                .collect(),
+            Self::Live { .. } => STAKERS.active.range_raw(store, None, None, Order::Ascending).collect(),
+        }
+    }
+}
+
+fn take_snapshot(store: &mut dyn Storage, latest: Option<u64>) -> StdResult<u64> {
+    let epoch = latest.map_or(0, |epoch| epoch + 1);
+    // the latest snapshot is replaced, so it can go if nothing references it
+    if let Some(latest) = latest {
+        prune_if_unreferenced(store, latest)?;
+    }
+
+    // the stake changes since the latest snapshot were recorded for this epoch
+    let snapshot = ExecutorSnapshot {
+        selection_mode: STAKING_CONFIG.load(store)?.selection_mode,
+        executor_count: STAKERS.active_count(store)?,
+        references:     0,
+    };
+    SNAPSHOTS.save(store, epoch, &snapshot)?;
+    SNAPSHOT_EPOCH.save(store, &epoch)?;
+    EXECUTOR_SET_CHANGED.save(store, &false)?;
+
+    Ok(epoch)
+}
+
+fn prune_if_unreferenced(store: &mut dyn Storage, epoch: u64) -> StdResult<()> {
+    if SNAPSHOTS
+        .may_load(store, epoch)?
+        .is_some_and(|snapshot| snapshot.references == 0)
+    {
+        SNAPSHOTS.remove(store, epoch);
+        prune_unseen_stakes(store)?;
+    }
+    Ok(())
+}
+
+/// Removes the stakes that stopped applying before the oldest live snapshot,
+/// which no snapshot can see anymore.
+fn prune_unseen_stakes(store: &mut dyn Storage) -> StdResult<()> {
+    let oldest_epoch = SNAPSHOTS.keys(store, None, None, Order::Ascending).next().transpose()?;
+    let until = match oldest_epoch {
+        Some(epoch) => PrefixBound::inclusive(epoch),
+        None => PrefixBound::exclusive(CURRENT_STAKE_EPOCH),
+    };
+    let unseen = SNAPSHOT_STAKES
+        .prefix_range(store, None, Some(until), Order::Ascending)
+        .map(|stake| stake.map(|(key, _)| key))
+        .collect::<StdResult<Vec<_>>>()?;
+    for (until, public_key) in unseen {
+        remove_snapshot_stake(store, until, &public_key);
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use cosmwasm_schema::cw_serde;
+use seda_common::msgs::staking::ExecutorStatsSortBy;
+
+use super::*;
+
+/// Counters of an executor's participation in data requests.
+#[cw_serde]
+#[derive(Default)]
+pub struct ExecutorStatsInfo {
+    pub commits:            u64,
+    pub reveals:            u64,
+    pub missed_reveals:     u64,
+    pub timeouts:           u64,
+    pub rewards_received:   Uint128,
+    pub last_active_height: u64,
+}
+
+/// Maps an executor public key to their stats.
+pub const EXECUTOR_STATS: Map<&PublicKey, ExecutorStatsInfo> = Map::new("executor_stats");
+
+/// Orders the executors by each of their stats, so they can be paginated
+/// without loading and sorting all of them.
+///
+/// Keyed by the stat, its value subtracted from the maximum so the highest
+/// comes first, and the public key so ties are ordered by it.
+pub const EXECUTOR_STATS_RANKING: Map<(u8, u128, &[u8]), ()> = Map::new("executor_stats_ranking");
+
+impl ExecutorStatsInfo {
+    fn ranking_keys(&self) -> [(u8, u128); 6] {
+        [
+            (ExecutorStatsSortBy::Commits, self.commits as u128),
+            (ExecutorStatsSortBy::Reveals, self.reveals as u128),
+            (ExecutorStatsSortBy::MissedReveals, self.missed_reveals as u128),
+            (ExecutorStatsSortBy::Timeouts, self.timeouts as u128),
+            (ExecutorStatsSortBy::RewardsReceived, self.rewards_received.u128()),
+            (ExecutorStatsSortBy::LastActiveHeight, self.last_active_height as u128),
+        ]
+        .map(|(sort_by, value)| (sort_by as u8, u128::MAX - value))
+    }
+}
+
+/// The public keys of executors ordered by `sort_by`, highest first.
+pub fn ranked_executors<'a>(
+    store: &'a dyn Storage,
+    sort_by: ExecutorStatsSortBy,
+) -> impl Iterator<Item = StdResult<Vec<u8>>> + 'a {
+    EXECUTOR_STATS_RANKING
+        .sub_prefix(sort_by as u8)
+        .keys(store, None, None, Order::Ascending)
+        .map(|key| key.map(|(_, public_key)| public_key))
+}
+
+/// Applies `update` to the stats of an executor, creating them if needed.
+pub fn update_executor_stats(
+    store: &mut dyn Storage,
+    public_key: &PublicKey,
+    update: impl FnOnce(&mut ExecutorStatsInfo),
+) -> StdResult<()> {
+    let previous = EXECUTOR_STATS.may_load(store, public_key)?;
+    let mut stats = previous.clone().unwrap_or_default();
+    update(&mut stats);
+    EXECUTOR_STATS.save(store, public_key, &stats)?;
+
+    // only the stats that changed move in the ranking
+    let previous_keys = previous.map(|previous| previous.ranking_keys());
+    for (i, (sort_by, value)) in stats.ranking_keys().into_iter().enumerate() {
+        match previous_keys.map(|keys| keys[i]) {
+            Some(previous) if previous == (sort_by, value) => continue,
+            Some((_, previous)) => EXECUTOR_STATS_RANKING.remove(store, (sort_by, previous, public_key.as_ref())),
+            None => {}
+        }
+        EXECUTOR_STATS_RANKING.save(store, (sort_by, value, public_key.as_ref()), &())?;
+    }
+
+    Ok(())
+}
+
+/// Moves the stats of an executor to their new public key.
+pub fn rotate_executor_stats(
+    store: &mut dyn Storage,
+    public_key: &PublicKey,
+    new_public_key: &PublicKey,
+) -> StdResult<()> {
+    if let Some(stats) = EXECUTOR_STATS.may_load(store, public_key)? {
+        EXECUTOR_STATS.remove(store, public_key);
+        EXECUTOR_STATS.save(store, new_public_key, &stats)?;
+        for (sort_by, value) in stats.ranking_keys() {
+            EXECUTOR_STATS_RANKING.remove(store, (sort_by, value, public_key.as_ref()));
+            EXECUTOR_STATS_RANKING.save(store, (sort_by, value, new_public_key.as_ref()), &())?;
+        }
+    }
+    Ok(())
+}
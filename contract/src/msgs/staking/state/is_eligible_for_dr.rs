@@ -1,10 +1,17 @@
-use std::num::NonZero;
+use std::{cmp::Ordering, collections::HashSet, num::NonZero};
 
-use data_requests::state::load_request;
+use data_requests::state::{load_request, may_load_request};
+use seda_common::msgs::{
+    data_requests::{DataRequestBase, DataRequestExecutor, DataRequestExecutorRole, GetDataRequestExecutorsResponse},
+    staking::SelectionMode,
+};
 use sha3::{Digest, Keccak256};
 
-use super::{staking::state::STAKERS, *};
-use crate::msgs::data_requests::state::DR_CONFIG;
+use super::{
+    staking::state::{executor_snapshots::ExecutorSet, STAKERS},
+    *,
+};
+use crate::msgs::data_requests::state::{DR_CONFIG, DR_EXECUTOR_SNAPSHOT};
 
 /// Compute deterministic hash for staker selection by combining public key and
 /// dr_id
@@ -15,8 +22,96 @@ fn compute_selection_hash(public_key: &[u8], dr_id: &[u8]) -> Hash {
     hasher.finalize().into()
 }
 
+/// Number of fractional bits in the fixed point `-log2` of a selection hash
+const SELECTION_FRAC_BITS: u32 = 32;
+
+/// Computes `-log2(u)` in fixed point, where `u` is the selection hash read as
+/// a fraction in `(0, 1]`.
+///
+/// Integer only, so every node computes the same ordering.
+fn selection_neg_log2(hash: &Hash) -> u64 {
+    // a zero hash would be -log2(0), clamp it to the largest possible value
+    let Some(first) = hash.iter().position(|byte| *byte != 0) else {
+        return 257 << SELECTION_FRAC_BITS;
+    };
+    let leading_zeros = first as u32 * 8 + hash[first].leading_zeros();
+
+    // the 64 bits after the leading zeros, read as a Q63 number in [1, 2)
+    let mut window = [0u8; 16];
+    let bytes = &hash[first..(first + 16).min(hash.len())];
+    window[..bytes.len()].copy_from_slice(bytes);
+    let mut x = (u128::from_be_bytes(window) << hash[first].leading_zeros()) >> 64;
+
+    // compute the fractional bits of log2(x) by repeated squaring
+    let mut frac = 0u64;
+    for _ in 0..SELECTION_FRAC_BITS {
+        x = (x * x) >> 63;
+        frac <<= 1;
+        if x >= 1 << 64 {
+            x >>= 1;
+            frac |= 1;
+        }
+    }
+
+    // u = x * 2^-(leading_zeros + 1), so -log2(u) = leading_zeros + 1 - log2(x)
+    ((leading_zeros as u64 + 1) << SELECTION_FRAC_BITS) - frac
+}
+
+/// Key ordering stakers for stake weighted selection, lower keys are selected
+/// first.
+///
+/// The key is `-log2(u) / stake`, which selects stakers with a probability
+/// proportional to their stake (Efraimidis-Spirakis sampling). Keys are
+/// compared by cross multiplying, so no division is needed.
+#[derive(PartialEq, Eq)]
+struct WeightedSelectionKey {
+    neg_log2: u64,
+    stake:    Uint128,
+    hash:     Hash,
+}
+
+impl WeightedSelectionKey {
+    fn new(public_key: &[u8], dr_id: &[u8], stake: Uint128) -> Self {
+        let hash = compute_selection_hash(public_key, dr_id);
+        Self {
+            neg_log2: selection_neg_log2(&hash),
+            stake,
+            hash,
+        }
+    }
+}
+
+impl Ord for WeightedSelectionKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Uint128::from(self.neg_log2)
+            .full_mul(other.stake)
+            .cmp(&Uint128::from(other.neg_log2).full_mul(self.stake))
+            // the hash breaks ties so the ordering is total
+            .then_with(|| self.hash.cmp(&other.hash))
+    }
+}
+
+impl PartialOrd for WeightedSelectionKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// Check if a staker is eligible to execute a data request
 ///
+/// Executors are selected from the snapshot of active executors taken when the
+/// data request was posted, so stake changes afterwards do not change who is
+/// selected. An executor that rotated their key since is selected under the key
+/// they had in the snapshot. Executors also have to be active right now, so one
+/// that got jailed, was removed from the allowlist or dropped below the minimum
+/// stake after the data request was posted can no longer commit to it.
+///
+/// Executors outside the snapshot are rejected with a single lookup, and once
+/// the backup executors cover the whole snapshot no ranking is needed.
+/// Otherwise the executor is ranked against every executor in the snapshot, or
+/// only the listed ones for a restricted data request, which is linear in their
+/// number.
+///
 /// # Arguments
 /// * `deps` - Dependencies for storage access
 /// * `env` - Environment info, used for block height
@@ -29,37 +124,205 @@ fn compute_selection_hash(public_key: &[u8], dr_id: &[u8]) -> Hash {
 /// * `Err` if there's an error accessing storage
 pub fn is_eligible_for_dr(deps: Deps, env: Env, dr_id: [u8; 32], public_key: PublicKey) -> Result<bool, ContractError> {
     let data_request = load_request(deps.storage, &dr_id)?;
-    let config = STAKING_CONFIG.load(deps.storage)?;
 
+    // an executor that left or got jailed since the data request was posted can
+    // no longer commit
     if !STAKERS.is_staker_executor(deps.storage, &public_key)? {
         return Ok(false);
     }
 
-    let stakers = STAKERS
-        .stakers
-        .range_raw(deps.storage, None, None, Order::Ascending)
-        .flatten();
+    let executors = ExecutorSet::load(deps.storage, DR_EXECUTOR_SNAPSHOT.may_load(deps.storage, &dr_id)?)?;
+    let Some(public_key) = key_in_set(deps.storage, &executors, &data_request.base, public_key)? else {
+        return Ok(false);
+    };
+
+    // a restricted data request only selects from the executors it lists
+    let allowed = allowed_executors(&data_request.base)?;
+    if allowed
+        .as_ref()
+        .is_some_and(|allowed| !allowed.contains(public_key.as_ref()))
+    {
+        return Ok(false);
+    }
+
     let blocks_passed = env.block.height - data_request.base.height;
     let dr_config = DR_CONFIG.load(deps.storage)?;
+    let replication_factor = data_request.base.replication_factor;
+
+    let eligible = match allowed {
+        // only the listed executors have to be ranked
+        Some(allowed) => {
+            let stakers = listed_stakes(deps.storage, &executors, allowed)?;
+            calculate_dr_eligibility(
+                stakers.into_iter(),
+                public_key.as_ref(),
+                executors.selection_mode(),
+                dr_config.backup_delay_in_blocks,
+                dr_id,
+                replication_factor,
+                blocks_passed,
+            )
+        }
+        // once every executor is needed there is nothing left to rank
+        None if total_needed(dr_config.backup_delay_in_blocks, replication_factor, blocks_passed)
+            >= executors.executor_count() as u64 =>
+        {
+            true
+        }
+        None => calculate_dr_eligibility(
+            executors.stakes(deps.storage)?.into_iter(),
+            public_key.as_ref(),
+            executors.selection_mode(),
+            dr_config.backup_delay_in_blocks,
+            dr_id,
+            replication_factor,
+            blocks_passed,
+        ),
+    };
+
+    Ok(eligible)
+}
+
+/// The key an executor has in the executor set of a data request, following
+/// their key rotations back to it.
+///
+/// Returns `None` if the executor is not part of the set, or already committed
+/// to the data request under one of their previous keys.
+fn key_in_set(
+    store: &dyn Storage,
+    executors: &ExecutorSet,
+    data_request: &DataRequestBase,
+    public_key: PublicKey,
+) -> StdResult<Option<PublicKey>> {
+    if executors.stake(store, public_key.as_ref())?.is_some() {
+        return Ok(Some(public_key));
+    }
 
-    Ok(calculate_dr_eligibility(
-        stakers,
-        public_key.as_ref(),
-        config.minimum_stake,
-        dr_config.backup_delay_in_blocks,
-        dr_id,
-        data_request.base.replication_factor,
-        blocks_passed,
-    ))
+    for previous_key in STAKERS.previous_keys(store, &public_key)? {
+        if data_request.has_committer(&previous_key.to_hex()) {
+            return Ok(None);
+        }
+        if executors.stake(store, previous_key.as_ref())?.is_some() {
+            return Ok(Some(previous_key));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Lists the executors of a data request in the order they are selected, with
+/// the height from which each of them can commit.
+///
+/// Returns `None` if the data request does not exist.
+pub fn data_request_executors(
+    deps: Deps,
+    env: &Env,
+    dr_id: [u8; 32],
+) -> Result<Option<GetDataRequestExecutorsResponse>, ContractError> {
+    let Some(data_request) = may_load_request(deps.storage, &dr_id)? else {
+        return Ok(None);
+    };
+
+    let executors = ExecutorSet::load(deps.storage, DR_EXECUTOR_SNAPSHOT.may_load(deps.storage, &dr_id)?)?;
+    let mut stakers = match allowed_executors(&data_request.base)? {
+        Some(allowed) => listed_stakes(deps.storage, &executors, allowed)?,
+        None => executors.stakes(deps.storage)?,
+    };
+    match executors.selection_mode() {
+        SelectionMode::Uniform => {
+            stakers.sort_by_cached_key(|(public_key, _)| compute_selection_hash(public_key, &dr_id))
+        }
+        SelectionMode::StakeWeighted => {
+            stakers.sort_by_cached_key(|(public_key, stake)| WeightedSelectionKey::new(public_key, &dr_id, *stake))
+        }
+    }
+
+    let replication_factor = data_request.base.replication_factor as u64;
+    let backup_delay_in_blocks = DR_CONFIG.load(deps.storage)?.backup_delay_in_blocks.get() as u64;
+    let executors = stakers
+        .into_iter()
+        .enumerate()
+        .map(|(position, (public_key, _))| {
+            // executors that rotated their key since are listed under their current key
+            let public_key = STAKERS.resolve_key(deps.storage, PublicKey::try_from(public_key.as_slice())?)?;
+            let position = position as u64;
+            // mirrors `total_needed`, the first backup slot opens once more than the
+            // backup delay passed and every next one a backup delay later
+            let (role, eligible_from) = if position < replication_factor {
+                (DataRequestExecutorRole::Primary, data_request.base.height)
+            } else {
+                let backup = position - replication_factor + 1;
+                (
+                    DataRequestExecutorRole::Backup,
+                    data_request.base.height + backup * backup_delay_in_blocks + 1,
+                )
+            };
+            let eligible =
+                env.block.height >= eligible_from && STAKERS.is_staker_executor(deps.storage, &public_key)?;
+
+            Ok(DataRequestExecutor {
+                public_key: public_key.to_hex(),
+                role,
+                eligible_from,
+                eligible,
+            })
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(Some(GetDataRequestExecutorsResponse { executors }))
+}
+
+/// The public keys a restricted data request selects its executors from.
+fn allowed_executors(data_request: &DataRequestBase) -> Result<Option<HashSet<Vec<u8>>>, ContractError> {
+    Ok(data_request
+        .executors
+        .as_ref()
+        .map(|executors| executors.iter().map(hex::decode).collect::<Result<HashSet<_>, _>>())
+        .transpose()?)
+}
+
+/// The stakes of the listed executors that are part of the set.
+fn listed_stakes(
+    store: &dyn Storage,
+    executors: &ExecutorSet,
+    listed: HashSet<Vec<u8>>,
+) -> StdResult<Vec<(Vec<u8>, Uint128)>> {
+    listed
+        .into_iter()
+        .filter_map(|key| {
+            executors
+                .stake(store, &key)
+                .transpose()
+                .map(|stake| stake.map(|stake| (key, stake)))
+        })
+        .collect()
+}
+
+/// The number of executors a data request needs after `blocks_passed` blocks,
+/// before it is capped by the number of executors available.
+///
+/// For the first backup executor to be eligible the number of blocks passed
+/// needs to be greater than the backup delay. For subsequent executors the
+/// number of blocks passed needs to be the same as the backup delay.
+fn total_needed(backup_delay_in_blocks: NonZero<u8>, replication_factor: u16, blocks_passed: u64) -> u64 {
+    if blocks_passed > backup_delay_in_blocks.get() as u64 {
+        // The blocks_passed - 1 is to account for the fact that from the outside world
+        // a DR only becomes visible at the posted_height + 1.
+        replication_factor as u64 + ((blocks_passed - 1) / backup_delay_in_blocks.get() as u64)
+    } else {
+        replication_factor as u64
+    }
 }
 
 /// Calculate if a staker is eligible based on their position in a deterministic
 /// ordering
 ///
 /// # Arguments
-/// * `active_stakers` - Iterator of (public_key, staker) pairs
+/// * `active_stakers` - Iterator of (public_key, stake) pairs of the active
+///   executors
 /// * `target_public_key` - Public key to check eligibility for
-/// * `minimum_stake` - Minimum stake required to be considered active
+/// * `selection_mode` - Whether stakers are ordered by hash alone or weighted
+///   by their stake
 /// * `backup_delay_in_blocks` - How many blocks to wait before a new staker is
 ///   eligible
 /// * `dr_id` - Data request ID used for deterministic selection
@@ -67,56 +330,75 @@ pub fn is_eligible_for_dr(deps: Deps, env: Env, dr_id: [u8; 32], public_key: Pub
 /// * `blocks_passed` - Number of blocks since DR was posted
 ///
 /// # Algorithm
-/// 1. Filter stakers that meet minimum stake requirement
-/// 2. Compute hash for target staker using public_key + dr_id
-/// 3. Count total eligible stakers and how many have lower hash than target
-/// 4. Calculate total needed stakers (replication_factor + blocks_passed),
+/// 1. Compute hash for target staker using public_key + dr_id, weighted by
+///    their stake in stake weighted mode
+/// 2. Count total eligible stakers and how many rank lower than target
+/// 3. Calculate total needed stakers (replication_factor + blocks_passed),
 ///    capped by total available
-/// 5. Target is eligible if fewer stakers have lower hash than total needed
+/// 4. Target is eligible if fewer stakers rank lower than total needed
 ///
 /// # Returns
 /// `true` if the staker is eligible, `false` otherwise
 fn calculate_dr_eligibility<I>(
     active_stakers: I,
     target_public_key: &[u8],
-    minimum_stake: Uint128,
+    selection_mode: &SelectionMode,
     backup_delay_in_blocks: NonZero<u8>,
     dr_id: [u8; 32],
     replication_factor: u16,
     blocks_passed: u64,
 ) -> bool
 where
-    I: Iterator<Item = (Vec<u8>, Staker)>,
+    I: Iterator<Item = (Vec<u8>, Uint128)>,
 {
-    let target_hash = compute_selection_hash(target_public_key, &dr_id);
-
-    // Count total eligible stakers and stakers with lower hash in one pass
-    let (total_stakers, lower_hash_count) = active_stakers
-        .filter(|(_, staker)| staker.tokens_staked >= minimum_stake)
-        .fold((0, 0), |(total, lower), (public_key, _)| {
-            let staker_hash = compute_selection_hash(&public_key, &dr_id);
-            (total + 1, lower + if staker_hash < target_hash { 1 } else { 0 })
-        });
+    // Count total eligible stakers and stakers that rank lower in one pass
+    let (total_stakers, lower_rank_count) = match selection_mode {
+        SelectionMode::Uniform => {
+            let target_hash = compute_selection_hash(target_public_key, &dr_id);
+            let (total, lower, found) = active_stakers.fold((0, 0, false), |(total, lower, found), (public_key, _)| {
+                let staker_hash = compute_selection_hash(&public_key, &dr_id);
+                (
+                    total + 1,
+                    lower + if staker_hash < target_hash { 1 } else { 0 },
+                    found || public_key == target_public_key,
+                )
+            });
+            // a staker that was not active when the data request was posted is never
+            // selected
+            if !found {
+                return false;
+            }
+            (total, lower)
+        }
+        SelectionMode::StakeWeighted => {
+            // the target's key depends on their stake, so it is only known once they
+            // are found
+            let keys = active_stakers
+                .map(|(public_key, stake)| {
+                    let key = WeightedSelectionKey::new(&public_key, &dr_id, stake);
+                    (public_key, key)
+                })
+                .collect::<Vec<_>>();
+            let Some((_, target_key)) = keys
+                .iter()
+                .find(|(public_key, _)| public_key.as_slice() == target_public_key)
+            else {
+                return false;
+            };
+            (keys.len(), keys.iter().filter(|(_, key)| key < target_key).count())
+        }
+    };
 
     if total_stakers == 0 {
         return false;
     }
 
     // Calculate total needed stakers, capped by total available
-    // For the first backup executor to be eligible the number of blocks passed
-    // needs to be greater than the backup delay. For subsequent executors the
-    // number of blocks passed needs to be the same as the backup delay.
-    let total_needed = if blocks_passed > backup_delay_in_blocks.get() as u64 {
-        // The blocks_passed - 1 is to account for the fact that from the outside world
-        // a DR only becomes visible at the posted_height + 1.
-        replication_factor as u64 + ((blocks_passed - 1) / backup_delay_in_blocks.get() as u64)
-    } else {
-        replication_factor as u64
-    };
-    let total_needed = total_needed.min(total_stakers as u64);
+    let total_needed =
+        total_needed(backup_delay_in_blocks, replication_factor, blocks_passed).min(total_stakers as u64);
 
     // Staker is eligible if their position (by hash order) is within needed range
-    lower_hash_count < total_needed as usize
+    lower_rank_count < total_needed as usize
 }
 
 #[cfg(test)]
@@ -124,25 +406,13 @@ mod tests {
 
     use super::*;
 
-    fn create_test_stakers(count: usize) -> Vec<(Vec<u8>, Staker)> {
-        (1..=count)
-            .map(|i| {
-                (
-                    vec![i as u8],
-                    Staker {
-                        tokens_staked:             Uint128::from(100u128),
-                        memo:                      None,
-                        tokens_pending_withdrawal: Uint128::from(0u128),
-                    },
-                )
-            })
-            .collect()
+    fn create_test_stakers(count: usize) -> Vec<(Vec<u8>, Uint128)> {
+        (1..=count).map(|i| (vec![i as u8], Uint128::from(100u128))).collect()
     }
 
     #[test]
     fn test_node_selection_consistency() {
         let dr_id = [1; 32];
-        let minimum_stake = Uint128::from(100u128);
         let backup_delay_in_blocks = NonZero::new(2).unwrap();
         let replication_factor = 3;
         let blocks_passed = 2;
@@ -173,7 +443,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -210,7 +480,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     different_dr_id,
                     replication_factor,
@@ -228,7 +498,6 @@ mod tests {
 
     #[test]
     fn test_backup_replication_factor() {
-        let minimum_stake = Uint128::from(100u128);
         let backup_delay_in_blocks = NonZero::new(2).unwrap();
         let dr_id = [1; 32];
         let replication_factor = 2;
@@ -241,7 +510,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -259,7 +528,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -277,7 +546,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -294,7 +563,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -307,7 +576,6 @@ mod tests {
 
     #[test]
     fn test_basic_backup_delay() {
-        let minimum_stake = Uint128::from(100u128);
         let backup_delay_in_blocks = NonZero::new(1).unwrap();
         let dr_id = [1; 32];
         let replication_factor = 1;
@@ -326,7 +594,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -344,7 +612,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -362,7 +630,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -375,7 +643,6 @@ mod tests {
 
     #[test]
     fn test_larger_backup_delay() {
-        let minimum_stake = Uint128::from(100u128);
         let backup_delay_in_blocks = NonZero::new(5).unwrap();
         let dr_id = [1; 32];
         let replication_factor = 2;
@@ -391,7 +658,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -407,7 +674,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -425,7 +692,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -443,7 +710,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -461,7 +728,7 @@ mod tests {
                 calculate_dr_eligibility(
                     stakers.clone().into_iter(),
                     public_key,
-                    minimum_stake,
+                    &SelectionMode::Uniform,
                     backup_delay_in_blocks,
                     dr_id,
                     replication_factor,
@@ -471,4 +738,119 @@ mod tests {
             .count();
         assert_eq!(eligible_count, (replication_factor + 2) as usize);
     }
+
+    #[test]
+    fn test_selection_neg_log2() {
+        let one = 1u64 << SELECTION_FRAC_BITS;
+        let mut hash = [0; 32];
+
+        // u = 1/2 and u = 1/4
+        hash[0] = 0x80;
+        assert_eq!(selection_neg_log2(&hash), one);
+        hash[0] = 0x40;
+        assert_eq!(selection_neg_log2(&hash), 2 * one);
+
+        // u = 3/4, -log2(0.75) = 0.41503749...
+        hash[0] = 0xC0;
+        let expected = (0.415_037_499_278_843_8 * one as f64) as u64;
+        assert!(selection_neg_log2(&hash).abs_diff(expected) <= 1);
+
+        // u = 2^-256 is the smallest non zero fraction
+        let mut hash = [0; 32];
+        hash[31] = 1;
+        assert_eq!(selection_neg_log2(&hash), 256 * one);
+    }
+
+    /// Selects a single staker for many data requests and returns how often
+    /// each staker was selected.
+    fn selection_counts(stakes: &[u128], selection_mode: SelectionMode, rounds: u32) -> Vec<u32> {
+        let stakers: Vec<_> = stakes
+            .iter()
+            .enumerate()
+            .map(|(i, stake)| (vec![i as u8], Uint128::from(*stake)))
+            .collect();
+
+        let mut counts = vec![0; stakers.len()];
+        for round in 0..rounds {
+            let dr_id: [u8; 32] = Keccak256::digest(round.to_be_bytes()).into();
+            let selected: Vec<_> = stakers
+                .iter()
+                .enumerate()
+                .filter(|(_, (public_key, _))| {
+                    calculate_dr_eligibility(
+                        stakers.clone().into_iter(),
+                        public_key,
+                        &selection_mode,
+                        NonZero::new(2).unwrap(),
+                        dr_id,
+                        1,
+                        0,
+                    )
+                })
+                .map(|(i, _)| i)
+                .collect();
+            assert_eq!(selected.len(), 1, "exactly one staker should be selected");
+            counts[selected[0]] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_stake_weighted_selection_distribution() {
+        let stakes = [100, 100, 200, 400, 800];
+        let rounds = 2_000;
+        let counts = selection_counts(&stakes, SelectionMode::StakeWeighted, rounds);
+
+        // each staker is selected in proportion to their stake, within 4% of all
+        // rounds
+        let total_stake: u128 = stakes.iter().sum();
+        for (stake, count) in stakes.iter().zip(counts) {
+            let expected = (*stake * rounds as u128 / total_stake) as u32;
+            assert!(
+                count.abs_diff(expected) <= rounds / 25,
+                "stake {stake} was selected {count} times, expected about {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_uniform_selection_ignores_stake() {
+        let stakes = [100, 100, 200, 400, 800];
+        let rounds = 2_000;
+        let counts = selection_counts(&stakes, SelectionMode::Uniform, rounds);
+
+        let expected = rounds / stakes.len() as u32;
+        for (stake, count) in stakes.iter().zip(counts) {
+            assert!(
+                count.abs_diff(expected) <= rounds / 25,
+                "stake {stake} was selected {count} times, expected about {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_stake_weighted_backup_delay() {
+        let backup_delay_in_blocks = NonZero::new(2).unwrap();
+        let dr_id = [1; 32];
+        let stakers = create_test_stakers(5);
+
+        // backup executors still become eligible as blocks pass
+        for (blocks_passed, expected) in [(0, 2), (3, 3), (5, 4), (100, 5)] {
+            let eligible_count = stakers
+                .iter()
+                .filter(|(public_key, _)| {
+                    calculate_dr_eligibility(
+                        stakers.clone().into_iter(),
+                        public_key,
+                        &SelectionMode::StakeWeighted,
+                        backup_delay_in_blocks,
+                        dr_id,
+                        2,
+                        blocks_passed,
+                    )
+                })
+                .count();
+            assert_eq!(eligible_count, expected, "blocks passed: {blocks_passed}");
+        }
+    }
 }
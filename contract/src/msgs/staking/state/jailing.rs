@@ -0,0 +1,45 @@
+use super::*;
+
+/// Counts a missed reveal for an executor, jailing them once they reach the
+/// configured threshold.
+///
+/// Returns the height the executor is jailed until if this jailed them.
+pub fn record_missed_reveal(
+    store: &mut dyn Storage,
+    public_key: &PublicKey,
+    current_height: u64,
+) -> StdResult<Option<u64>> {
+    let config = STAKING_CONFIG.load(store)?;
+    // the executor may have withdrawn everything since committing
+    let Some(mut staker) = STAKERS.may_get_staker(store, public_key)? else {
+        return Ok(None);
+    };
+    if config.missed_reveals_jail_threshold == 0 || staker.jailed_until.is_some() {
+        return Ok(None);
+    }
+
+    staker.missed_reveals += 1;
+    let jailed_until = if staker.missed_reveals >= config.missed_reveals_jail_threshold {
+        staker.missed_reveals = 0;
+        staker.jailed_until = Some(current_height + config.jail_duration_in_blocks);
+        staker.jailed_until
+    } else {
+        None
+    };
+    STAKERS.update(store, public_key.clone(), &staker)?;
+
+    Ok(jailed_until)
+}
+
+/// Clears the missed reveals of an executor after they revealed.
+pub fn reset_missed_reveals(store: &mut dyn Storage, public_key: &PublicKey) -> StdResult<()> {
+    let Some(mut staker) = STAKERS.may_get_staker(store, public_key)? else {
+        return Ok(());
+    };
+    if staker.missed_reveals == 0 {
+        return Ok(());
+    }
+
+    staker.missed_reveals = 0;
+    STAKERS.update(store, public_key.clone(), &staker)
+}
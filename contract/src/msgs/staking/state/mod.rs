@@ -1,4 +1,8 @@
+pub mod delegations;
+pub mod executor_snapshots;
+pub mod executor_stats;
 pub mod is_eligible_for_dr;
+pub mod jailing;
 pub mod stakers_map;
 
 use seda_common::msgs::staking::{Staker, StakingConfig};
@@ -11,3 +15,16 @@ pub const STAKING_CONFIG: Item<StakingConfig> = Item::new("staking_config");
 
 /// A map of stakers (of address to info).
 pub const STAKERS: StakersMap = new_stakers_map!("data_request_executors");
+
+/// The stake counted towards an executor's eligibility, including what has been
+/// delegated to them.
+pub fn effective_stake(staker: &Staker) -> Uint128 {
+    staker.tokens_staked.saturating_add(staker.tokens_delegated)
+}
+
+#[cfg(test)]
+#[path = ""]
+mod tests {
+    use super::*;
+    mod eligibility_gas_tests;
+}
@@ -1,35 +1,160 @@
+use std::collections::HashMap;
+
+use executor_snapshots::{mark_executor_set_changed, record_stake_change};
 use owner::state::ALLOWLIST;
 
 use super::*;
 
 pub struct StakersMap<'a> {
-    pub stakers:     Map<&'a PublicKey, Staker>,
-    pub public_keys: EnumerableSet<PublicKey>,
+    pub stakers:       Map<&'a PublicKey, Staker>,
+    pub public_keys:   EnumerableSet<PublicKey>,
+    /// Maps a rotated public key to the key that replaced it.
+    pub rotated_keys:  Map<&'a PublicKey, PublicKey>,
+    /// Maps a public key to the rotated key it replaced.
+    pub replaced_keys: Map<&'a PublicKey, PublicKey>,
+    /// Maps the public key of every staker that can currently be selected to
+    /// their effective stake.
+    pub active:        Map<&'a [u8], Uint128>,
+    /// The number of entries in `active`.
+    pub active_count:  Item<u32>,
 }
 
 impl StakersMap<'_> {
     pub fn initialize(&self, store: &mut dyn Storage) -> StdResult<()> {
         self.public_keys.initialize(store)?;
+        self.active_count.save(store, &0)?;
         Ok(())
     }
 
     pub fn insert(&self, store: &mut dyn Storage, key: PublicKey, value: &Staker) -> StdResult<()> {
         self.stakers.save(store, &key, value)?;
-        self.public_keys.add(store, key)?;
+        self.public_keys.add(store, key.clone())?;
+        self.refresh_active(store, &key)?;
         Ok(())
     }
 
     pub fn update(&self, store: &mut dyn Storage, key: PublicKey, value: &Staker) -> StdResult<()> {
         self.stakers.save(store, &key, value)?;
+        self.refresh_active(store, &key)?;
         Ok(())
     }
 
     pub fn remove(&self, store: &mut dyn Storage, key: PublicKey) -> StdResult<()> {
         self.stakers.remove(store, &key);
-        self.public_keys.remove(store, key)?;
+        self.public_keys.remove(store, key.clone())?;
+        self.refresh_active(store, &key)?;
+        Ok(())
+    }
+
+    /// Moves a staker to a new public key, keeping their index in the set.
+    pub fn rotate(&self, store: &mut dyn Storage, key: PublicKey, new_key: PublicKey) -> StdResult<()> {
+        let staker = self.get_staker(store, &key)?;
+        self.stakers.remove(store, &key);
+        self.stakers.save(store, &new_key, &staker)?;
+        self.public_keys.replace(store, key.clone(), new_key.clone())?;
+        self.rotated_keys.save(store, &key, &new_key)?;
+        self.replaced_keys.save(store, &new_key, &key)?;
+        self.refresh_active(store, &key)?;
+        self.refresh_active(store, &new_key)?;
         Ok(())
     }
 
+    /// Adds or removes a staker from the active executors after anything
+    /// deciding whether they are active changed.
+    pub fn refresh_active(&self, store: &mut dyn Storage, key: &PublicKey) -> StdResult<()> {
+        let config = STAKING_CONFIG.load(store)?;
+        let stake = match self.may_get_staker(store, key)? {
+            Some(staker) if self.is_active(store, &config, key, &staker)? => Some(effective_stake(&staker)),
+            _ => None,
+        };
+
+        let current = self.active.may_load(store, key.as_ref())?;
+        if current == stake {
+            return Ok(());
+        }
+
+        let mut count = self.active_count(store)?;
+        match stake {
+            Some(stake) => {
+                self.active.save(store, key.as_ref(), &stake)?;
+                if current.is_none() {
+                    count += 1;
+                }
+            }
+            None => {
+                self.active.remove(store, key.as_ref());
+                count -= 1;
+            }
+        }
+        self.active_count.save(store, &count)?;
+        record_stake_change(store, key.as_ref(), stake)
+    }
+
+    /// Rebuilds the active executors from scratch, for when the staking config
+    /// changed.
+    pub fn rebuild_active(&self, store: &mut dyn Storage) -> StdResult<()> {
+        let mut previous = self
+            .active
+            .range_raw(store, None, None, Order::Ascending)
+            .collect::<StdResult<HashMap<Vec<u8>, _>>>()?;
+        self.active.clear(store);
+
+        let config = STAKING_CONFIG.load(store)?;
+        let stakers = self
+            .stakers
+            .range_raw(store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut count = 0;
+        for (public_key, staker) in stakers {
+            let stake = match self.is_active(store, &config, &public_key, &staker)? {
+                true => Some(effective_stake(&staker)),
+                false => None,
+            };
+            if let Some(stake) = stake {
+                self.active.save(store, &public_key, &stake)?;
+                count += 1;
+            }
+            if previous.remove(&public_key) != stake {
+                record_stake_change(store, &public_key, stake)?;
+            }
+        }
+        for public_key in previous.into_keys() {
+            record_stake_change(store, &public_key, None)?;
+        }
+        self.active_count.save(store, &count)?;
+        // the selection mode may have changed as well
+        mark_executor_set_changed(store)
+    }
+
+    pub fn active_count(&self, store: &dyn Storage) -> StdResult<u32> {
+        Ok(self.active_count.may_load(store)?.unwrap_or_default())
+    }
+
+    /// Whether the public key belongs to a staker or has been rotated away
+    /// from.
+    pub fn is_key_used(&self, store: &dyn Storage, key: &PublicKey) -> bool {
+        self.stakers.has(store, key) || self.rotated_keys.has(store, key)
+    }
+
+    /// Follows key rotations to the public key currently used by a staker.
+    pub fn resolve_key(&self, store: &dyn Storage, mut key: PublicKey) -> StdResult<PublicKey> {
+        while let Some(new_key) = self.rotated_keys.may_load(store, &key)? {
+            key = new_key;
+        }
+        Ok(key)
+    }
+
+    /// The public keys a staker rotated away from, latest first.
+    pub fn previous_keys(&self, store: &dyn Storage, key: &PublicKey) -> StdResult<Vec<PublicKey>> {
+        let mut previous_keys = Vec::new();
+        let mut key = key.clone();
+        while let Some(previous_key) = self.replaced_keys.may_load(store, &key)? {
+            previous_keys.push(previous_key.clone());
+            key = previous_key;
+        }
+        Ok(previous_keys)
+    }
+
     pub fn may_get_staker(&self, store: &dyn Storage, pub_key: &PublicKey) -> StdResult<Option<Staker>> {
         self.stakers.may_load(store, pub_key)
     }
@@ -45,9 +170,19 @@ impl StakersMap<'_> {
     }
 
     pub fn is_staker_executor(&self, store: &dyn Storage, executor: &PublicKey) -> StdResult<bool> {
-        let config = STAKING_CONFIG.load(store)?;
+        Ok(self.active.has(store, executor.as_ref()))
+    }
+
+    /// Whether a staker can currently be selected to execute data requests.
+    pub fn is_active(
+        &self,
+        store: &dyn Storage,
+        config: &StakingConfig,
+        public_key: &[u8],
+        staker: &Staker,
+    ) -> StdResult<bool> {
         if config.allowlist_enabled {
-            let allowed = ALLOWLIST.may_load(store, executor)?;
+            let allowed = ALLOWLIST.may_load(store, &PublicKey::try_from(public_key)?)?;
             // If the executor is not in the allowlist, they are not eligible.
             // If the executor is in the allowlist, but the value is false, they are not
             // eligible.
@@ -56,11 +191,7 @@ impl StakersMap<'_> {
             }
         }
 
-        let executor = self.may_get_staker(store, executor)?;
-        Ok(match executor {
-            Some(staker) => staker.tokens_staked >= config.minimum_stake,
-            None => false,
-        })
+        Ok(staker.jailed_until.is_none() && effective_stake(staker) >= config.minimum_stake)
     }
 
     pub fn len(&self, store: &dyn Storage) -> StdResult<u32> {
@@ -71,8 +202,12 @@ impl StakersMap<'_> {
 macro_rules! new_stakers_map {
     ($namespace:literal) => {
         StakersMap {
-            stakers:     Map::new(concat!($namespace, "_stakers")),
-            public_keys: $crate::enumerable_set!(concat!($namespace, "_public_keys")),
+            stakers:       Map::new(concat!($namespace, "_stakers")),
+            public_keys:   $crate::enumerable_set!(concat!($namespace, "_public_keys")),
+            rotated_keys:  Map::new(concat!($namespace, "_rotated_keys")),
+            replaced_keys: Map::new(concat!($namespace, "_replaced_keys")),
+            active:        Map::new(concat!($namespace, "_active")),
+            active_count:  Item::new(concat!($namespace, "_active_count")),
         }
     };
 }
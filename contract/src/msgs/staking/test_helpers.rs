@@ -1,4 +1,12 @@
-use seda_common::msgs::staking::{GetExecutorEligibilityResponse, GetExecutorsResponse};
+use seda_common::msgs::staking::{
+    Delegation,
+    ExecutorStatsSortBy,
+    GetDelegationsResponse,
+    GetExecutorEligibilityBatchResponse,
+    GetExecutorEligibilityResponse,
+    GetExecutorStatsResponse,
+    GetExecutorsResponse,
+};
 
 use super::{
     msgs::staking::{execute, query},
@@ -95,10 +103,16 @@ impl TestAccount {
 
     #[track_caller]
     pub fn unstake(&self) -> Result<(), ContractError> {
+        self.unstake_amount(None)
+    }
+
+    #[track_caller]
+    pub fn unstake_amount(&self, amount: Option<u128>) -> Result<(), ContractError> {
         let seq = self.get_account_sequence();
 
         let factory = execute::unstake::Execute::factory(
             self.pub_key_hex(),
+            amount.map(Into::into),
             self.test_info.chain_id(),
             self.test_info.contract_addr_str(),
             seq,
@@ -183,6 +197,19 @@ impl TestAccount {
             .unwrap()
     }
 
+    #[track_caller]
+    pub fn get_executor_eligibility_batch(&self, dr_ids: Vec<String>) -> GetExecutorEligibilityBatchResponse {
+        let factory = query::is_executor_eligible_batch::Query::factory(
+            self.pub_key_hex(),
+            dr_ids,
+            self.test_info.chain_id(),
+            self.test_info.contract_addr_str(),
+        );
+        let proof = self.prove(factory.get_hash());
+
+        self.test_info.query(factory.create_message(proof)).unwrap()
+    }
+
     #[track_caller]
     pub fn get_account_sequence(&self) -> Uint128 {
         self.test_info
@@ -198,4 +225,137 @@ impl TestAccount {
             .query(query::QueryMsg::GetExecutors { offset, limit })
             .unwrap()
     }
+
+    #[track_caller]
+    pub fn get_executor_stats(
+        &self,
+        sort_by: ExecutorStatsSortBy,
+        offset: u32,
+        limit: u32,
+    ) -> GetExecutorStatsResponse {
+        self.test_info
+            .query(query::QueryMsg::GetExecutorStats { sort_by, offset, limit })
+            .unwrap()
+    }
+
+    #[track_caller]
+    pub fn set_commission_rate(&self, commission_rate_bps: u16) -> Result<(), ContractError> {
+        let seq = self.get_account_sequence();
+
+        let factory = execute::set_commission_rate::Execute::factory(
+            self.pub_key_hex(),
+            commission_rate_bps,
+            self.test_info.chain_id(),
+            self.test_info.contract_addr_str(),
+            seq,
+        );
+        let proof = self.prove(factory.get_hash());
+        let msg = factory.create_message(proof);
+
+        self.test_info.execute(self, &msg)
+    }
+
+    #[track_caller]
+    pub fn set_reward_policy(&self, restake_ratio_bps: u16) -> Result<(), ContractError> {
+        let seq = self.get_account_sequence();
+
+        let factory = execute::set_reward_policy::Execute::factory(
+            self.pub_key_hex(),
+            restake_ratio_bps,
+            self.test_info.chain_id(),
+            self.test_info.contract_addr_str(),
+            seq,
+        );
+        let proof = self.prove(factory.get_hash());
+        let msg = factory.create_message(proof);
+
+        self.test_info.execute(self, &msg)
+    }
+
+    #[track_caller]
+    pub fn delegate(&self, executor: &TestAccount, amount: u128) -> Result<(), ContractError> {
+        let msg = execute::delegate::Execute {
+            public_key: executor.pub_key_hex(),
+        }
+        .into();
+
+        self.test_info.execute_with_funds(self, &msg, amount)
+    }
+
+    #[track_caller]
+    pub fn undelegate(&self, executor: &TestAccount, amount: u128) -> Result<(), ContractError> {
+        let msg = execute::undelegate::Execute {
+            public_key: executor.pub_key_hex(),
+            amount:     amount.into(),
+        }
+        .into();
+
+        self.test_info.execute(self, &msg)
+    }
+
+    #[track_caller]
+    pub fn withdraw_delegation(&self, executor: &TestAccount) -> Result<(), ContractError> {
+        let msg = execute::withdraw_delegation::Execute {
+            public_key: executor.pub_key_hex(),
+        }
+        .into();
+
+        self.test_info.execute(self, &msg)
+    }
+
+    #[track_caller]
+    pub fn get_delegation(&self, executor: &TestAccount) -> Option<Delegation> {
+        self.test_info
+            .query(query::QueryMsg::GetDelegation {
+                delegator:  self.addr().to_string(),
+                public_key: executor.pub_key_hex(),
+            })
+            .unwrap()
+    }
+
+    #[track_caller]
+    pub fn get_delegations(&self, start_after: Option<String>, limit: u32) -> GetDelegationsResponse {
+        self.test_info
+            .query(query::QueryMsg::GetDelegations {
+                public_key: self.pub_key_hex(),
+                start_after,
+                limit,
+            })
+            .unwrap()
+    }
+
+    #[track_caller]
+    pub fn rotate_key(&self, new_key: &TestAccount) -> Result<(), ContractError> {
+        let seq = self.get_account_sequence();
+
+        let factory = execute::rotate_key::Execute::factory(
+            self.pub_key_hex(),
+            new_key.pub_key_hex(),
+            self.test_info.chain_id(),
+            self.test_info.contract_addr_str(),
+            seq,
+        )
+        .unwrap();
+        let proof = self.prove(factory.get_hash());
+        let new_proof = new_key.prove(factory.get_hash());
+        let msg = factory.create_message(proof, new_proof);
+
+        self.test_info.execute(self, &msg)
+    }
+
+    #[track_caller]
+    pub fn unjail(&self) -> Result<(), ContractError> {
+        let seq = self.get_account_sequence();
+
+        let factory = execute::unjail::Execute::factory(
+            self.pub_key_hex(),
+            self.test_info.chain_id(),
+            self.test_info.contract_addr_str(),
+            seq,
+        );
+        let proof = self.prove(factory.get_hash());
+        let msg = factory.create_message(proof);
+
+        self.test_info.execute(self, &msg)
+    }
 }
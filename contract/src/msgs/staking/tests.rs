@@ -2,7 +2,16 @@ use msgs::data_requests::{
     sudo::{DistributionDataProxyReward, DistributionMessage},
     RevealBody,
 };
-use seda_common::msgs::staking::{ExecutorEligibilityStatus, GetExecutorEligibilityResponse, Staker, StakingConfig};
+use seda_common::msgs::staking::{
+    Delegation,
+    ExecutorEligibilityStatus,
+    GetExecutorEligibilityBatchResponse,
+    GetExecutorEligibilityResponse,
+    SelectionMode,
+    Staker,
+    StakingConfig,
+    UnbondingEntry,
+};
 
 use super::*;
 use crate::{new_public_key, seda_to_aseda, TestInfo};
@@ -12,8 +21,12 @@ fn owner_set_staking_config() {
     let test_info = TestInfo::init();
 
     let new_config = StakingConfig {
-        minimum_stake:     200u8.into(),
-        allowlist_enabled: false,
+        minimum_stake:                 200u8.into(),
+        allowlist_enabled:             false,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
 
     // owner sets staking config
@@ -26,8 +39,12 @@ fn non_owner_cannot_set_staking_config() {
     let test_info = TestInfo::init();
 
     let new_config = StakingConfig {
-        minimum_stake:     200u8.into(),
-        allowlist_enabled: false,
+        minimum_stake:                 200u8.into(),
+        allowlist_enabled:             false,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
 
     // non-owner sets staking config
@@ -44,8 +61,12 @@ fn deposit_stake_withdraw() {
     let anyone = test_info.new_account("anyone", 3);
 
     let new_config = StakingConfig {
-        minimum_stake:     1u8.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 1u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
 
     // owner sets staking config
@@ -79,14 +100,23 @@ fn deposit_stake_withdraw() {
     // can double call unstake. doesn't do anything
     assert!(anyone.unstake().is_ok());
 
-    // data request executor's stake should be 0 and pending 3
+    // data request executor's stake should be 0 and unbonding 3
     let value: Option<Staker> = anyone.get_staker_info();
     assert_eq!(
         value,
         Some(Staker {
             memo:                      Some("address".as_bytes().into()),
             tokens_staked:             0u8.into(),
-            tokens_pending_withdrawal: 3u8.into(),
+            tokens_pending_withdrawal: 0u8.into(),
+            unbonding:                 vec![UnbondingEntry {
+                amount:         3u8.into(),
+                release_height: test_info.block_height(),
+            }],
+            tokens_delegated:          0u8.into(),
+            commission_rate_bps:       0,
+            missed_reveals:            0,
+            jailed_until:              None,
+            restake_ratio_bps:         0,
         }),
     );
 
@@ -134,6 +164,12 @@ fn register_data_request_executor() {
             memo:                      Some("memo".to_string().as_bytes().into()),
             tokens_staked:             1u8.into(),
             tokens_pending_withdrawal: 0u8.into(),
+            unbonding:                 vec![],
+            tokens_delegated:          0u8.into(),
+            commission_rate_bps:       0,
+            missed_reveals:            0,
+            jailed_until:              None,
+            restake_ratio_bps:         0,
         }),
     );
 }
@@ -154,6 +190,12 @@ fn unregister_data_request_executor() {
             memo:                      Some("memo".to_string().as_bytes().into()),
             tokens_staked:             2u8.into(),
             tokens_pending_withdrawal: 0u8.into(),
+            unbonding:                 vec![],
+            tokens_delegated:          0u8.into(),
+            commission_rate_bps:       0,
+            missed_reveals:            0,
+            jailed_until:              None,
+            restake_ratio_bps:         0,
         }),
     );
 
@@ -165,7 +207,16 @@ fn unregister_data_request_executor() {
         Some(Staker {
             memo:                      Some("memo".to_string().as_bytes().into()),
             tokens_staked:             0u8.into(),
-            tokens_pending_withdrawal: 2u8.into(),
+            tokens_pending_withdrawal: 0u8.into(),
+            unbonding:                 vec![UnbondingEntry {
+                amount:         2u8.into(),
+                release_height: test_info.block_height(),
+            }],
+            tokens_delegated:          0u8.into(),
+            commission_rate_bps:       0,
+            missed_reveals:            0,
+            jailed_until:              None,
+            restake_ratio_bps:         0,
         }),
     );
 
@@ -247,8 +298,12 @@ fn only_allow_active_stakers_to_be_eligible() {
     let test_info = TestInfo::init();
 
     let msg = msgs::ExecuteMsg::Staking(msgs::staking::execute::ExecuteMsg::SetStakingConfig(StakingConfig {
-        minimum_stake:     Uint128::from(10u32),
-        allowlist_enabled: false,
+        minimum_stake:                 Uint128::from(10u32),
+        allowlist_enabled:             false,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     }));
 
     test_info.execute::<()>(&test_info.creator(), &msg).unwrap();
@@ -275,6 +330,190 @@ fn only_allow_active_stakers_to_be_eligible() {
     assert!(!val2.is_staker_executor());
 }
 
+#[test]
+fn eligibility_uses_executor_snapshot() {
+    let test_info = TestInfo::init();
+    let val1 = test_info.new_executor("val1", 20, 1);
+    let val2 = test_info.new_executor("val2", 20, 1);
+
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(1, 2);
+    let dr_id = val1.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    // val3 starts executing after the data request was posted
+    let val3 = test_info.new_executor("val3", 20, 1);
+    assert!(val3.is_staker_executor());
+
+    // even once every backup executor is eligible, val3 is not part of the snapshot
+    test_info.set_block_height(100);
+    assert!(val1.is_executor_eligible(dr_id.clone()));
+    assert!(val2.is_executor_eligible(dr_id.clone()));
+    assert!(!val3.is_executor_eligible(dr_id));
+
+    // a data request posted afterwards does select val3
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(2, 3);
+    let dr_id = val1.post_data_request(dr, vec![], vec![], 100, None).unwrap();
+    assert!(val3.is_executor_eligible(dr_id));
+}
+
+#[test]
+fn unused_executor_snapshots_are_pruned() {
+    let test_info = TestInfo::init();
+    let val1 = test_info.new_executor("val1", 20, 1);
+
+    let snapshot_epochs = || {
+        state::executor_snapshots::SNAPSHOTS
+            .keys(
+                &*test_info.app().contract_storage(&test_info.contract_addr()),
+                None,
+                None,
+                Order::Ascending,
+            )
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap()
+    };
+
+    // data requests share a snapshot while the executor set does not change
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id1 = val1.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(2, 1);
+    let dr_id2 = val1.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    assert_eq!(vec![0], snapshot_epochs());
+
+    test_info.new_executor("val2", 20, 1);
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(3, 1);
+    let dr_id3 = val1.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    assert_eq!(vec![0, 1], snapshot_epochs());
+
+    // the old snapshot goes once no data request references it anymore
+    val1.cancel_data_request(&dr_id1).unwrap();
+    assert_eq!(vec![0, 1], snapshot_epochs());
+    val1.cancel_data_request(&dr_id2).unwrap();
+    assert_eq!(vec![1], snapshot_epochs());
+
+    // the latest snapshot is kept for the next data request
+    val1.cancel_data_request(&dr_id3).unwrap();
+    assert_eq!(vec![1], snapshot_epochs());
+}
+
+#[test]
+fn executor_snapshots_only_store_stake_changes() {
+    let test_info = TestInfo::init();
+    let val1 = test_info.new_executor("val1", 20, 1);
+    let val2 = test_info.new_executor("val2", 20, 1);
+
+    let contract_addr = test_info.contract_addr();
+    let stake_epochs = |executor: &crate::TestAccount| {
+        state::executor_snapshots::SNAPSHOT_STAKE_EPOCHS
+            .prefix(executor.pub_key().as_ref())
+            .keys(
+                &*test_info.app().contract_storage(&contract_addr),
+                None,
+                None,
+                Order::Ascending,
+            )
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap()
+    };
+    let snapshot_stakes = |epoch: u64| {
+        let app = test_info.app();
+        let store = app.contract_storage(&contract_addr);
+        state::executor_snapshots::ExecutorSet::load(&*store, Some(epoch))
+            .unwrap()
+            .stakes(&*store)
+            .unwrap()
+    };
+    let snapshot_stake = |epoch: u64, executor: &crate::TestAccount| {
+        snapshot_stakes(epoch)
+            .into_iter()
+            .find(|(public_key, _)| public_key.as_slice() == executor.pub_key().as_ref())
+            .unwrap()
+            .1
+    };
+    const CURRENT: u64 = state::executor_snapshots::CURRENT_STAKE_EPOCH;
+
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id1 = val1.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    // a new snapshot only needs the stake that changed
+    val1.stake(5).unwrap();
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(2, 1);
+    let dr_id2 = val1.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    assert_eq!(vec![1, CURRENT], stake_epochs(&val1));
+    assert_eq!(vec![CURRENT], stake_epochs(&val2));
+
+    // the first data request still sees the stake val1 had when it was posted
+    assert_eq!(2, snapshot_stakes(0).len());
+    assert!(snapshot_stake(0, &val1) < snapshot_stake(1, &val1));
+    assert_eq!(snapshot_stake(0, &val2), snapshot_stake(1, &val2));
+
+    // stakes no snapshot sees anymore are dropped along with the snapshot
+    val1.cancel_data_request(&dr_id1).unwrap();
+    assert_eq!(vec![CURRENT], stake_epochs(&val1));
+    val1.cancel_data_request(&dr_id2).unwrap();
+    val1.stake(1).unwrap();
+    assert_eq!(vec![2, CURRENT], stake_epochs(&val1));
+    assert_eq!(2, snapshot_stakes(1).len());
+}
+
+#[test]
+fn eligibility_follows_key_rotation() {
+    let test_info = TestInfo::init();
+    let val1 = test_info.new_executor("val1", 20, 1);
+    let val2 = test_info.new_executor("val2", 20, 1);
+    let val1_new = test_info.new_account("val1_new", 0);
+
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(1, 2);
+    let dr_id1 = val2.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(2, 2);
+    let dr_id2 = val2.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    let reveal = |executor: &crate::TestAccount, dr_id: &str| {
+        executor.create_reveal_message(RevealBody {
+            dr_id:             dr_id.to_string(),
+            dr_block_height:   1,
+            exit_code:         0,
+            gas_used:          0,
+            reveal:            "10".hash().into(),
+            proxy_public_keys: vec![],
+        })
+    };
+    val1.commit_result(&dr_id1, &reveal(&val1, &dr_id1)).unwrap();
+    val1.rotate_key(&val1_new).unwrap();
+
+    // the new key is selected in place of the key in the snapshot
+    assert!(!val1.is_executor_eligible(dr_id2.clone()));
+    assert!(val1_new.is_executor_eligible(dr_id2.clone()));
+    let executors = val2.get_data_request_executors(&dr_id2).unwrap().executors;
+    assert!(executors
+        .iter()
+        .any(|e| e.public_key == val1_new.pub_key_hex() && e.eligible));
+    val1_new.commit_result(&dr_id2, &reveal(&val1_new, &dr_id2)).unwrap();
+
+    // but can't commit again where the old key already did
+    assert!(!val1_new.is_executor_eligible(dr_id1.clone()));
+    let res = val1_new.commit_result(&dr_id1, &reveal(&val1_new, &dr_id1));
+    assert!(res.is_err_and(|e| e == ContractError::AlreadyCommitted));
+}
+
+#[test]
+fn eligibility_requires_being_active() {
+    let test_info = TestInfo::init();
+    let val1 = test_info.new_executor("val1", 20, 1);
+    let val2 = test_info.new_executor("val2", 20, 1);
+
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(1, 2);
+    let dr_id = val1.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+    assert!(val2.is_executor_eligible(dr_id.clone()));
+
+    // an executor in the snapshot that drops below the minimum stake can't commit
+    val2.unstake().unwrap();
+    assert!(!val2.is_executor_eligible(dr_id.clone()));
+
+    // until they are active again, with the stake they had in the snapshot
+    val2.stake(1).unwrap();
+    assert!(val2.is_executor_eligible(dr_id));
+}
+
 const VALIDATORS_AMOUNT: usize = 50;
 
 lazy_static::lazy_static! {
@@ -462,6 +701,139 @@ fn get_executor_eligibility_response_status() {
     assert_eq!(response.block_height, 42);
 }
 
+#[test]
+fn get_executor_eligibility_v2_payload() {
+    let test_info = TestInfo::init();
+
+    let alice = test_info.new_executor("alice", 40, 2);
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![1, 2, 3], 1, None).unwrap();
+
+    let query_v2 = |expiry_height| {
+        let factory = query::is_executor_eligible::Query::factory_v2(
+            alice.pub_key_hex(),
+            dr_id.clone(),
+            expiry_height,
+            test_info.chain_id(),
+            test_info.contract_addr_str(),
+        )
+        .unwrap();
+        let proof = alice.prove(factory.get_hash());
+        let (_, data) = factory.create_message(proof);
+        query::is_executor_eligible::Query { data }
+    };
+    let status = |query: query::is_executor_eligible::Query| {
+        let response: GetExecutorEligibilityResponse =
+            test_info.query(query::QueryMsg::GetExecutorEligibility(query)).unwrap();
+        response.status
+    };
+
+    // a v2 query works like a legacy one until it expires
+    test_info.set_block_height(5);
+    assert_eq!(status(query_v2(None)), ExecutorEligibilityStatus::Eligible);
+    assert_eq!(status(query_v2(Some(5))), ExecutorEligibilityStatus::Eligible);
+    assert!(test_info
+        .query::<_, bool>(query::QueryMsg::IsExecutorEligible(query_v2(Some(5))))
+        .unwrap());
+
+    test_info.set_block_height(6);
+    assert_eq!(status(query_v2(Some(5))), ExecutorEligibilityStatus::InvalidSignature);
+    assert!(!test_info
+        .query::<_, bool>(query::QueryMsg::IsExecutorEligible(query_v2(Some(5))))
+        .unwrap());
+
+    // payloads that can't be decoded are reported instead of failing the query
+    let malformed = query::is_executor_eligible::Query {
+        data: Binary::new(vec![2, 1, 2, 3]),
+    };
+    assert_eq!(status(malformed.clone()), ExecutorEligibilityStatus::MalformedRequest);
+    assert!(!test_info
+        .query::<_, bool>(query::QueryMsg::IsExecutorEligible(malformed))
+        .unwrap());
+}
+
+#[test]
+fn get_executor_eligibility_batch() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 40, 2);
+    let dr = data_requests::test_helpers::calculate_dr_id_and_args(1, 1);
+    let dr_id = alice.post_data_request(dr, vec![], vec![], 1, None).unwrap();
+
+    // bob started executing after the data request was posted
+    let bob = test_info.new_executor("bob", 20, 2);
+    let charlie = test_info.new_account("charlie", 40);
+
+    let fake_dr_id = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef".to_string();
+    let dr_ids = vec![dr_id.clone(), fake_dr_id.clone(), "not_hex".to_string()];
+    let statuses = |response: GetExecutorEligibilityBatchResponse| {
+        response
+            .eligibilities
+            .into_iter()
+            .map(|eligibility| (eligibility.dr_id, eligibility.status))
+            .collect::<Vec<_>>()
+    };
+
+    test_info.set_block_height(3);
+    let response = alice.get_executor_eligibility_batch(dr_ids.clone());
+    assert_eq!(3, response.block_height);
+    assert_eq!(
+        vec![
+            (dr_id.clone(), ExecutorEligibilityStatus::Eligible),
+            (fake_dr_id.clone(), ExecutorEligibilityStatus::DataRequestNotFound),
+            ("not_hex".to_string(), ExecutorEligibilityStatus::MalformedRequest),
+        ],
+        statuses(response)
+    );
+
+    let response = bob.get_executor_eligibility_batch(vec![dr_id.clone()]);
+    assert_eq!(
+        vec![(dr_id.clone(), ExecutorEligibilityStatus::NotEligible)],
+        statuses(response)
+    );
+
+    let response = charlie.get_executor_eligibility_batch(vec![dr_id.clone()]);
+    assert_eq!(
+        vec![(dr_id.clone(), ExecutorEligibilityStatus::NotStaker)],
+        statuses(response)
+    );
+
+    // a proof over a different list of data requests is rejected for every one of
+    // them
+    let factory = query::is_executor_eligible_batch::Query::factory(
+        alice.pub_key_hex(),
+        vec![dr_id.clone()],
+        test_info.chain_id(),
+        test_info.contract_addr_str(),
+    );
+    let proof = alice.prove(factory.get_hash());
+    let query = query::is_executor_eligible_batch::Query {
+        public_key: alice.pub_key_hex(),
+        dr_ids,
+        proof: proof.to_hex(),
+    };
+    let response: GetExecutorEligibilityBatchResponse = test_info
+        .query(query::QueryMsg::GetExecutorEligibilityBatch(query))
+        .unwrap();
+    assert!(response
+        .eligibilities
+        .iter()
+        .all(|eligibility| eligibility.status == ExecutorEligibilityStatus::InvalidSignature));
+
+    // oversized batches are rejected before anything is checked
+    let query = query::is_executor_eligible_batch::Query {
+        public_key: alice.pub_key_hex(),
+        dr_ids:     vec![dr_id; consts::MAX_ELIGIBILITY_BATCH_SIZE + 1],
+        proof:      proof.to_hex(),
+    };
+    let res: Result<GetExecutorEligibilityBatchResponse, _> =
+        test_info.query(query::QueryMsg::GetExecutorEligibilityBatch(query));
+    let expected = ContractError::EligibilityBatchTooLarge(
+        consts::MAX_ELIGIBILITY_BATCH_SIZE + 1,
+        consts::MAX_ELIGIBILITY_BATCH_SIZE,
+    );
+    assert!(res.is_err_and(|e| e.to_string().contains(&expected.to_string())));
+}
+
 #[test]
 fn execute_messages_get_paused() {
     let test_info = TestInfo::init();
@@ -488,8 +860,12 @@ fn execute_messages_get_paused() {
 
     // can still change the staking config
     let new_config = StakingConfig {
-        minimum_stake:     10u8.into(),
-        allowlist_enabled: false,
+        minimum_stake:                 10u8.into(),
+        allowlist_enabled:             false,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     test_info.creator().set_staking_config(new_config).unwrap();
 }
@@ -500,8 +876,12 @@ fn staker_not_in_allowlist_withdrawing() {
 
     // update the config with allowlist enabled
     let new_config = StakingConfig {
-        minimum_stake:     10u8.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 10u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     test_info.creator().set_staking_config(new_config).unwrap();
     let alice = test_info.new_account("alice", 100);
@@ -526,8 +906,12 @@ fn minimum_stake_cannot_be_zero() {
 
     // update the config with allowlist enabled
     let new_config = StakingConfig {
-        minimum_stake:     0u8.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 0u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     let res = test_info.creator().set_staking_config(new_config);
     assert!(res.is_err_and(|x| x == ContractError::ZeroMinimumStakeToRegister));
@@ -545,6 +929,7 @@ fn cannot_frontrun_unstake() {
     let seq = alice.get_account_sequence();
     let factory = msgs::staking::execute::unstake::Execute::factory(
         alice.pub_key_hex(),
+        None,
         test_info.chain_id(),
         test_info.contract_addr_str(),
         seq,
@@ -555,9 +940,9 @@ fn cannot_frontrun_unstake() {
     // fred frontruns alice's withdraw
     test_info.execute::<()>(&fred, &msg).unwrap();
 
-    // verify alice tokens are in pending withdrawal
+    // verify alice tokens are unbonding
     let staker = alice.get_staker_info().unwrap();
-    assert_eq!(staker.tokens_pending_withdrawal.u128(), 10);
+    assert_eq!(staker.unbonding[0].amount.u128(), 10);
 
     // fred should still have their same balance (1seda [original] - 1aseda
     // [staked])
@@ -582,9 +967,9 @@ fn cannot_frontrun_withdraw() {
     // alice unstakes all tokens
     alice.unstake().unwrap();
 
-    // verify alice has 10 tokens pending withdrawal
+    // verify alice has 10 tokens unbonding
     let staker = alice.get_staker_info().unwrap();
-    assert_eq!(staker.tokens_pending_withdrawal.u128(), 10);
+    assert_eq!(staker.unbonding[0].amount.u128(), 10);
 
     // alice produces the withdraw message to withdraw their tokens
     let seq = alice.get_account_sequence();
@@ -625,6 +1010,309 @@ fn withdraw_to_invalid_address() {
     alice.withdraw_to("not-an-address".to_string()).unwrap();
 }
 
+#[test]
+fn withdraw_waits_for_unbonding_period() {
+    let test_info = TestInfo::init();
+    test_info
+        .creator()
+        .set_staking_config(StakingConfig {
+            minimum_stake:                 1u8.into(),
+            allowlist_enabled:             false,
+            unbonding_period_in_blocks:    10,
+            missed_reveals_jail_threshold: 0,
+            jail_duration_in_blocks:       0,
+            selection_mode:                SelectionMode::Uniform,
+        })
+        .unwrap();
+
+    let alice = test_info.new_executor("alice", 1, 10);
+    test_info.set_block_height(5);
+    alice.unstake().unwrap();
+
+    // the unstaked tokens are locked until the release height
+    let staker = alice.get_staker_info().unwrap();
+    assert_eq!(
+        staker.unbonding,
+        vec![UnbondingEntry {
+            amount:         10u8.into(),
+            release_height: 15,
+        }]
+    );
+    let res = alice.withdraw();
+    assert!(res.is_err_and(|e| e == ContractError::NothingToWithdraw));
+
+    test_info.set_block_height(14);
+    let res = alice.withdraw();
+    assert!(res.is_err_and(|e| e == ContractError::NothingToWithdraw));
+
+    // once matured the tokens can be withdrawn and alice is no longer a staker
+    test_info.set_block_height(15);
+    alice.withdraw().unwrap();
+    assert_eq!(alice.get_staker_info(), None);
+    assert_eq!(seda_to_aseda(1.into()), test_info.executor_balance("alice"));
+}
+
+#[test]
+fn withdraw_releases_only_matured_entries() {
+    let test_info = TestInfo::init();
+    test_info
+        .creator()
+        .set_staking_config(StakingConfig {
+            minimum_stake:                 1u8.into(),
+            allowlist_enabled:             false,
+            unbonding_period_in_blocks:    10,
+            missed_reveals_jail_threshold: 0,
+            jail_duration_in_blocks:       0,
+            selection_mode:                SelectionMode::Uniform,
+        })
+        .unwrap();
+
+    let alice = test_info.new_executor("alice", 1, 10);
+    test_info.set_block_height(1);
+    alice.unstake().unwrap();
+    test_info.set_block_height(6);
+    alice.stake(4).unwrap();
+    alice.unstake().unwrap();
+
+    // only the first entry has matured
+    test_info.set_block_height(11);
+    alice.withdraw().unwrap();
+
+    let staker = alice.get_staker_info().unwrap();
+    assert!(staker.tokens_pending_withdrawal.is_zero());
+    assert_eq!(
+        staker.unbonding,
+        vec![UnbondingEntry {
+            amount:         4u8.into(),
+            release_height: 16,
+        }]
+    );
+    assert_eq!(seda_to_aseda(1.into()) - 4, test_info.executor_balance("alice"));
+}
+
+#[test]
+fn partial_unstake() {
+    let test_info = TestInfo::init();
+    test_info
+        .creator()
+        .set_staking_config(StakingConfig {
+            minimum_stake:                 5u8.into(),
+            allowlist_enabled:             false,
+            unbonding_period_in_blocks:    0,
+            missed_reveals_jail_threshold: 0,
+            jail_duration_in_blocks:       0,
+            selection_mode:                SelectionMode::Uniform,
+        })
+        .unwrap();
+
+    let alice = test_info.new_executor("alice", 1, 10);
+
+    // unstaking more than is staked fails
+    let res = alice.unstake_amount(Some(11));
+    assert!(res.is_err_and(|e| e == ContractError::InsufficientStake(11u8.into(), 10u8.into())));
+
+    // the remaining stake can't drop below the minimum
+    let res = alice.unstake_amount(Some(6));
+    assert!(res.is_err_and(|e| e == ContractError::RemainingStakeBelowMinimum(5u8.into(), 4u8.into())));
+
+    alice.unstake_amount(Some(5)).unwrap();
+    let staker = alice.get_staker_info().unwrap();
+    assert_eq!(5, staker.tokens_staked.u128());
+    assert_eq!(5, staker.unbonding[0].amount.u128());
+    assert!(alice.is_staker_executor());
+
+    // unstaking the rest is always allowed
+    alice.unstake_amount(Some(5)).unwrap();
+    let staker = alice.get_staker_info().unwrap();
+    assert!(staker.tokens_staked.is_zero());
+    assert!(!alice.is_staker_executor());
+}
+
+#[test]
+fn delegated_tokens_count_toward_stake() {
+    let test_info = TestInfo::init();
+    test_info
+        .creator()
+        .set_staking_config(StakingConfig {
+            minimum_stake:                 10u8.into(),
+            allowlist_enabled:             false,
+            unbonding_period_in_blocks:    10,
+            missed_reveals_jail_threshold: 0,
+            jail_duration_in_blocks:       0,
+            selection_mode:                SelectionMode::Uniform,
+        })
+        .unwrap();
+
+    let alice = test_info.new_executor("alice", 1, 10);
+    let bob = test_info.new_account("bob", 1);
+    alice.unstake_amount(Some(10)).unwrap();
+    assert!(!alice.is_staker_executor());
+
+    // bob's delegation makes alice an executor again
+    bob.delegate(&alice, 10).unwrap();
+    assert!(alice.is_staker_executor());
+    let staker = alice.get_staker_info().unwrap();
+    assert_eq!(10, staker.tokens_delegated.u128());
+    assert_eq!(
+        vec![Delegation {
+            delegator:       bob.addr().to_string(),
+            public_key:      alice.pub_key_hex(),
+            amount:          10u8.into(),
+            accrued_rewards: 0u8.into(),
+            unbonding:       vec![],
+        }],
+        alice.get_delegations(None, 10).delegations
+    );
+
+    // undelegated tokens are locked for the unbonding period
+    test_info.set_block_height(1);
+    bob.undelegate(&alice, 4).unwrap();
+    assert!(!alice.is_staker_executor());
+    let res = bob.undelegate(&alice, 7);
+    assert!(res.is_err_and(|e| e == ContractError::InsufficientStake(7u8.into(), 6u8.into())));
+    let res = bob.withdraw_delegation(&alice);
+    assert!(res.is_err_and(|e| e == ContractError::NothingToWithdraw));
+
+    test_info.set_block_height(11);
+    bob.withdraw_delegation(&alice).unwrap();
+    assert_eq!(seda_to_aseda(1.into()) - 6, test_info.executor_balance("bob"));
+    assert_eq!(6, bob.get_delegation(&alice).unwrap().amount.u128());
+
+    // alice is kept around while she has delegations
+    test_info.set_block_height(20);
+    alice.withdraw().unwrap();
+    assert_eq!(6, alice.get_staker_info().unwrap().tokens_delegated.u128());
+}
+
+#[test]
+fn delegate_to_unknown_executor_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_account("alice", 1);
+    let bob = test_info.new_account("bob", 1);
+
+    assert!(bob.delegate(&alice, 10).is_err());
+    let res = bob.undelegate(&alice, 10);
+    assert!(res.is_err_and(|e| e == ContractError::NoDelegation));
+}
+
+#[test]
+fn set_commission_rate() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 1, 10);
+
+    alice.set_commission_rate(500).unwrap();
+    assert_eq!(500, alice.get_staker_info().unwrap().commission_rate_bps);
+
+    let res = alice.set_commission_rate(10_001);
+    assert!(res.is_err_and(|e| e == ContractError::InvalidCommissionRate(10_001)));
+}
+
+#[test]
+fn set_reward_policy() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 1, 10);
+    assert_eq!(0, alice.get_staker_info().unwrap().restake_ratio_bps);
+
+    alice.set_reward_policy(2_500).unwrap();
+    assert_eq!(2_500, alice.get_staker_info().unwrap().restake_ratio_bps);
+
+    let res = alice.set_reward_policy(10_001);
+    assert!(res.is_err_and(|e| e == ContractError::InvalidRestakeRatio(10_001)));
+}
+
+#[test]
+fn rotate_key_moves_staker() {
+    let test_info = TestInfo::init();
+    test_info
+        .creator()
+        .set_staking_config(StakingConfig {
+            minimum_stake:                 1u8.into(),
+            allowlist_enabled:             true,
+            unbonding_period_in_blocks:    0,
+            missed_reveals_jail_threshold: 0,
+            jail_duration_in_blocks:       0,
+            selection_mode:                SelectionMode::Uniform,
+        })
+        .unwrap();
+
+    let alice = test_info.new_account("alice", 1);
+    let bob = test_info.new_account("bob", 1);
+    let carol = test_info.new_account("carol", 1);
+    let alice_new = test_info.new_account("alice_new", 0);
+    for account in [&alice, &bob] {
+        test_info.creator().add_to_allowlist(account.pub_key()).unwrap();
+        account.stake(10).unwrap();
+    }
+    carol.delegate(&alice, 5).unwrap();
+    let alice_staker = alice.get_staker_info().unwrap();
+    let alice_seq = alice.get_account_sequence();
+
+    alice.rotate_key(&alice_new).unwrap();
+
+    // the staker, allowlist entry and delegations moved to the new key
+    assert_eq!(None, alice.get_staker_info());
+    assert_eq!(Some(alice_staker), alice_new.get_staker_info());
+    assert!(alice_new.is_staker_executor());
+    assert!(!alice.is_staker_executor());
+    let allowlist = test_info.creator().get_allowlist();
+    assert_eq!(2, allowlist.len());
+    assert!(allowlist.contains(&alice_new.pub_key_hex()));
+    assert_eq!(5, carol.get_delegation(&alice_new).unwrap().amount.u128());
+    assert_eq!(None, carol.get_delegation(&alice));
+
+    // the new key keeps the old sequence and the executor keeps their index
+    assert_eq!(alice_seq + Uint128::one(), alice_new.get_account_sequence());
+    let executors = alice.query_executors(0, 10).executors;
+    assert_eq!(alice_new.pub_key_hex(), executors[0].public_key);
+    assert_eq!(bob.pub_key_hex(), executors[1].public_key);
+
+    // the new key can act as the executor
+    alice_new.unstake().unwrap();
+    alice_new.withdraw().unwrap();
+    assert_eq!(seda_to_aseda(1.into()) - 10, test_info.executor_balance("alice"));
+    assert_eq!(10, test_info.executor_balance("alice_new"));
+}
+
+#[test]
+fn rotate_key_to_used_key_fails() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 1, 10);
+    let bob = test_info.new_executor("bob", 1, 10);
+    let alice_new = test_info.new_account("alice_new", 0);
+
+    // a key that belongs to another staker
+    let res = alice.rotate_key(&bob);
+    assert!(res.is_err_and(|e| e == ContractError::PublicKeyAlreadyUsed(bob.pub_key_hex())));
+
+    // a key that was rotated away from
+    alice.rotate_key(&alice_new).unwrap();
+    let res = alice_new.rotate_key(&alice);
+    assert!(res.is_err_and(|e| e == ContractError::PublicKeyAlreadyUsed(alice.pub_key_hex())));
+}
+
+#[test]
+fn rotate_key_requires_both_proofs() {
+    let test_info = TestInfo::init();
+    let alice = test_info.new_executor("alice", 1, 10);
+    let alice_new = test_info.new_account("alice_new", 0);
+    let fred = test_info.new_account("fred", 0);
+
+    let factory = msgs::staking::execute::rotate_key::Execute::factory(
+        alice.pub_key_hex(),
+        alice_new.pub_key_hex(),
+        test_info.chain_id(),
+        test_info.contract_addr_str(),
+        alice.get_account_sequence(),
+    )
+    .unwrap();
+    let proof = alice.prove(factory.get_hash());
+    let msg = factory.create_message(proof, fred.prove(&[0; 32]));
+
+    let res = test_info.execute::<()>(&fred, &msg);
+    assert!(res.is_err());
+    assert!(alice.get_staker_info().is_some());
+}
+
 #[test]
 fn query_paginated_executors_works() {
     let test_info = TestInfo::init();
@@ -699,8 +1387,12 @@ fn query_paginated_executors_removed_from_allowlist() {
 
     // enable allowlist
     let new_config = StakingConfig {
-        minimum_stake:     1u8.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 1u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     test_info.creator().set_staking_config(new_config).unwrap();
 
@@ -729,8 +1421,12 @@ fn query_paginated_executors_swap_removal() {
     let test_info = TestInfo::init();
 
     let new_config = StakingConfig {
-        minimum_stake:     1u8.into(),
-        allowlist_enabled: true,
+        minimum_stake:                 1u8.into(),
+        allowlist_enabled:             true,
+        unbonding_period_in_blocks:    0,
+        missed_reveals_jail_threshold: 0,
+        jail_duration_in_blocks:       0,
+        selection_mode:                SelectionMode::Uniform,
     };
     test_info.creator().set_staking_config(new_config).unwrap();
 
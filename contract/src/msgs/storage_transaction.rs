@@ -0,0 +1,125 @@
+use std::{cmp::Ordering, collections::BTreeMap, iter::Peekable, ops::Bound};
+
+use cosmwasm_std::{Order, Record, Storage};
+
+/// Buffers writes on top of another storage until they are committed, so an
+/// operation that fails halfway leaves the underlying storage untouched.
+pub struct StorageTransaction<'a> {
+    inner:  &'a mut dyn Storage,
+    /// Pending writes, where `None` marks a removed key.
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> StorageTransaction<'a> {
+    pub fn new(inner: &'a mut dyn Storage) -> Self {
+        Self {
+            inner,
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Applies the pending writes to the underlying storage.
+    pub fn commit(self) {
+        for (key, value) in self.writes {
+            match value {
+                Some(value) => self.inner.set(&key, &value),
+                None => self.inner.remove(&key),
+            }
+        }
+    }
+}
+
+impl Storage for StorageTransaction<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.inner.get(key),
+        }
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        let bounds = (
+            start.map_or(Bound::Unbounded, Bound::Included),
+            end.map_or(Bound::Unbounded, Bound::Excluded),
+        );
+        let writes = self.writes.range::<[u8], _>(bounds);
+        let writes: Box<dyn Iterator<Item = _>> = match order {
+            Order::Ascending => Box::new(writes),
+            Order::Descending => Box::new(writes.rev()),
+        };
+
+        Box::new(MergedRange {
+            inner: self.inner.range(start, end, order).peekable(),
+            writes: writes.peekable(),
+            order,
+        })
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
+
+type PendingWrite<'a> = (&'a Vec<u8>, &'a Option<Vec<u8>>);
+
+/// Merges the records of the underlying storage with the pending writes,
+/// which take precedence.
+struct MergedRange<'a> {
+    inner:  Peekable<Box<dyn Iterator<Item = Record> + 'a>>,
+    writes: Peekable<Box<dyn Iterator<Item = PendingWrite<'a>> + 'a>>,
+    order:  Order,
+}
+
+impl Iterator for MergedRange<'_> {
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Record> {
+        loop {
+            let from_writes = match (self.inner.peek(), self.writes.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => false,
+                (None, Some(_)) => true,
+                (Some((inner_key, _)), Some((write_key, _))) => {
+                    let ordering = match self.order {
+                        Order::Ascending => inner_key.as_slice().cmp(write_key.as_slice()),
+                        Order::Descending => write_key.as_slice().cmp(inner_key.as_slice()),
+                    };
+                    match ordering {
+                        Ordering::Less => false,
+                        Ordering::Greater => true,
+                        Ordering::Equal => {
+                            // the pending write replaces the stored record
+                            self.inner.next();
+                            true
+                        }
+                    }
+                }
+            };
+
+            if !from_writes {
+                return self.inner.next();
+            }
+            if let Some((key, Some(value))) = self.writes.next() {
+                return Some((key.clone(), value.clone()));
+            }
+        }
+    }
+}
+
+/// Runs `f` in a [`StorageTransaction`], only keeping its writes if it
+/// succeeds.
+pub fn transactional<T, E>(store: &mut dyn Storage, f: impl FnOnce(&mut dyn Storage) -> Result<T, E>) -> Result<T, E> {
+    let mut transaction = StorageTransaction::new(store);
+    let value = f(&mut transaction)?;
+    transaction.commit();
+    Ok(value)
+}
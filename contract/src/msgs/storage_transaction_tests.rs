@@ -0,0 +1,95 @@
+use testing::MockStorage;
+
+use super::*;
+
+fn store_with(records: &[(&[u8], &[u8])]) -> MockStorage {
+    let mut store = MockStorage::new();
+    for (key, value) in records {
+        store.set(key, value);
+    }
+    store
+}
+
+fn records(store: &dyn Storage, start: Option<&[u8]>, end: Option<&[u8]>, order: Order) -> Vec<Record> {
+    store.range(start, end, order).collect()
+}
+
+#[test]
+fn writes_are_only_applied_on_commit() {
+    let mut store = store_with(&[(b"a", b"1"), (b"b", b"2")]);
+
+    let mut transaction = StorageTransaction::new(&mut store);
+    transaction.set(b"a", b"3");
+    transaction.remove(b"b");
+    transaction.set(b"c", b"4");
+    assert_eq!(Some(b"3".to_vec()), transaction.get(b"a"));
+    assert_eq!(None, transaction.get(b"b"));
+    assert_eq!(Some(b"4".to_vec()), transaction.get(b"c"));
+    drop(transaction);
+
+    assert_eq!(Some(b"1".to_vec()), store.get(b"a"));
+    assert_eq!(Some(b"2".to_vec()), store.get(b"b"));
+    assert_eq!(None, store.get(b"c"));
+
+    let mut transaction = StorageTransaction::new(&mut store);
+    transaction.set(b"a", b"3");
+    transaction.remove(b"b");
+    transaction.commit();
+
+    assert_eq!(Some(b"3".to_vec()), store.get(b"a"));
+    assert_eq!(None, store.get(b"b"));
+}
+
+#[test]
+fn range_merges_pending_writes() {
+    let mut store = store_with(&[(b"a", b"1"), (b"c", b"2"), (b"e", b"3"), (b"g", b"4")]);
+
+    let mut transaction = StorageTransaction::new(&mut store);
+    transaction.set(b"b", b"5");
+    transaction.set(b"c", b"6");
+    transaction.remove(b"e");
+    transaction.remove(b"f");
+    transaction.set(b"h", b"7");
+
+    let expected = vec![
+        (b"a".to_vec(), b"1".to_vec()),
+        (b"b".to_vec(), b"5".to_vec()),
+        (b"c".to_vec(), b"6".to_vec()),
+        (b"g".to_vec(), b"4".to_vec()),
+        (b"h".to_vec(), b"7".to_vec()),
+    ];
+    assert_eq!(expected, records(&transaction, None, None, Order::Ascending));
+
+    let mut reversed = expected.clone();
+    reversed.reverse();
+    assert_eq!(reversed, records(&transaction, None, None, Order::Descending));
+
+    // the start is inclusive and the end exclusive
+    assert_eq!(
+        expected[1..3],
+        records(&transaction, Some(b"b"), Some(b"g"), Order::Ascending)
+    );
+    assert_eq!(
+        reversed[2..4],
+        records(&transaction, Some(b"b"), Some(b"g"), Order::Descending)
+    );
+}
+
+#[test]
+fn transactional_discards_writes_on_error() {
+    let mut store = store_with(&[(b"a", b"1")]);
+
+    let result: Result<(), &str> = transactional(&mut store, |store| {
+        store.set(b"a", b"2");
+        Err("failed")
+    });
+    assert_eq!(Err("failed"), result);
+    assert_eq!(Some(b"1".to_vec()), store.get(b"a"));
+
+    let result: Result<(), &str> = transactional(&mut store, |store| {
+        store.set(b"a", b"2");
+        Ok(())
+    });
+    assert_eq!(Ok(()), result);
+    assert_eq!(Some(b"2".to_vec()), store.get(b"a"));
+}
@@ -25,3 +25,10 @@ pub fn inc_get_seq(store: &mut dyn Storage, public_key: &PublicKey) -> StdResult
     ACCOUNT_SEQ.save(store, public_key, &(seq + 1))?;
     Ok(seq.into())
 }
+
+/// Carries the account sequence over to a rotated key. The old key keeps its
+/// sequence so messages it signed before the rotation can't be replayed.
+pub fn rotate_seq(store: &mut dyn Storage, public_key: &PublicKey, new_public_key: &PublicKey) -> StdResult<()> {
+    let seq = get_seq(store, public_key)?.max(get_seq(store, new_public_key)?);
+    ACCOUNT_SEQ.save(store, new_public_key, &seq)
+}
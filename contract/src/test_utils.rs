@@ -4,15 +4,46 @@ use std::{
     rc::Rc,
 };
 
-use cosmwasm_std::{coins, from_json, testing::MockApi, to_json_binary, Addr, Empty, StdError};
-use cw_multi_test::{AppBuilder, ContractWrapper, Executor, StargateAccepting};
+use cosmwasm_std::{
+    coins,
+    from_json,
+    testing::MockApi,
+    to_json_binary,
+    Addr,
+    Api,
+    BankMsg,
+    Binary,
+    BlockInfo,
+    CustomMsg,
+    CustomQuery,
+    DistributionMsg,
+    DistributionQuery,
+    Empty,
+    Querier,
+    StdError,
+    Storage,
+};
+use cw_multi_test::{
+    error::AnyResult,
+    AppBuilder,
+    AppResponse,
+    ContractWrapper,
+    CosmosRouter,
+    Distribution,
+    Executor,
+    Module,
+    StargateAccepting,
+};
 use k256::{
     ecdsa::{SigningKey, VerifyingKey},
     elliptic_curve::rand_core::OsRng,
 };
 use num_bigfloat::BigFloat;
 use seda_common::{
-    msgs::{staking::StakingConfig, *},
+    msgs::{
+        staking::{SelectionMode, StakingConfig},
+        *,
+    },
     types::ToHexStr,
 };
 use serde::{de::DeserializeOwned, Serialize};
@@ -28,6 +59,81 @@ pub fn new_public_key() -> (SigningKey, PublicKey) {
     (signing_key, PublicKey(public_key))
 }
 
+/// Name of the account holding the community pool funds.
+const COMMUNITY_POOL: &str = "distribution";
+
+/// The cw-multi-test distribution keeper, extended to move funds sent to the
+/// community pool to the distribution module account.
+pub struct CommunityPoolKeeper {
+    keeper:         cw_multi_test::DistributionKeeper,
+    community_pool: Addr,
+}
+
+impl CommunityPoolKeeper {
+    fn new(community_pool: Addr) -> Self {
+        Self {
+            keeper: cw_multi_test::DistributionKeeper::new(),
+            community_pool,
+        }
+    }
+}
+
+impl Distribution for CommunityPoolKeeper {}
+
+impl Module for CommunityPoolKeeper {
+    type ExecT = DistributionMsg;
+    type QueryT = DistributionQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: DistributionMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            DistributionMsg::FundCommunityPool { amount } => {
+                let to_address = self.community_pool.to_string();
+                router.execute(api, storage, block, sender, BankMsg::Send { to_address, amount }.into())
+            }
+            msg => self.keeper.execute(api, storage, router, block, sender, msg),
+        }
+    }
+
+    fn query(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        block: &BlockInfo,
+        request: DistributionQuery,
+    ) -> AnyResult<Binary> {
+        self.keeper.query(api, storage, querier, block, request)
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        msg: Empty,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        self.keeper.sudo(api, storage, router, block, msg)
+    }
+}
+
 pub type App = cw_multi_test::App<
     cw_multi_test::BankKeeper,
     MockApi,
@@ -35,7 +141,7 @@ pub type App = cw_multi_test::App<
     cw_multi_test::FailingModule<Empty, Empty, Empty>,
     cw_multi_test::WasmKeeper<Empty, Empty>,
     cw_multi_test::StakeKeeper,
-    cw_multi_test::DistributionKeeper,
+    CommunityPoolKeeper,
     cw_multi_test::IbcFailingModule,
     cw_multi_test::GovFailingModule,
     StargateAccepting,
@@ -67,10 +173,13 @@ impl TestInfo {
 
     pub fn init_with_version(version: Option<&'static str>) -> Rc<Self> {
         let mut creator_addr = Addr::unchecked("creator");
+        let api = MockApi::default().with_prefix("seda");
+        let community_pool = api.addr_make(COMMUNITY_POOL);
         let app = Rc::new(RefCell::new(
             AppBuilder::default()
                 .with_stargate(StargateAccepting)
-                .with_api(MockApi::default().with_prefix("seda"))
+                .with_distribution(CommunityPoolKeeper::new(community_pool))
+                .with_api(api)
                 .build(|router, api, storage| {
                     creator_addr = api.addr_make("creator");
                     router
@@ -80,7 +189,11 @@ impl TestInfo {
                 }),
         ));
 
-        let contract = Box::new(ContractWrapper::new(execute, instantiate, query).with_sudo(sudo));
+        let contract = Box::new(
+            ContractWrapper::new(execute, instantiate, query)
+                .with_sudo(sudo)
+                .with_reply(reply),
+        );
         let chain_id = "seda_test".to_string();
 
         let code_id = app.borrow_mut().store_code_with_creator(creator_addr.clone(), contract);
@@ -89,8 +202,12 @@ impl TestInfo {
             owner:          creator_addr.to_string(),
             chain_id:       chain_id.clone(),
             staking_config: Some(StakingConfig {
-                minimum_stake:     1u128.into(),
-                allowlist_enabled: false,
+                minimum_stake:                 1u128.into(),
+                allowlist_enabled:             false,
+                unbonding_period_in_blocks:    0,
+                missed_reveals_jail_threshold: 0,
+                jail_duration_in_blocks:       0,
+                selection_mode:                SelectionMode::Uniform,
             }),
             dr_config:      None,
         };
@@ -201,6 +318,15 @@ impl TestInfo {
             .u128()
     }
 
+    pub fn community_pool_balance(&self) -> u128 {
+        self.app()
+            .wrap()
+            .query_balance(self.new_address(COMMUNITY_POOL), "aseda")
+            .unwrap()
+            .amount
+            .u128()
+    }
+
     pub fn app(&self) -> Ref<'_, App> {
         self.app.borrow()
     }
@@ -256,7 +382,7 @@ impl TestInfo {
                 if e.downcast_ref::<ContractError>().is_some() {
                     e.downcast().unwrap()
                 } else if let Some(s_err) = e.downcast_ref::<StdError>() {
-                    return ContractError::Std(s_err.to_string());
+                    ContractError::Std(s_err.to_string())
                 } else {
                     ContractError::Dbg(e.to_string())
                 }
@@ -278,7 +404,7 @@ impl TestInfo {
                 if e.downcast_ref::<ContractError>().is_some() {
                     e.downcast().unwrap()
                 } else if let Some(s_err) = e.downcast_ref::<StdError>() {
-                    return ContractError::Std(s_err.to_string());
+                    ContractError::Std(s_err.to_string())
                 } else {
                     ContractError::Dbg(e.to_string())
                 }
@@ -305,7 +431,7 @@ impl TestInfo {
                 if e.downcast_ref::<ContractError>().is_some() {
                     e.downcast().unwrap()
                 } else if let Some(s_err) = e.downcast_ref::<StdError>() {
-                    return ContractError::Std(s_err.to_string());
+                    ContractError::Std(s_err.to_string())
                 } else {
                     ContractError::Dbg(e.to_string())
                 }
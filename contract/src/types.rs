@@ -69,7 +69,7 @@ impl PrimaryKey<'_> for PublicKey {
     type Suffix = ();
     type SuperSuffix = ();
 
-    fn key(&self) -> Vec<cw_storage_plus::Key> {
+    fn key(&self) -> Vec<cw_storage_plus::Key<'_>> {
         self.0.key()
     }
 }
@@ -139,8 +139,12 @@ fn create_data_request(
             payback_address: Default::default(),
             height: rand::random(),
             posted_gas_price: 10u128.into(),
+            commit_timeout_in_blocks: 50.try_into().unwrap(),
+            reveal_timeout_in_blocks: 5.try_into().unwrap(),
+            executors: None,
         },
         reveals,
+        timeout_height: None,
     }
 }
 